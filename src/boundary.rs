@@ -1,4 +1,4 @@
-use crate::LatLng;
+use crate::{CellIndex, DirectedEdgeIndex, LatLng, NUM_HEX_VERTS};
 use std::{fmt, ops::Deref};
 
 /// Maximum number of cell boundary vertices.
@@ -59,3 +59,153 @@ impl From<Boundary> for geo::LineString {
         Self::new(value.iter().copied().map(geo::Coord::from).collect())
     }
 }
+
+// -----------------------------------------------------------------------------
+
+/// Boundaries of many cells (or edges), stored in a single flat buffer.
+///
+/// Vertices of every boundary are stored one after the other, and the `i`-th
+/// boundary spans `points()[offsets()[i]..offsets()[i + 1]]` (the layout used
+/// by `GeoArrow`, see the `geoarrow` module for the actual encoding).
+///
+/// # Example
+///
+/// ```
+/// use h3o::{BoundaryBuffer, CellIndex};
+///
+/// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+/// let boundaries = index.grid_disk::<Vec<_>>(1)
+///     .into_iter()
+///     .collect::<BoundaryBuffer>();
+/// assert_eq!(boundaries.len(), 7);
+/// assert_eq!(boundaries.get(0), Some(&*index.boundary()));
+/// # Ok::<(), h3o::error::InvalidCellIndex>(())
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BoundaryBuffer {
+    /// Vertices of every boundary.
+    points: Vec<LatLng>,
+    /// Start of every boundary, followed by the end of the last one.
+    offsets: Vec<usize>,
+}
+
+impl BoundaryBuffer {
+    /// Initializes a new empty buffer.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            points: Vec::new(),
+            offsets: vec![0],
+        }
+    }
+
+    /// Initializes a new empty buffer, with room for `count` cell boundaries.
+    #[must_use]
+    pub fn with_capacity(count: usize) -> Self {
+        // Hexagons are the common case.
+        let points = Vec::with_capacity(count * usize::from(NUM_HEX_VERTS));
+        let mut offsets = Vec::with_capacity(count + 1);
+        offsets.push(0);
+
+        Self { points, offsets }
+    }
+
+    /// Appends the boundary of a cell.
+    pub fn push(&mut self, cell: CellIndex) {
+        self.points.extend_from_slice(&cell.boundary());
+        self.offsets.push(self.points.len());
+    }
+
+    /// Appends the boundary of a directed edge.
+    pub fn push_edge(&mut self, edge: DirectedEdgeIndex) {
+        self.points.extend_from_slice(&edge.boundary());
+        self.offsets.push(self.points.len());
+    }
+
+    /// Returns the number of boundaries.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    /// Returns true if the buffer contains no boundary.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the `index`-th boundary, if any.
+    #[must_use]
+    pub fn get(&self, index: usize) -> Option<&[LatLng]> {
+        let start = *self.offsets.get(index)?;
+        let end = *self.offsets.get(index + 1)?;
+
+        Some(&self.points[start..end])
+    }
+
+    /// Returns an iterator over the boundaries.
+    pub fn iter(&self) -> impl Iterator<Item = &[LatLng]> {
+        self.offsets
+            .windows(2)
+            .map(|range| &self.points[range[0]..range[1]])
+    }
+
+    /// Returns the vertices of every boundary, as a flat buffer.
+    #[must_use]
+    pub fn points(&self) -> &[LatLng] {
+        &self.points
+    }
+
+    /// Returns the offsets delimiting every boundary in the flat buffer.
+    ///
+    /// Starts with 0, and contains one more item than there are boundaries.
+    #[must_use]
+    pub fn offsets(&self) -> &[usize] {
+        &self.offsets
+    }
+
+    /// Clears the buffer, keeping the allocated memory for reuse.
+    pub fn clear(&mut self) {
+        self.points.clear();
+        self.offsets.truncate(1);
+    }
+}
+
+impl Default for BoundaryBuffer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Extend<CellIndex> for BoundaryBuffer {
+    fn extend<T: IntoIterator<Item = CellIndex>>(&mut self, iter: T) {
+        for cell in iter {
+            self.push(cell);
+        }
+    }
+}
+
+impl FromIterator<CellIndex> for BoundaryBuffer {
+    fn from_iter<T: IntoIterator<Item = CellIndex>>(iter: T) -> Self {
+        let iter = iter.into_iter();
+        let mut buffer = Self::with_capacity(iter.size_hint().0);
+        buffer.extend(iter);
+        buffer
+    }
+}
+
+impl Extend<DirectedEdgeIndex> for BoundaryBuffer {
+    fn extend<T: IntoIterator<Item = DirectedEdgeIndex>>(&mut self, iter: T) {
+        for edge in iter {
+            self.push_edge(edge);
+        }
+    }
+}
+
+impl FromIterator<DirectedEdgeIndex> for BoundaryBuffer {
+    fn from_iter<T: IntoIterator<Item = DirectedEdgeIndex>>(iter: T) -> Self {
+        let mut buffer = Self::new();
+        buffer.extend(iter);
+        buffer
+    }
+}
@@ -0,0 +1,594 @@
+//! Fixed-point coordinates.
+//!
+//! The conversions implemented here only rely on integer arithmetic, hence
+//! their results are bit-identical on every platform (unlike the floating-point
+//! ones, which depend on the platform math library).
+//!
+//! Numbers are represented as `i128` with 62 fractional bits, unless stated
+//! otherwise, and trigonometric functions are computed using CORDIC.
+
+use super::{CoordIJK, FaceIJK};
+use crate::{
+    error::InvalidLatLng, face, CellIndex, Face, Resolution, NUM_ICOSA_FACES,
+};
+use std::{array, sync::OnceLock};
+
+/// Number of fractional bits.
+const FRAC_BITS: u32 = 62;
+
+/// 1.0
+const ONE: i128 = 1 << FRAC_BITS;
+
+/// π
+const PI: i128 = 14_488_038_916_154_245_685;
+
+/// π/2
+const HALF_PI: i128 = 7_244_019_458_077_122_842;
+
+/// π/4
+const QUARTER_PI: i128 = 3_622_009_729_038_561_421;
+
+/// Rotation angle between Class II and Class III resolution axes.
+///
+/// `asin(sqrt(3/28))`
+const AP7_ROT_RADS: i128 = 1_537_873_565_994_402_141;
+
+/// 1/sin(60°)
+const RSIN60: i128 = 5_325_116_328_314_171_701;
+
+/// √3/2
+const SQRT3_2: i128 = 3_993_837_246_235_628_775;
+
+/// Number of CORDIC iterations.
+const CORDIC_ITERATIONS: u32 = FRAC_BITS;
+
+/// CORDIC gain compensation factor, for `CORDIC_ITERATIONS` iterations.
+const CORDIC_GAIN: i128 = 2_800_459_870_029_452_954;
+
+/// `atan(2^-i)`, beyond that `atan(2^-i)` rounds to `2^-i`.
+#[allow(clippy::decimal_literal_representation)] // Not bit patterns.
+const ATAN_POWERS: [i128; 21] = [
+    3_622_009_729_038_561_421,
+    2_138_197_195_906_305_897,
+    1_129_764_675_555_192_497,
+    573_486_189_672_913_778,
+    287_855_953_345_232_185,
+    144_068_303_048_368_715,
+    72_051_730_834_756_822,
+    36_028_064_038_054_493,
+    18_014_306_884_351_854,
+    9_007_187_801_521_084,
+    4_503_598_195_715_550,
+    2_251_799_634_728_303,
+    1_125_899_884_473_003,
+    562_949_950_625_109,
+    281_474_976_361_131,
+    140_737_488_311_637,
+    70_368_744_172_203,
+    35_184_372_088_149,
+    17_592_186_044_331,
+    8_796_093_022_197,
+    4_398_046_511_103,
+];
+
+/// π/180e9, with 126 fractional bits.
+const NANODEGREES_TO_RADS: i128 = 1_484_761_922_312_453_816_471_778_296;
+
+/// 180e9/π
+const RADS_TO_NANODEGREES: i128 = 264_230_145_295_380_110_384_661_944_863;
+
+/// Scaling factor from gnomonic to `hex2d` coordinates (`√7^res / u`) for each
+/// resolution.
+const HEX2D_SCALE: [i128; 16] = [
+    12_073_550_741_685_580_227,
+    31_943_612_704_019_484_006,
+    84_514_855_191_799_061_586,
+    223_605_288_928_136_388_044,
+    591_603_986_342_593_431_105,
+    1_565_237_022_496_954_716_307,
+    4_141_227_904_398_154_017_736,
+    10_956_659_157_478_683_014_152,
+    28_988_595_330_787_078_124_152,
+    76_696_614_102_350_781_099_063,
+    202_920_167_315_509_546_869_067,
+    536_876_298_716_455_467_693_444,
+    1_420_441_171_208_566_828_083_466,
+    3_758_134_091_015_188_273_854_109,
+    9_943_088_198_459_967_796_584_260,
+    26_306_938_637_106_317_916_978_763,
+];
+
+/// Scaling factor from `hex2d` to gnomonic coordinates (`u / √7^res`) for each
+/// resolution, with 124 fractional bits.
+const GNOMONIC_SCALE: [i128; 16] = [
+    8_123_518_649_470_971_165_909_014_300_721_182_406,
+    3_070_401_445_327_924_897_881_027_319_698_119_423,
+    1_160_502_664_210_138_737_987_002_042_960_168_915,
+    438_628_777_903_989_271_125_861_045_671_159_918,
+    165_786_094_887_162_676_855_286_006_137_166_988,
+    62_661_253_986_284_181_589_408_720_810_165_703,
+    23_683_727_841_023_239_550_755_143_733_880_998,
+    8_951_607_712_326_311_655_629_817_258_595_100,
+    3_383_389_691_574_748_507_250_734_819_125_857,
+    1_278_801_101_760_901_665_089_973_894_085_014,
+    483_341_384_510_678_358_178_676_402_732_265,
+    182_685_871_680_128_809_298_567_699_155_002,
+    69_048_769_215_811_194_025_525_200_390_324,
+    26_097_981_668_589_829_899_795_385_593_572,
+    9_864_109_887_973_027_717_932_171_484_332,
+    3_728_283_095_512_832_842_827_912_227_653,
+];
+
+/// Half a turn, in nanodegrees.
+const HALF_TURN_NANODEGREES: i64 = 180_000_000_000;
+
+/// A quarter of a turn, in nanodegrees.
+const QUARTER_TURN_NANODEGREES: i64 = 90_000_000_000;
+
+// -----------------------------------------------------------------------------
+
+/// A coordinate on the sphere, in fixed-point nanodegrees.
+///
+/// Unlike [`LatLng`](crate::LatLng), conversions from and to cell indexes only
+/// use integer arithmetic and are thus guaranteed to give bit-identical
+/// results across architectures, compilers and math libraries.
+///
+/// The computations are carried with 62 fractional bits, i.e. an error well
+/// below the nanodegree (~0.1mm) of the input coordinates. As a consequence,
+/// cell assignments match the floating-point ones except for points within a
+/// few nanometers of a cell boundary.
+///
+/// # Example
+///
+/// ```
+/// use h3o::{CellIndex, FixedLatLng, Resolution};
+///
+/// let ll = FixedLatLng::new(48_864_716_000, 2_349_014_000)?;
+/// let cell = ll.to_cell(Resolution::Nine);
+/// assert_eq!(cell, CellIndex::try_from(0x891fb4662d7ffff)?);
+///
+/// let center = FixedLatLng::from(cell);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct FixedLatLng {
+    /// Latitude, in nanodegrees.
+    lat: i64,
+    /// Longitude, in nanodegrees.
+    lng: i64,
+}
+
+impl FixedLatLng {
+    /// Initializes a new coordinate from nanodegrees.
+    ///
+    /// The longitude is normalized into `[-180°, 180°)`.
+    ///
+    /// # Errors
+    ///
+    /// [`InvalidLatLng`] when the latitude is out of `[-90°, 90°]`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let ll = h3o::FixedLatLng::new(48_864_716_000, 2_349_014_000)?;
+    /// # Ok::<(), h3o::error::InvalidLatLng>(())
+    /// ```
+    pub fn new(lat: i64, lng: i64) -> Result<Self, InvalidLatLng> {
+        if !(-QUARTER_TURN_NANODEGREES..=QUARTER_TURN_NANODEGREES)
+            .contains(&lat)
+        {
+            // Only used to report the invalid value.
+            #[allow(clippy::cast_precision_loss)]
+            return Err(InvalidLatLng::new(
+                lat as f64 / 1e9,
+                "latitude out of range",
+            ));
+        }
+
+        Ok(Self::new_unchecked(lat, lng))
+    }
+
+    /// Initializes a new coordinate, normalizing the longitude.
+    const fn new_unchecked(lat: i64, lng: i64) -> Self {
+        let lng = lng.rem_euclid(2 * HALF_TURN_NANODEGREES);
+        let lng = if lng >= HALF_TURN_NANODEGREES {
+            lng - 2 * HALF_TURN_NANODEGREES
+        } else {
+            lng
+        };
+
+        Self { lat, lng }
+    }
+
+    /// Latitude, in nanodegrees.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let ll = h3o::FixedLatLng::new(48_864_716_000, 2_349_014_000)?;
+    /// assert_eq!(ll.lat(), 48_864_716_000);
+    /// # Ok::<(), h3o::error::InvalidLatLng>(())
+    /// ```
+    #[must_use]
+    pub const fn lat(self) -> i64 {
+        self.lat
+    }
+
+    /// Longitude, in nanodegrees.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let ll = h3o::FixedLatLng::new(48_864_716_000, 2_349_014_000)?;
+    /// assert_eq!(ll.lng(), 2_349_014_000);
+    /// # Ok::<(), h3o::error::InvalidLatLng>(())
+    /// ```
+    #[must_use]
+    pub const fn lng(self) -> i64 {
+        self.lng
+    }
+
+    /// Indexes the location at the specified resolution, returning the index of
+    /// the cell containing the location.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let ll = h3o::FixedLatLng::new(48_864_716_000, 2_349_014_000)?;
+    /// let cell = ll.to_cell(h3o::Resolution::Five);
+    /// # Ok::<(), h3o::error::InvalidLatLng>(())
+    /// ```
+    #[must_use]
+    pub fn to_cell(self, resolution: Resolution) -> CellIndex {
+        let (sin_lat, cos_lat) = sin_cos(to_rads(self.lat));
+        let (sin_lng, cos_lng) = sin_cos(to_rads(self.lng));
+        let point = [mul(cos_lat, cos_lng), mul(cos_lat, sin_lng), sin_lat];
+
+        // Find the closest face, i.e. the one whose center is the most aligned
+        // with the point.
+        let frames = face_frames();
+        let (face, frame) = frames.iter().enumerate().skip(1).fold(
+            (0, &frames[0]),
+            |closest, (face, frame)| {
+                if dot(&point, &frame.center) > dot(&point, &closest.1.center) {
+                    (face, frame)
+                } else {
+                    closest
+                }
+            },
+        );
+
+        // Gnomonic projection on the face plane.
+        let axes = &frame.axes[usize::from(resolution.is_class3())];
+        let distance = dot(&point, &frame.center);
+        let x = div(dot(&point, &axes.x), distance);
+        let y = div(dot(&point, &axes.y), distance);
+
+        // Scale for current resolution length `u`.
+        let scale = HEX2D_SCALE[usize::from(resolution)];
+        let coord = hex2d_to_ijk(
+            mul_shr(x, scale, FRAC_BITS),
+            mul_shr(y, scale, FRAC_BITS),
+        );
+
+        // SAFETY: `face` is always in range because it's a index of `frames`.
+        let face = Face::new_unchecked(face);
+        FaceIJK::new(face, coord).to_cell(resolution)
+    }
+}
+
+impl From<CellIndex> for FixedLatLng {
+    /// Determines the coordinates of the center point of an H3 index.
+    fn from(value: CellIndex) -> Self {
+        let fijk = FaceIJK::from(value);
+        let resolution = value.resolution();
+
+        // Center point in 2D cartesian coordinates.
+        let i = i128::from(fijk.coord.i() - fijk.coord.k());
+        let j = i128::from(fijk.coord.j() - fijk.coord.k());
+        let (x, y) = ((i << FRAC_BITS) - (j << (FRAC_BITS - 1)), j * SQRT3_2);
+
+        // Scale back to gnomonic coordinates.
+        let scale = GNOMONIC_SCALE[usize::from(resolution)];
+        let (x, y) = (mul_shr(x, scale, 124), mul_shr(y, scale, 124));
+
+        // Inverse gnomonic projection, from the face plane.
+        let frame = &face_frames()[usize::from(fijk.face)];
+        let axes = &frame.axes[usize::from(resolution.is_class3())];
+        let point: [i128; 3] = array::from_fn(|i| {
+            frame.center[i] + mul(x, axes.x[i]) + mul(y, axes.y[i])
+        });
+
+        let lat = atan2(point[2], hypot(point[0], point[1]));
+        let lng = atan2(point[1], point[0]);
+
+        Self::new_unchecked(to_nanodegrees(lat), to_nanodegrees(lng))
+    }
+}
+
+// -----------------------------------------------------------------------------
+
+/// Axes of a `hex2d` coordinate system, on the plane tangent to a face.
+#[derive(Debug)]
+struct Axes {
+    /// `x` axis (aligned with the `i` axis).
+    x: [i128; 3],
+    /// `y` axis.
+    y: [i128; 3],
+}
+
+/// Reference frame of an icosahedron face.
+#[derive(Debug)]
+struct FaceFrame {
+    /// Face center, on the unit sphere.
+    center: [i128; 3],
+    /// Axes of the Class II and Class III coordinate systems.
+    axes: [Axes; 2],
+}
+
+impl FaceFrame {
+    /// Computes the reference frame of the given face.
+    fn new(face: usize) -> Self {
+        let center = face::CENTER_GEO[face];
+        let (sin_lat, cos_lat) = sin_cos(from_f64(center.lat_radians()));
+        let (sin_lng, cos_lng) = sin_cos(from_f64(center.lng_radians()));
+
+        // Local north and east directions, at the face center.
+        let north = [-mul(sin_lat, cos_lng), -mul(sin_lat, sin_lng), cos_lat];
+        let east = [-sin_lng, cos_lng, 0];
+
+        let i_axis = from_f64(face::AXES_AZ_RADS_CII[face][0]);
+        let axes = [i_axis, i_axis - AP7_ROT_RADS].map(|azimuth| {
+            let (sin_az, cos_az) = sin_cos(azimuth);
+            Axes {
+                x: array::from_fn(|i| {
+                    mul(north[i], cos_az) + mul(east[i], sin_az)
+                }),
+                y: array::from_fn(|i| {
+                    mul(north[i], sin_az) - mul(east[i], cos_az)
+                }),
+            }
+        });
+
+        Self {
+            center: [mul(cos_lat, cos_lng), mul(cos_lat, sin_lng), sin_lat],
+            axes,
+        }
+    }
+}
+
+/// Returns the reference frames of the icosahedron faces.
+fn face_frames() -> &'static [FaceFrame; NUM_ICOSA_FACES] {
+    static FRAMES: OnceLock<[FaceFrame; NUM_ICOSA_FACES]> = OnceLock::new();
+
+    FRAMES.get_or_init(|| array::from_fn(FaceFrame::new))
+}
+
+// Returns the containing hex in `IJK` coordinates for a 2D cartesian
+// coordinate vector (from DGGRID).
+//
+// Fixed-point version of `CoordIJK::from(Vec2d)`.
+fn hex2d_to_ijk(x: i128, y: i128) -> CoordIJK {
+    let mask = ONE - 1;
+    let a1 = x.abs();
+    let a2 = y.abs();
+
+    // First do a reverse conversion.
+    let x2 = mul_shr(a2, RSIN60, FRAC_BITS);
+    let x1 = a1 + (x2 >> 1);
+
+    // Check if we have the center of a hex.
+    // Truncation cannot happen: coordinates are bounded by the resolution.
+    #[allow(clippy::cast_possible_truncation)]
+    let m1 = (x1 >> FRAC_BITS) as i32;
+    #[allow(clippy::cast_possible_truncation)]
+    let m2 = (x2 >> FRAC_BITS) as i32;
+
+    // Otherwise round correctly.
+    let r1 = x1 & mask;
+    let r2 = x2 & mask;
+
+    let (mut i, mut j) = if 2 * r1 < ONE {
+        if 3 * r1 < ONE {
+            let i = m1;
+            let j = m2 + i32::from(2 * r2 >= ONE + r1);
+            (i, j)
+        } else {
+            let i = m1 + i32::from(ONE - r1 <= r2 && r2 < 2 * r1);
+            let j = m2 + i32::from(r2 >= ONE - r1);
+            (i, j)
+        }
+    } else if 3 * r1 < 2 * ONE {
+        let j = m2 + i32::from(r2 >= ONE - r1);
+        let i = m1 + i32::from(2 * r1 - ONE >= r2 || r2 >= ONE - r1);
+        (i, j)
+    } else {
+        let i = m1 + 1;
+        let j = m2 + i32::from(2 * r2 >= r1);
+        (i, j)
+    };
+
+    // Now fold across the axes if necessary.
+    if x < 0 {
+        let offset = j % 2;
+        // `j` is positive at this point.
+        let axis_i = (j + offset) >> 1;
+        let diff = i - axis_i;
+        i -= 2 * diff + offset;
+    }
+
+    if y < 0 {
+        i -= (2 * j + 1) / 2;
+        j = -j;
+    }
+
+    CoordIJK::new(i, j, 0).normalize()
+}
+
+// -----------------------------------------------------------------------------
+
+/// Converts an exact `f64` value.
+fn from_f64(value: f64) -> i128 {
+    // 2^62, exactly representable.
+    #[allow(clippy::cast_precision_loss)]
+    const SCALE: f64 = ONE as f64;
+
+    // Scaling by a power of two is exact, and so is the truncation.
+    #[allow(clippy::cast_possible_truncation)]
+    let value = (value * SCALE) as i128;
+    value
+}
+
+/// Converts nanodegrees into radians.
+fn to_rads(nanodegrees: i64) -> i128 {
+    mul_shr(
+        i128::from(nanodegrees),
+        NANODEGREES_TO_RADS,
+        126 - FRAC_BITS,
+    )
+}
+
+/// Converts radians into nanodegrees, rounded to the nearest integer.
+fn to_nanodegrees(rads: i128) -> i64 {
+    let half_units = mul_shr(rads, RADS_TO_NANODEGREES, 2 * FRAC_BITS - 1);
+    let nanodegrees = half_units / 2 + half_units % 2;
+
+    // Truncation cannot happen: angles are in [-π, π].
+    #[allow(clippy::cast_possible_truncation)]
+    let nanodegrees = nanodegrees as i64;
+    nanodegrees
+}
+
+/// Fixed-point multiplication.
+fn mul(lhs: i128, rhs: i128) -> i128 {
+    mul_shr(lhs, rhs, FRAC_BITS)
+}
+
+/// Fixed-point division.
+///
+/// The dividend must be small enough to be shifted by `FRAC_BITS`.
+const fn div(lhs: i128, rhs: i128) -> i128 {
+    (lhs << FRAC_BITS) / rhs
+}
+
+/// Dot product.
+fn dot(lhs: &[i128; 3], rhs: &[i128; 3]) -> i128 {
+    mul(lhs[0], rhs[0]) + mul(lhs[1], rhs[1]) + mul(lhs[2], rhs[2])
+}
+
+/// Computes `sqrt(x² + y²)`.
+///
+/// The components must be lower than 2 (in absolute value).
+const fn hypot(x: i128, y: i128) -> i128 {
+    let x = x.unsigned_abs();
+    let y = y.unsigned_abs();
+
+    // Cannot overflow: the result is lower than 2√2.
+    #[allow(clippy::cast_possible_wrap)]
+    let result = (x * x + y * y).isqrt() as i128;
+    result
+}
+
+/// Computes `(lhs * rhs) >> shift`, rounded toward zero, using a 256-bit
+/// intermediate product.
+///
+/// The result must fit into an `i128`.
+fn mul_shr(lhs: i128, rhs: i128, shift: u32) -> i128 {
+    const LOW: u128 = u64::MAX as u128;
+
+    debug_assert!(shift > 0 && shift < 128);
+    let is_negative = (lhs < 0) != (rhs < 0);
+    let (lhs, rhs) = (lhs.unsigned_abs(), rhs.unsigned_abs());
+
+    // Schoolbook multiplication, on 64-bit limbs.
+    let (lhs_hi, lhs_lo) = (lhs >> 64, lhs & LOW);
+    let (rhs_hi, rhs_lo) = (rhs >> 64, rhs & LOW);
+    let lo_lo = lhs_lo * rhs_lo;
+    let lo_hi = lhs_lo * rhs_hi;
+    let hi_lo = lhs_hi * rhs_lo;
+    let hi_hi = lhs_hi * rhs_hi;
+    let mid = (lo_lo >> 64) + (lo_hi & LOW) + (hi_lo & LOW);
+    let lo = (lo_lo & LOW) | (mid << 64);
+    let hi = hi_hi + (lo_hi >> 64) + (hi_lo >> 64) + (mid >> 64);
+
+    debug_assert!(hi >> shift == 0, "fixed-point overflow");
+    // Cannot overflow, as long as the result fits.
+    #[allow(clippy::cast_possible_wrap)]
+    let result = ((lo >> shift) | (hi << (128 - shift))) as i128;
+
+    if is_negative {
+        -result
+    } else {
+        result
+    }
+}
+
+/// Returns `atan(2^-i)`.
+fn atan_power(i: u32) -> i128 {
+    ATAN_POWERS
+        .get(i as usize)
+        .copied()
+        .unwrap_or(1 << (FRAC_BITS - i))
+}
+
+/// Computes the sine and cosine of an angle, in radians.
+fn sin_cos(angle: i128) -> (i128, i128) {
+    // Reduce into [-π/4, π/4], keeping track of the quadrant.
+    let quadrant = (angle + QUARTER_PI).div_euclid(HALF_PI);
+    let mut z = angle - quadrant * HALF_PI;
+
+    // CORDIC, in rotation mode.
+    let (mut x, mut y) = (CORDIC_GAIN, 0);
+    for i in 0..CORDIC_ITERATIONS {
+        let (dx, dy) = (y >> i, x >> i);
+        if z >= 0 {
+            (x, y, z) = (x - dx, y + dy, z - atan_power(i));
+        } else {
+            (x, y, z) = (x + dx, y - dy, z + atan_power(i));
+        }
+    }
+
+    match quadrant.rem_euclid(4) {
+        0 => (y, x),
+        1 => (x, -y),
+        2 => (-y, -x),
+        _ => (-x, y),
+    }
+}
+
+/// Computes the four quadrant arctangent of `y` and `x`, in `[-π, π]`.
+fn atan2(y: i128, x: i128) -> i128 {
+    if x == 0 && y == 0 {
+        return 0;
+    }
+
+    // The angle is scale-invariant: use as much bits as possible (keeping
+    // some headroom for the CORDIC gain).
+    let shift = (x.unsigned_abs() | y.unsigned_abs())
+        .leading_zeros()
+        .saturating_sub(4);
+    let (mut x, mut y) = (x << shift, y << shift);
+
+    // Rotate into the right half-plane.
+    let mut z = 0;
+    if x < 0 {
+        z = if y >= 0 { PI } else { -PI };
+        (x, y) = (-x, -y);
+    }
+
+    // CORDIC, in vectoring mode.
+    for i in 0..CORDIC_ITERATIONS {
+        let (dx, dy) = (y >> i, x >> i);
+        if y > 0 {
+            (x, y, z) = (x + dx, y - dy, z + atan_power(i));
+        } else {
+            (x, y, z) = (x - dx, y + dy, z - atan_power(i));
+        }
+    }
+
+    z
+}
+
+#[cfg(test)]
+#[path = "./fixed_tests.rs"]
+mod tests;
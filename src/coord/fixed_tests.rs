@@ -0,0 +1,69 @@
+use super::*;
+use std::f64::consts::{FRAC_PI_2, PI as PI_F64};
+
+/// Converts a fixed-point number into a float.
+#[allow(clippy::cast_precision_loss)] // Only used to compare with floats.
+fn to_f64(value: i128) -> f64 {
+    value as f64 / ONE as f64
+}
+
+#[test]
+fn multiplication() {
+    assert_eq!(mul(3 * ONE, ONE / 2), 3 * ONE / 2);
+    assert_eq!(mul(-3 * ONE, ONE / 2), -3 * ONE / 2);
+    assert_eq!(mul(-ONE, -ONE), ONE);
+    // Intermediate product beyond 128 bits.
+    let big = 5_000_000 * ONE;
+    assert_eq!(mul(big, 7 * ONE), 35_000_000 * ONE);
+    assert_eq!(mul_shr(big, big, 124), 25_000_000_000_000);
+}
+
+#[test]
+fn sine_cosine() {
+    for step in -800..=800 {
+        let angle = f64::from(step) / 100.;
+        let (sin, cos) = sin_cos(from_f64(angle));
+
+        assert!((to_f64(sin) - angle.sin()).abs() < 1e-15, "sin({angle})");
+        assert!((to_f64(cos) - angle.cos()).abs() < 1e-15, "cos({angle})");
+    }
+}
+
+#[test]
+fn arctangent() {
+    for step in 0..360 {
+        let angle = (f64::from(step) - 179.5).to_radians();
+        for radius in [1e-6, 1e-3, 1., 2.] {
+            let (x, y) = (radius * angle.cos(), radius * angle.sin());
+            let result = to_f64(atan2(from_f64(y), from_f64(x)));
+
+            assert!((result - y.atan2(x)).abs() < 1e-12, "atan2({y}, {x})");
+        }
+    }
+
+    assert_eq!(atan2(0, 0), 0);
+    assert!((to_f64(atan2(ONE, 0)) - FRAC_PI_2).abs() < 1e-15);
+    assert!((to_f64(atan2(0, -ONE)) - PI_F64).abs() < 1e-15);
+}
+
+#[test]
+fn degrees_conversion() {
+    for nanodegrees in [0, 1, -1, 123_456_789_012, -180_000_000_000] {
+        assert_eq!(to_nanodegrees(to_rads(nanodegrees)), nanodegrees);
+    }
+}
+
+#[test]
+fn face_frames_orthonormal() {
+    let epsilon = 1 << 10;
+    for frame in face_frames() {
+        assert!((dot(&frame.center, &frame.center) - ONE).abs() < epsilon);
+        for axes in &frame.axes {
+            assert!((dot(&axes.x, &axes.x) - ONE).abs() < epsilon);
+            assert!((dot(&axes.y, &axes.y) - ONE).abs() < epsilon);
+            assert!(dot(&axes.x, &axes.y).abs() < epsilon);
+            assert!(dot(&axes.x, &frame.center).abs() < epsilon);
+            assert!(dot(&axes.y, &frame.center).abs() < epsilon);
+        }
+    }
+}
@@ -12,7 +12,7 @@
 #![allow(clippy::use_self)] // False positive with `auto_ops::impl_op_ex`
 
 use super::{CoordCube, Vec2d, SQRT3_2};
-use crate::{error::HexGridError, Direction};
+use crate::{error::HexGridError, math::mul_add, Direction};
 use auto_ops::impl_op_ex;
 use std::{cmp, fmt};
 
@@ -202,7 +202,7 @@ impl From<CoordIJK> for Vec2d {
         let i = f64::from(value.i - value.k);
         let j = f64::from(value.j - value.k);
 
-        Self::new(0.5_f64.mul_add(-j, i), j * SQRT3_2)
+        Self::new(mul_add(0.5, -j, i), j * SQRT3_2)
     }
 }
 
@@ -3,8 +3,10 @@ use super::{
     RES0_U_GNOMONIC, SQRT7_POWERS,
 };
 use crate::{
-    error::InvalidLatLng, face, CellIndex, Face, Resolution, EARTH_RADIUS_KM,
-    TWO_PI,
+    error::InvalidLatLng,
+    face, grid,
+    math::{acos, asin, atan2, cos, hypot, mul_add, sin, tan},
+    CellIndex, Face, Resolution, EARTH_RADIUS_KM, TWO_PI,
 };
 use float_eq::float_eq;
 use std::{
@@ -153,15 +155,16 @@ impl LatLng {
     /// ```
     #[must_use]
     pub fn distance_rads(self, other: Self) -> f64 {
-        let sin_lat = ((other.lat - self.lat) / 2.).sin();
-        let sin_lng = ((other.lng - self.lng) / 2.).sin();
+        let sin_lat = sin((other.lat - self.lat) / 2.);
+        let sin_lng = sin((other.lng - self.lng) / 2.);
 
-        let a = sin_lat.mul_add(
+        let a = mul_add(
             sin_lat,
-            self.lat.cos() * other.lat.cos() * sin_lng * sin_lng,
+            sin_lat,
+            cos(self.lat) * cos(other.lat) * sin_lng * sin_lng,
         );
 
-        2. * a.sqrt().atan2((1. - a).sqrt())
+        2. * atan2(a.sqrt(), (1. - a).sqrt())
     }
 
     /// The great circle distance, in kilometers, between two spherical
@@ -197,6 +200,39 @@ impl LatLng {
         self.distance_km(other) * 1000.
     }
 
+    /// Computes the great circle distance, in radians, between the coordinate
+    /// and the great circle arc going from `start` to `end`.
+    #[must_use]
+    pub(crate) fn distance_to_arc_rads(self, start: Self, end: Self) -> f64 {
+        let (point, a, b) =
+            (Vec3d::from(self), Vec3d::from(start), Vec3d::from(end));
+        let normal = a.cross(&b);
+
+        // Degenerated arc (single point).
+        if normal.dot(&normal) < EPSILON {
+            return self.distance_rads(start);
+        }
+        let normal = normal.normalize();
+
+        // Project the point on the great circle, then check if the projection
+        // lies between the two ends of the arc.
+        let offset = point.dot(&normal);
+        let projection = Vec3d::new(
+            mul_add(-offset, normal.x, point.x),
+            mul_add(-offset, normal.y, point.y),
+            mul_add(-offset, normal.z, point.z),
+        );
+        if a.cross(&projection).dot(&normal) >= 0.
+            && projection.cross(&b).dot(&normal) >= 0.
+        {
+            // Cross-track distance.
+            return asin(offset.clamp(-1., 1.)).abs();
+        }
+
+        // Otherwise, the closest point is one of the ends.
+        self.distance_rads(start).min(self.distance_rads(end))
+    }
+
     /// Indexes the location at the specified resolution, returning the index of
     /// the cell containing the location.
     ///
@@ -212,6 +248,38 @@ impl LatLng {
         self.to_face_ijk(resolution).to_cell(resolution)
     }
 
+    /// Computes the cells, at the specified resolution, crossed by the great
+    /// circle route from this location to `destination`.
+    ///
+    /// Unlike [`CellIndex::grid_path_cells`] between the endpoint cells, which
+    /// follows the grid, this follows the geographic route: cells are returned
+    /// in the order they are crossed, consecutive cells being neighbors.
+    ///
+    /// Note that the route between two antipodal locations is undefined.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{LatLng, Resolution};
+    ///
+    /// let paris = LatLng::new(48.864716, 2.349014)?;
+    /// let new_york = LatLng::new(40.712776, -74.005974)?;
+    /// let cells = paris.great_circle_cells(new_york, Resolution::Three);
+    /// assert_eq!(cells.first(), Some(&paris.to_cell(Resolution::Three)));
+    /// # Ok::<(), h3o::error::InvalidLatLng>(())
+    /// ```
+    #[must_use]
+    pub fn great_circle_cells(
+        self,
+        destination: Self,
+        resolution: Resolution,
+    ) -> Vec<CellIndex> {
+        grid::arc_cells(self, destination, resolution)
+            .into_iter()
+            .map(|(cell, _)| cell)
+            .collect()
+    }
+
     /// Encodes a coordinate on the sphere to the `FaceIJK` address of the
     /// containing cell at the specified resolution.
     ///
@@ -231,7 +299,7 @@ impl LatLng {
 
         let r = {
             // cos(r) = 1 - 2 * sin^2(r/2) = 1 - 2 * (sqd / 4) = 1 - sqd/2
-            let r = (1. - distance / 2.).acos();
+            let r = acos(1. - distance / 2.);
 
             if r < EPSILON {
                 return Vec2d::new(0., 0.);
@@ -239,7 +307,7 @@ impl LatLng {
 
             // Perform gnomonic scaling of `r` (`tan(r)`) and scale for current
             // resolution length `u`.
-            (r.tan() / RES0_U_GNOMONIC) * SQRT7_POWERS[usize::from(resolution)]
+            (tan(r) / RES0_U_GNOMONIC) * SQRT7_POWERS[usize::from(resolution)]
         };
 
         let theta = {
@@ -255,7 +323,7 @@ impl LatLng {
         };
 
         // Convert to local x, y.
-        Vec2d::new(r * theta.cos(), r * theta.sin())
+        Vec2d::new(r * cos(theta), r * sin(theta))
     }
 
     /// Finds the closest icosahedral face from the current coordinate.
@@ -290,12 +358,12 @@ impl LatLng {
     /// Computes the azimuth to `other` from `self`, in radians.
     #[must_use]
     pub(crate) fn azimuth(self, other: &Self) -> f64 {
-        (other.lat.cos() * (other.lng - self.lng).sin()).atan2(
-            self.lat.cos().mul_add(
-                other.lat.sin(),
-                -self.lat.sin()
-                    * other.lat.cos()
-                    * (other.lng - self.lng).cos(),
+        atan2(
+            cos(other.lat) * sin(other.lng - self.lng),
+            mul_add(
+                cos(self.lat),
+                sin(other.lat),
+                -sin(self.lat) * cos(other.lat) * cos(other.lng - self.lng),
             ),
         )
     }
@@ -319,14 +387,14 @@ impl LatLng {
                 self.lat - distance // Due South.
             }
         } else {
-            self.lat
-                .sin()
-                .mul_add(
-                    distance.cos(),
-                    self.lat.cos() * distance.sin() * azimuth.cos(),
+            asin(
+                mul_add(
+                    sin(self.lat),
+                    cos(distance),
+                    cos(self.lat) * sin(distance) * cos(azimuth),
                 )
-                .clamp(-1., 1.)
-                .asin()
+                .clamp(-1., 1.),
+            )
         };
 
         // Handle poles.
@@ -341,11 +409,11 @@ impl LatLng {
             self.lng
         } else {
             let sinlng =
-                (azimuth.sin() * distance.sin() / lat.cos()).clamp(-1., 1.);
-            let coslng = self.lat.sin().mul_add(-lat.sin(), distance.cos())
-                / self.lat.cos()
-                / lat.cos();
-            self.lng + sinlng.atan2(coslng)
+                (sin(azimuth) * sin(distance) / cos(lat)).clamp(-1., 1.);
+            let coslng = mul_add(sin(self.lat), -sin(lat), cos(distance))
+                / cos(self.lat)
+                / cos(lat);
+            self.lng + atan2(sinlng, coslng)
         };
 
         // XXX: make sure longitudes are in the proper bounds.
@@ -359,6 +427,31 @@ impl LatLng {
         Self::new_unchecked(lat, lng)
     }
 
+    /// Computes the point located at `fraction` of the way along the great
+    /// circle arc from `self` to `other`.
+    ///
+    /// `fraction` is expected to be in `[0; 1]`, where 0 is `self` and 1 is
+    /// `other`.
+    #[must_use]
+    pub(crate) fn interpolate(self, other: Self, fraction: f64) -> Self {
+        let distance = self.distance_rads(other);
+        if distance < EPSILON {
+            return self;
+        }
+
+        // Spherical linear interpolation, see:
+        // https://en.wikipedia.org/wiki/Slerp#Geometric_Slerp
+        let from_weight = sin((1. - fraction) * distance) / sin(distance);
+        let to_weight = sin(fraction * distance) / sin(distance);
+        let (from, to) = (Vec3d::from(self), Vec3d::from(other));
+
+        let x = mul_add(from_weight, from.x, to_weight * to.x);
+        let y = mul_add(from_weight, from.y, to_weight * to.y);
+        let z = mul_add(from_weight, from.z, to_weight * to.z);
+
+        Self::new_unchecked(atan2(z, hypot(x, y)), atan2(y, x))
+    }
+
     /// Initializes a new coordinate with the specified, possibly invalid,
     /// values.
     ///
@@ -393,6 +486,24 @@ impl LatLng {
         )
     }
 
+    /// Converts the coordinate into a geometry coordinate, in degrees or in
+    /// radians.
+    ///
+    /// Radians are the internal representation, and are thus returned as-is
+    /// without any conversion.
+    #[must_use]
+    #[cfg(feature = "geo")]
+    pub(crate) fn to_coord(self, use_degrees: bool) -> geo::Coord {
+        if use_degrees {
+            self.into()
+        } else {
+            geo::Coord {
+                x: self.lng,
+                y: self.lat,
+            }
+        }
+    }
+
     /// Encodes a coordinate on the sphere to the `FaceIJK` address of the
     /// containing cell at the specified resolution.
     ///
@@ -421,11 +532,11 @@ impl From<LatLng> for Vec3d {
     /// Computes the 3D coordinate on unit sphere from the latitude and
     /// longitude.
     fn from(value: LatLng) -> Self {
-        let r = value.lat.cos();
+        let r = cos(value.lat);
 
-        let z = value.lat.sin();
-        let x = value.lng.cos() * r;
-        let y = value.lng.sin() * r;
+        let z = sin(value.lat);
+        let x = cos(value.lng) * r;
+        let y = sin(value.lng) * r;
 
         Self::new(x, y, z)
     }
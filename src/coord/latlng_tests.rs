@@ -182,3 +182,42 @@ fn closest_face() {
         "distance"
     );
 }
+
+#[test]
+fn distance_to_arc_rads() {
+    let start = LatLng::new(0., 0.).expect("start");
+    let end = LatLng::new(0., 10.).expect("end");
+
+    // Projection inside the arc: cross-track distance.
+    let ll = LatLng::new(1., 5.).expect("inside");
+    assert_float_eq!(
+        ll.distance_to_arc_rads(start, end),
+        1_f64.to_radians(),
+        abs <= 1e-12
+    );
+
+    // Projection outside the arc: distance to the closest end.
+    let ll = LatLng::new(1., -3.).expect("before");
+    assert_float_eq!(
+        ll.distance_to_arc_rads(start, end),
+        ll.distance_rads(start),
+        abs <= f64::EPSILON
+    );
+    let ll = LatLng::new(-1., 12.).expect("after");
+    assert_float_eq!(
+        ll.distance_to_arc_rads(start, end),
+        ll.distance_rads(end),
+        abs <= f64::EPSILON
+    );
+
+    // Point on the arc.
+    let ll = LatLng::new(0., 7.).expect("on");
+    assert_float_eq!(ll.distance_to_arc_rads(start, end), 0., abs <= 1e-12);
+
+    // Degenerated arc.
+    assert_float_eq!(
+        ll.distance_to_arc_rads(start, start),
+        ll.distance_rads(start),
+        abs <= f64::EPSILON
+    );
+}
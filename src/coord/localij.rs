@@ -55,11 +55,13 @@ impl TryFrom<LocalIJK> for CellIndex {
 
         // Check for res 0/base cell.
         if resolution == Resolution::Zero {
-            let dir = Direction::try_from(value.coord)?;
+            let dir = Direction::try_from(value.coord)
+                .map_err(|_| Self::Error::too_far(origin_base_cell, None))?;
             // Bail out if we're moving in an invalid direction off a pentagon.
-            let new_base_cell = origin_base_cell
-                .neighbor(dir)
-                .ok_or(Self::Error::Pentagon)?;
+            let new_base_cell =
+                origin_base_cell.neighbor(dir).ok_or_else(|| {
+                    Self::Error::pentagon(origin_base_cell, origin_base_cell)
+                })?;
             return Ok(Self::new_unchecked(bits::set_base_cell(
                 bits,
                 new_base_cell.into(),
@@ -73,7 +75,8 @@ impl TryFrom<LocalIJK> for CellIndex {
             bits::directions_bits_from_ijk(value.coord, &mut bits, resolution);
 
         // Lookup the correct base cell.
-        let mut dir = Direction::try_from(ijk)?;
+        let mut dir = Direction::try_from(ijk)
+            .map_err(|_| Self::Error::too_far(origin_base_cell, None))?;
         let mut base_cell = origin_base_cell.neighbor(dir);
         // If `base_cell` is invalid, it must be because the origin base cell is
         // a pentagon, and because pentagon base cells do not border each other,
@@ -97,9 +100,13 @@ impl TryFrom<LocalIJK> for CellIndex {
                 // The pentagon rotations are being chosen so that dir is not
                 // the deleted direction. If it still happens, it means we're
                 // moving into a deleted subsequence, so there is no index here.
-                let fixed_base_cell = origin_base_cell
-                    .neighbor(dir)
-                    .ok_or(Self::Error::Pentagon)?;
+                let fixed_base_cell =
+                    origin_base_cell.neighbor(dir).ok_or_else(|| {
+                        Self::Error::pentagon(
+                            origin_base_cell,
+                            origin_base_cell,
+                        )
+                    })?;
                 base_cell = Some(fixed_base_cell);
                 debug_assert!(!fixed_base_cell.is_pentagon());
             }
@@ -168,7 +175,8 @@ impl TryFrom<LocalIJK> for CellIndex {
             // TODO: There are cases which are failed but not accounted for
             // here, instead just fail if the recovered index is invalid.
             if bits::first_axe(bits) == Direction::K.axe() {
-                return Err(Self::Error::Pentagon);
+                let pentagon = base_cell.expect("pentagon base cell");
+                return Err(Self::Error::pentagon(origin_base_cell, pentagon));
             }
         }
 
@@ -246,6 +254,52 @@ impl LocalIJ {
         self.coord.j
     }
 
+    /// Converts the coordinates into a cell index, rejecting the ones that
+    /// don't unambiguously designate a cell.
+    ///
+    /// Past the anchor base cell, the coordinate spaces of neighboring base
+    /// cells overlap: like H3's `localIjToCell`, [`CellIndex::try_from`]
+    /// resolves such coordinates into a cell whose own local coordinates may
+    /// differ. This method checks that the cell maps back to the coordinates,
+    /// at the cost of a round-trip for the cells outside of the anchor base
+    /// cell.
+    ///
+    /// # Errors
+    ///
+    /// [`LocalIjError::BaseCellOverlap`] if the coordinates fall in an
+    /// overlapping area, or any error returned by [`CellIndex::try_from`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::CellIndex;
+    ///
+    /// let anchor = CellIndex::try_from(0x823147fffffffff)?;
+    /// let index = CellIndex::try_from(0x8230e7fffffffff)?;
+    /// let localij = index.to_local_ij(anchor)?;
+    /// assert_eq!(localij.to_cell_strict()?, index);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn to_cell_strict(self) -> Result<CellIndex, LocalIjError> {
+        let index = CellIndex::try_from(self)?;
+        if index.base_cell() == self.anchor.base_cell() {
+            return Ok(index);
+        }
+
+        let coord = CoordIJK::from(self.coord).normalize();
+        let is_match = index
+            .to_local_ijk(self.anchor)
+            .is_ok_and(|roundtrip| roundtrip.coord.normalize() == coord);
+        if is_match {
+            Ok(index)
+        } else {
+            Err(LocalIjError::base_cell_overlap(
+                self.anchor.base_cell(),
+                index.base_cell(),
+            ))
+        }
+    }
+
     /// Initialize a new `LocalIJ` from its components.
     ///
     /// Could be used to build invalid local IJ coordinate, only used for tests.
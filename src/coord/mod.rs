@@ -4,6 +4,7 @@
 
 mod cube;
 mod faceijk;
+mod fixed;
 mod ijk;
 mod latlng;
 mod localij;
@@ -12,6 +13,7 @@ mod vec3d;
 
 pub use cube::CoordCube;
 pub use faceijk::{FaceIJK, Overage};
+pub use fixed::FixedLatLng;
 pub use ijk::{CoordIJ, CoordIJK};
 pub use latlng::LatLng;
 pub use localij::{LocalIJ, LocalIJK};
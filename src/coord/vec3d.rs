@@ -1,3 +1,5 @@
+use crate::math::mul_add;
+
 /// 3D floating-point vector.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Vec3d {
@@ -21,7 +23,28 @@ impl Vec3d {
         let y_diff = self.y - other.y;
         let z_diff = self.z - other.z;
 
-        x_diff.mul_add(x_diff, y_diff.mul_add(y_diff, z_diff * z_diff))
+        mul_add(x_diff, x_diff, mul_add(y_diff, y_diff, z_diff * z_diff))
+    }
+
+    /// Computes the dot product of two 3D vectors.
+    pub fn dot(&self, other: &Self) -> f64 {
+        mul_add(self.x, other.x, mul_add(self.y, other.y, self.z * other.z))
+    }
+
+    /// Computes the cross product of two 3D vectors.
+    pub fn cross(&self, other: &Self) -> Self {
+        Self::new(
+            mul_add(self.y, other.z, -self.z * other.y),
+            mul_add(self.z, other.x, -self.x * other.z),
+            mul_add(self.x, other.y, -self.y * other.x),
+        )
+    }
+
+    /// Returns the vector scaled to a unit length.
+    pub fn normalize(&self) -> Self {
+        let norm = self.dot(self).sqrt();
+
+        Self::new(self.x / norm, self.y / norm, self.z / norm)
     }
 }
 
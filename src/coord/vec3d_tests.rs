@@ -40,3 +40,33 @@ fn distance() {
         "distance to <1,1,2> is 6"
     );
 }
+
+#[test]
+fn dot() {
+    let v1 = Vec3d::new(1., 2., 3.);
+    let v2 = Vec3d::new(4., -5., 6.);
+
+    assert_float_eq!(v1.dot(&v2), 12., abs <= f64::EPSILON);
+    assert_float_eq!(v1.dot(&v1), 14., abs <= f64::EPSILON);
+}
+
+#[test]
+fn cross() {
+    let i = Vec3d::new(1., 0., 0.);
+    let j = Vec3d::new(0., 1., 0.);
+    let k = Vec3d::new(0., 0., 1.);
+
+    assert_eq!(i.cross(&j), k, "i × j = k");
+    assert_eq!(j.cross(&k), i, "j × k = i");
+    assert_eq!(k.cross(&i), j, "k × i = j");
+    assert_eq!(j.cross(&i), Vec3d::new(0., 0., -1.), "j × i = -k");
+}
+
+#[test]
+fn normalize() {
+    let v = Vec3d::new(3., 0., 4.).normalize();
+
+    assert_float_eq!(v.dot(&v), 1., abs <= f64::EPSILON);
+    assert_float_eq!(v.x, 0.6, abs <= f64::EPSILON);
+    assert_float_eq!(v.z, 0.8, abs <= f64::EPSILON);
+}
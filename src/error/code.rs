@@ -0,0 +1,187 @@
+use super::{
+    CompactionError, HexGridError, InvalidBaseCell, InvalidCellIndex,
+    InvalidDirectedEdgeIndex, InvalidDirection, InvalidEdge, InvalidErrorCode,
+    InvalidFace, InvalidGraticuleStep, InvalidLatLng, InvalidResolution,
+    InvalidUndirectedEdgeIndex, InvalidVertex, InvalidVertexIndex,
+    LocalIjError, ResolutionMismatch,
+};
+#[cfg(feature = "geo")]
+use super::{InvalidGeometry, OutlinerError};
+use std::{error::Error, fmt};
+
+/// H3 error codes, as defined by the reference implementation (`H3Error`).
+///
+/// Every h3o error can be converted into its H3 counterpart (either through
+/// `From` or their `code` method), which is useful to interoperate with systems
+/// specified in terms of those codes or to handle errors programmatically.
+///
+/// Those codes are only the coarse categories of H3, and distinct failures
+/// may share the same code (e.g. every `InvalidGeometry` is a `Domain` error):
+/// finer details are given by the error types themselves, their variants or
+/// their kind (e.g. `InvalidGeometry::kind`).
+///
+/// Note that `E_SUCCESS` (0) isn't an error, and thus has no equivalent here.
+///
+/// # Example
+///
+/// ```
+/// use h3o::{error::ErrorCode, CellIndex};
+///
+/// let error = CellIndex::try_from(0).expect_err("invalid cell");
+/// let code = ErrorCode::from(error);
+/// assert_eq!(code, ErrorCode::CellInvalid);
+/// assert_eq!(u32::from(code), 5);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(u32)]
+#[non_exhaustive]
+pub enum ErrorCode {
+    /// The operation failed but a more specific error is not available
+    /// (`E_FAILED`).
+    Failed = 1,
+    /// Argument was outside of acceptable range (`E_DOMAIN`).
+    Domain = 2,
+    /// Latitude or longitude arguments were outside of acceptable range
+    /// (`E_LATLNG_DOMAIN`).
+    LatLngDomain = 3,
+    /// Resolution argument was outside of acceptable range (`E_RES_DOMAIN`).
+    ResDomain = 4,
+    /// Cell argument was not valid (`E_CELL_INVALID`).
+    CellInvalid = 5,
+    /// Directed edge argument was not valid (`E_DIR_EDGE_INVALID`).
+    DirEdgeInvalid = 6,
+    /// Undirected edge argument was not valid (`E_UNDIR_EDGE_INVALID`).
+    UndirEdgeInvalid = 7,
+    /// Vertex argument was not valid (`E_VERTEX_INVALID`).
+    VertexInvalid = 8,
+    /// Pentagon distortion was encountered (`E_PENTAGON`).
+    Pentagon = 9,
+    /// Duplicate input was encountered in the arguments
+    /// (`E_DUPLICATE_INPUT`).
+    DuplicateInput = 10,
+    /// Cell arguments were not neighbors (`E_NOT_NEIGHBORS`).
+    NotNeighbors = 11,
+    /// Cell arguments had incompatible resolutions (`E_RES_MISMATCH`).
+    ResMismatch = 12,
+    /// Necessary memory allocation failed (`E_MEMORY_ALLOC`).
+    MemoryAlloc = 13,
+    /// Bounds of provided memory were not large enough
+    /// (`E_MEMORY_BOUNDS`).
+    MemoryBounds = 14,
+    /// Mode or flags argument was not valid (`E_OPTION_INVALID`).
+    OptionInvalid = 15,
+    /// Index argument was not valid (`E_INDEX_INVALID`).
+    IndexInvalid = 16,
+    /// Base cell number was outside of acceptable range
+    /// (`E_BASE_CELL_DOMAIN`).
+    BaseCellDomain = 17,
+    /// Child indexing digits invalid (`E_DIGIT_DOMAIN`).
+    DigitDomain = 18,
+    /// Child indexing digits refer to a deleted subsequence
+    /// (`E_DELETED_DIGIT`).
+    DeletedDigit = 19,
+}
+
+impl TryFrom<u32> for ErrorCode {
+    type Error = InvalidErrorCode;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Err(Self::Error::new(value, "not an error")),
+            1 => Ok(Self::Failed),
+            2 => Ok(Self::Domain),
+            3 => Ok(Self::LatLngDomain),
+            4 => Ok(Self::ResDomain),
+            5 => Ok(Self::CellInvalid),
+            6 => Ok(Self::DirEdgeInvalid),
+            7 => Ok(Self::UndirEdgeInvalid),
+            8 => Ok(Self::VertexInvalid),
+            9 => Ok(Self::Pentagon),
+            10 => Ok(Self::DuplicateInput),
+            11 => Ok(Self::NotNeighbors),
+            12 => Ok(Self::ResMismatch),
+            13 => Ok(Self::MemoryAlloc),
+            14 => Ok(Self::MemoryBounds),
+            15 => Ok(Self::OptionInvalid),
+            16 => Ok(Self::IndexInvalid),
+            17 => Ok(Self::BaseCellDomain),
+            18 => Ok(Self::DigitDomain),
+            19 => Ok(Self::DeletedDigit),
+            _ => Err(Self::Error::new(value, "out of range")),
+        }
+    }
+}
+
+impl From<ErrorCode> for u32 {
+    fn from(value: ErrorCode) -> Self {
+        value as Self
+    }
+}
+
+impl fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let description = match *self {
+            Self::Failed => "operation failed",
+            Self::Domain => "argument out of range",
+            Self::LatLngDomain => "latitude or longitude out of range",
+            Self::ResDomain => "resolution out of range",
+            Self::CellInvalid => "invalid cell",
+            Self::DirEdgeInvalid => "invalid directed edge",
+            Self::UndirEdgeInvalid => "invalid undirected edge",
+            Self::VertexInvalid => "invalid vertex",
+            Self::Pentagon => "pentagon distortion",
+            Self::DuplicateInput => "duplicate input",
+            Self::NotNeighbors => "cells are not neighbors",
+            Self::ResMismatch => "resolution mismatch",
+            Self::MemoryAlloc => "memory allocation failed",
+            Self::MemoryBounds => "memory bounds too small",
+            Self::OptionInvalid => "invalid mode or flags",
+            Self::IndexInvalid => "invalid index",
+            Self::BaseCellDomain => "base cell out of range",
+            Self::DigitDomain => "invalid child indexing digits",
+            Self::DeletedDigit => "deleted child indexing digits",
+        };
+        write!(f, "{description} (H3 error {})", u32::from(*self))
+    }
+}
+
+impl Error for ErrorCode {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        None
+    }
+}
+
+// -----------------------------------------------------------------------------
+
+// Macro to convert an error type into its error code.
+macro_rules! error_code {
+    ($error:ty) => {
+        impl From<$error> for ErrorCode {
+            fn from(value: $error) -> Self {
+                value.code()
+            }
+        }
+    };
+}
+
+error_code!(InvalidResolution);
+error_code!(InvalidCellIndex);
+error_code!(InvalidVertexIndex);
+error_code!(InvalidDirectedEdgeIndex);
+error_code!(InvalidUndirectedEdgeIndex);
+error_code!(InvalidLatLng);
+error_code!(InvalidGraticuleStep);
+error_code!(InvalidEdge);
+error_code!(InvalidVertex);
+error_code!(InvalidFace);
+error_code!(InvalidBaseCell);
+error_code!(InvalidDirection);
+error_code!(InvalidErrorCode);
+error_code!(HexGridError);
+error_code!(ResolutionMismatch);
+error_code!(CompactionError);
+error_code!(LocalIjError);
+#[cfg(feature = "geo")]
+error_code!(InvalidGeometry);
+#[cfg(feature = "geo")]
+error_code!(OutlinerError);
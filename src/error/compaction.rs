@@ -1,3 +1,4 @@
+use super::ErrorCode;
 use std::{error::Error, fmt};
 
 /// Errors occurring while compacting a set of cell indices.
@@ -10,6 +11,17 @@ pub enum CompactionError {
     DuplicateInput,
 }
 
+impl CompactionError {
+    /// Returns the H3 error code matching this error.
+    #[must_use]
+    pub const fn code(&self) -> ErrorCode {
+        match *self {
+            Self::HeterogeneousResolution => ErrorCode::ResMismatch,
+            Self::DuplicateInput => ErrorCode::DuplicateInput,
+        }
+    }
+}
+
 impl fmt::Display for CompactionError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
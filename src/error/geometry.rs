@@ -1,15 +1,35 @@
+use super::ErrorCode;
 use std::{error::Error, fmt};
 
 /// Errors related to the geometries.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
 pub struct InvalidGeometry {
+    kind: InvalidGeometryKind,
     reason: &'static str,
 }
 
 impl InvalidGeometry {
-    /// Initializes a new [`InvalidGeometry`] with the given error message.
-    pub(crate) const fn new(reason: &'static str) -> Self {
-        Self { reason }
+    /// Initializes a new [`InvalidGeometry`] with the given kind and error
+    /// message.
+    pub(crate) const fn new(
+        kind: InvalidGeometryKind,
+        reason: &'static str,
+    ) -> Self {
+        Self { kind, reason }
+    }
+
+    /// Returns the kind of error, to tell apart the failures that share the
+    /// same H3 error code.
+    #[must_use]
+    pub const fn kind(&self) -> InvalidGeometryKind {
+        self.kind
+    }
+
+    /// Returns the H3 error code matching this error.
+    #[must_use]
+    pub const fn code(&self) -> ErrorCode {
+        ErrorCode::Domain
     }
 }
 
@@ -24,3 +44,22 @@ impl Error for InvalidGeometry {
         None
     }
 }
+
+/// The cause of an [`InvalidGeometry`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum InvalidGeometryKind {
+    /// A coordinate is invalid (e.g. infinite or out of range).
+    Coordinate,
+    /// Not enough coordinates to make up the geometry (e.g. a single point
+    /// polyline).
+    TooFewCoordinates,
+    /// The encoded geometry is malformed (e.g. a truncated polyline).
+    Encoding,
+    /// The geometry isn't of the expected type (e.g. a `GeoJSON` feature
+    /// without geometry).
+    Type,
+    /// A parameter of the geometry is out of range (e.g. a viewport zoom
+    /// level).
+    Parameter,
+}
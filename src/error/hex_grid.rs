@@ -1,8 +1,10 @@
+use super::ErrorCode;
 use std::{error::Error, fmt};
 
 /// Errors related to the `IJK` coordinate system and its variants (e.g.
 /// [`LocalIJ`](crate::LocalIJ)).
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
 pub struct HexGridError {
     reason: &'static str,
 }
@@ -12,6 +14,12 @@ impl HexGridError {
     pub(crate) const fn new(reason: &'static str) -> Self {
         Self { reason }
     }
+
+    /// Returns the H3 error code matching this error.
+    #[must_use]
+    pub const fn code(&self) -> ErrorCode {
+        ErrorCode::Failed
+    }
 }
 
 impl fmt::Display for HexGridError {
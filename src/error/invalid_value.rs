@@ -1,12 +1,14 @@
+use super::ErrorCode;
 use std::{error::Error, fmt};
 
 // Macro to declare type-specific InvalidValue error type.
 macro_rules! invalid_value_error {
-    ($name:literal, $error:ident, $value_type:ty) => {
+    ($name:literal, $error:ident, $value_type:ty, $code:ident) => {
         #[doc = concat!("Invalid ", $name, ".")]
         #[derive(Clone, Copy, Debug, PartialEq)]
         // Value type may not be `Eq` (e.g. f64).
         #[allow(clippy::derive_partial_eq_without_eq)]
+        #[non_exhaustive]
         pub struct $error {
             /// The invalid value.
             pub value: $value_type,
@@ -21,6 +23,12 @@ macro_rules! invalid_value_error {
             ) -> Self {
                 Self { value, reason }
             }
+
+            /// Returns the H3 error code matching this error.
+            #[must_use]
+            pub const fn code(&self) -> ErrorCode {
+                ErrorCode::$code
+            }
         }
 
         impl fmt::Display for $error {
@@ -41,17 +49,31 @@ macro_rules! invalid_value_error {
     };
 }
 
-invalid_value_error!("resolution", InvalidResolution, Option<u8>);
-invalid_value_error!("cell index", InvalidCellIndex, Option<u64>);
-invalid_value_error!("vertex index", InvalidVertexIndex, Option<u64>);
+invalid_value_error!("resolution", InvalidResolution, Option<u8>, ResDomain);
+invalid_value_error!("cell index", InvalidCellIndex, Option<u64>, CellInvalid);
+invalid_value_error!(
+    "vertex index",
+    InvalidVertexIndex,
+    Option<u64>,
+    VertexInvalid
+);
 invalid_value_error!(
     "directed edge index",
     InvalidDirectedEdgeIndex,
-    Option<u64>
+    Option<u64>,
+    DirEdgeInvalid
+);
+invalid_value_error!(
+    "undirected edge index",
+    InvalidUndirectedEdgeIndex,
+    Option<u64>,
+    UndirEdgeInvalid
 );
-invalid_value_error!("latitude/longitude", InvalidLatLng, f64);
-invalid_value_error!("cell edge", InvalidEdge, u8);
-invalid_value_error!("cell vertex", InvalidVertex, u8);
-invalid_value_error!("icosahedron face", InvalidFace, u8);
-invalid_value_error!("base cell", InvalidBaseCell, u8);
-invalid_value_error!("direction", InvalidDirection, u8);
+invalid_value_error!("latitude/longitude", InvalidLatLng, f64, LatLngDomain);
+invalid_value_error!("cell edge", InvalidEdge, u8, Domain);
+invalid_value_error!("cell vertex", InvalidVertex, u8, Domain);
+invalid_value_error!("icosahedron face", InvalidFace, u8, Domain);
+invalid_value_error!("base cell", InvalidBaseCell, u8, BaseCellDomain);
+invalid_value_error!("direction", InvalidDirection, u8, DigitDomain);
+invalid_value_error!("error code", InvalidErrorCode, u32, Domain);
+invalid_value_error!("graticule step", InvalidGraticuleStep, f64, Domain);
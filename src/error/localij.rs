@@ -1,26 +1,103 @@
-use super::HexGridError;
+use super::{ErrorCode, HexGridError};
+use crate::BaseCell;
 use std::{error::Error, fmt};
 
 /// Errors occurring during [`LocalIJ`](crate::LocalIJ) coordinate system
 /// conversions.
+///
+/// When possible, the error describes where the failure occurred, which can be
+/// used to pick a better anchor.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[non_exhaustive]
+#[allow(variant_size_differences)] // Not worth boxing the error message.
 pub enum LocalIjError {
     /// Local origin and cell index have incompatible resolutions.
     ResolutionMismatch,
     /// Pentagon distortion was encountered and could not be handled.
-    Pentagon,
+    Pentagon {
+        /// Base cell of the anchor.
+        anchor: BaseCell,
+        /// Base cell of the pentagon causing the distortion.
+        pentagon: BaseCell,
+    },
+    /// The target is too far from the anchor (i.e. it's neither on the anchor
+    /// base cell nor on one of its neighbors).
+    TooFar {
+        /// Base cell of the anchor.
+        anchor: BaseCell,
+        /// Base cell of the target, if known.
+        target: Option<BaseCell>,
+    },
+    /// The coordinates fall where the coordinate spaces of the anchor base
+    /// cell and of one of its neighbors overlap, and don't unambiguously
+    /// designate a cell.
+    ///
+    /// Only reported by [`LocalIJ::to_cell_strict`](crate::LocalIJ::to_cell_strict).
+    BaseCellOverlap {
+        /// Base cell of the anchor.
+        anchor: BaseCell,
+        /// Neighboring base cell the coordinates were resolved into.
+        neighbor: BaseCell,
+    },
     /// Error related to the `IJK` coordinate system.
     HexGrid(HexGridError),
 }
 
+impl LocalIjError {
+    /// Initializes a new pentagon distortion error.
+    pub(crate) const fn pentagon(anchor: BaseCell, pentagon: BaseCell) -> Self {
+        Self::Pentagon { anchor, pentagon }
+    }
+
+    /// Initializes a new error for a target too far from the anchor.
+    pub(crate) const fn too_far(
+        anchor: BaseCell,
+        target: Option<BaseCell>,
+    ) -> Self {
+        Self::TooFar { anchor, target }
+    }
+
+    /// Initializes a new error for coordinates in an overlapping area.
+    pub(crate) const fn base_cell_overlap(
+        anchor: BaseCell,
+        neighbor: BaseCell,
+    ) -> Self {
+        Self::BaseCellOverlap { anchor, neighbor }
+    }
+
+    /// Returns the H3 error code matching this error.
+    #[must_use]
+    pub const fn code(&self) -> ErrorCode {
+        match *self {
+            Self::ResolutionMismatch => ErrorCode::ResMismatch,
+            Self::Pentagon { .. } => ErrorCode::Pentagon,
+            Self::TooFar { .. } | Self::BaseCellOverlap { .. } => {
+                ErrorCode::Failed
+            }
+            Self::HexGrid(ref err) => err.code(),
+        }
+    }
+}
+
 impl fmt::Display for LocalIjError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
             Self::ResolutionMismatch => {
                 write!(f, "resolution mismatch")
             }
-            Self::Pentagon => write!(f, "pentagon distortion"),
+            Self::Pentagon { anchor, pentagon } => write!(
+                f,
+                "pentagon distortion (pentagon {pentagon}, anchor {anchor})"
+            ),
+            Self::TooFar { anchor, target } => {
+                write!(f, "too far from anchor {anchor}")?;
+                target.map_or(Ok(()), |target| write!(f, " (target {target})"))
+            }
+            Self::BaseCellOverlap { anchor, neighbor } => write!(
+                f,
+                "ambiguous coordinates between base cells {anchor} and \
+                 {neighbor}"
+            ),
             Self::HexGrid(err) => write!(f, "hex grid error: {err}"),
         }
     }
@@ -29,7 +106,10 @@ impl fmt::Display for LocalIjError {
 impl Error for LocalIjError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
-            Self::ResolutionMismatch | Self::Pentagon => None,
+            Self::ResolutionMismatch
+            | Self::Pentagon { .. }
+            | Self::TooFar { .. }
+            | Self::BaseCellOverlap { .. } => None,
             Self::HexGrid(ref err) => Some(err),
         }
     }
@@ -1,5 +1,6 @@
 //! H3O error types.
 
+mod code;
 mod compaction;
 mod hex_grid;
 mod invalid_value;
@@ -14,17 +15,19 @@ mod outliner;
 #[cfg(test)]
 mod tests;
 
+pub use code::ErrorCode;
 pub use compaction::CompactionError;
 pub use hex_grid::HexGridError;
 pub use invalid_value::{
     InvalidBaseCell, InvalidCellIndex, InvalidDirectedEdgeIndex,
-    InvalidDirection, InvalidEdge, InvalidFace, InvalidLatLng,
-    InvalidResolution, InvalidVertex, InvalidVertexIndex,
+    InvalidDirection, InvalidEdge, InvalidErrorCode, InvalidFace,
+    InvalidGraticuleStep, InvalidLatLng, InvalidResolution,
+    InvalidUndirectedEdgeIndex, InvalidVertex, InvalidVertexIndex,
 };
 pub use localij::LocalIjError;
 pub use resolution_mismatch::ResolutionMismatch;
 
 #[cfg(feature = "geo")]
-pub use geometry::InvalidGeometry;
+pub use geometry::{InvalidGeometry, InvalidGeometryKind};
 #[cfg(feature = "geo")]
 pub use outliner::OutlinerError;
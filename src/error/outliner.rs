@@ -1,3 +1,4 @@
+use super::ErrorCode;
 use std::{error::Error, fmt};
 
 /// Errors occurring during the outline computation of a set of cell indices.
@@ -10,6 +11,17 @@ pub enum OutlinerError {
     DuplicateInput,
 }
 
+impl OutlinerError {
+    /// Returns the H3 error code matching this error.
+    #[must_use]
+    pub const fn code(&self) -> ErrorCode {
+        match *self {
+            Self::HeterogeneousResolution => ErrorCode::ResMismatch,
+            Self::DuplicateInput => ErrorCode::DuplicateInput,
+        }
+    }
+}
+
 impl fmt::Display for OutlinerError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
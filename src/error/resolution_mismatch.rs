@@ -1,9 +1,19 @@
+use super::ErrorCode;
 use std::{error::Error, fmt};
 
 /// Resolution mismatch between two cell indexes.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
 pub struct ResolutionMismatch;
 
+impl ResolutionMismatch {
+    /// Returns the H3 error code matching this error.
+    #[must_use]
+    pub const fn code(&self) -> ErrorCode {
+        ErrorCode::ResMismatch
+    }
+}
+
 impl fmt::Display for ResolutionMismatch {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "resolution mismatch")
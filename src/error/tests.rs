@@ -1,17 +1,20 @@
 use crate::error::{
-    CompactionError, HexGridError, InvalidBaseCell, InvalidCellIndex,
-    InvalidDirectedEdgeIndex, InvalidDirection, InvalidEdge, InvalidFace,
-    InvalidLatLng, InvalidResolution, InvalidVertex, InvalidVertexIndex,
-    LocalIjError, ResolutionMismatch,
+    CompactionError, ErrorCode, HexGridError, InvalidBaseCell,
+    InvalidCellIndex, InvalidDirectedEdgeIndex, InvalidDirection, InvalidEdge,
+    InvalidErrorCode, InvalidFace, InvalidGraticuleStep, InvalidLatLng,
+    InvalidResolution, InvalidUndirectedEdgeIndex, InvalidVertex,
+    InvalidVertexIndex, LocalIjError, ResolutionMismatch,
 };
 #[cfg(feature = "geo")]
-use crate::error::{InvalidGeometry, OutlinerError};
+use crate::error::{InvalidGeometry, InvalidGeometryKind, OutlinerError};
+use crate::BaseCell;
 use std::error::Error;
 
 // All error must have a non-empty display.
 #[test]
 fn display() {
     let hex_grid_error = HexGridError::new("error");
+    let base_cell = BaseCell::try_from(4).expect("base cell");
 
     assert!(!CompactionError::HeterogeneousResolution
         .to_string()
@@ -29,6 +32,9 @@ fn display() {
     assert!(!InvalidDirectedEdgeIndex::new(Some(0), "error")
         .to_string()
         .is_empty());
+    assert!(!InvalidUndirectedEdgeIndex::new(Some(0), "error")
+        .to_string()
+        .is_empty());
     assert!(!InvalidVertexIndex::new(Some(0), "error")
         .to_string()
         .is_empty());
@@ -38,15 +44,51 @@ fn display() {
     assert!(!InvalidFace::new(33, "error").to_string().is_empty());
     assert!(!InvalidBaseCell::new(128, "error").to_string().is_empty());
     assert!(!InvalidDirection::new(9, "error").to_string().is_empty());
+    assert!(!InvalidErrorCode::new(0, "error").to_string().is_empty());
+    assert!(!InvalidGraticuleStep::new(0., "error")
+        .to_string()
+        .is_empty());
 
     assert!(!LocalIjError::ResolutionMismatch.to_string().is_empty());
-    assert!(!LocalIjError::Pentagon.to_string().is_empty());
+    assert!(!LocalIjError::Pentagon {
+        anchor: base_cell,
+        pentagon: base_cell
+    }
+    .to_string()
+    .is_empty());
+    assert!(!LocalIjError::TooFar {
+        anchor: base_cell,
+        target: None
+    }
+    .to_string()
+    .is_empty());
+    assert!(!LocalIjError::TooFar {
+        anchor: base_cell,
+        target: Some(base_cell)
+    }
+    .to_string()
+    .is_empty());
+    assert!(!LocalIjError::BaseCellOverlap {
+        anchor: base_cell,
+        neighbor: base_cell
+    }
+    .to_string()
+    .is_empty());
     assert!(!LocalIjError::HexGrid(hex_grid_error).to_string().is_empty());
 
     assert!(!ResolutionMismatch.to_string().is_empty());
 
+    for code in 1..=19 {
+        let code = ErrorCode::try_from(code).expect("error code");
+        assert!(!code.to_string().is_empty());
+    }
+
     #[cfg(feature = "geo")]
-    assert!(!InvalidGeometry::new("error").to_string().is_empty());
+    assert!(
+        !InvalidGeometry::new(InvalidGeometryKind::Coordinate, "error")
+            .to_string()
+            .is_empty()
+    );
 
     #[cfg(feature = "geo")]
     assert!(!OutlinerError::HeterogeneousResolution
@@ -60,6 +102,7 @@ fn display() {
 #[test]
 fn source() {
     let hex_grid_error = HexGridError::new("error");
+    let base_cell = BaseCell::try_from(4).expect("base cell");
 
     assert!(CompactionError::HeterogeneousResolution.source().is_none());
     assert!(CompactionError::DuplicateInput.source().is_none());
@@ -71,6 +114,9 @@ fn source() {
     assert!(InvalidDirectedEdgeIndex::new(Some(0), "error")
         .source()
         .is_none());
+    assert!(InvalidUndirectedEdgeIndex::new(Some(0), "error")
+        .source()
+        .is_none());
     assert!(InvalidVertexIndex::new(Some(0), "error").source().is_none());
     assert!(InvalidLatLng::new(f64::NAN, "error").source().is_none());
     assert!(InvalidEdge::new(7, "error").source().is_none());
@@ -78,18 +124,138 @@ fn source() {
     assert!(InvalidFace::new(33, "error").source().is_none());
     assert!(InvalidBaseCell::new(128, "error").source().is_none());
     assert!(InvalidDirection::new(9, "error").source().is_none());
+    assert!(InvalidErrorCode::new(0, "error").source().is_none());
+    assert!(InvalidGraticuleStep::new(0., "error").source().is_none());
 
     assert!(LocalIjError::ResolutionMismatch.source().is_none());
-    assert!(LocalIjError::Pentagon.source().is_none());
-    assert!(LocalIjError::HexGrid(hex_grid_error).source().is_some());
+    assert!(LocalIjError::Pentagon {
+        anchor: base_cell,
+        pentagon: base_cell
+    }
+    .source()
+    .is_none());
+    assert!(LocalIjError::TooFar {
+        anchor: base_cell,
+        target: None
+    }
+    .source()
+    .is_none());
+    assert!(LocalIjError::BaseCellOverlap {
+        anchor: base_cell,
+        neighbor: base_cell
+    }
+    .source()
+    .is_none());
+    assert_eq!(
+        LocalIjError::HexGrid(hex_grid_error)
+            .source()
+            .and_then(|err| err.downcast_ref::<HexGridError>()),
+        Some(&hex_grid_error)
+    );
 
     assert!(ResolutionMismatch.source().is_none());
 
+    assert!(ErrorCode::Pentagon.source().is_none());
+
     #[cfg(feature = "geo")]
-    assert!(InvalidGeometry::new("error").source().is_none());
+    assert!(
+        InvalidGeometry::new(InvalidGeometryKind::Coordinate, "error")
+            .source()
+            .is_none()
+    );
 
     #[cfg(feature = "geo")]
     assert!(OutlinerError::HeterogeneousResolution.source().is_none());
     #[cfg(feature = "geo")]
     assert!(OutlinerError::DuplicateInput.source().is_none());
 }
+
+// Error codes match the values of the reference implementation.
+#[test]
+fn error_code() {
+    let base_cell = BaseCell::try_from(4).expect("base cell");
+
+    for value in 1..=19 {
+        let code = ErrorCode::try_from(value).expect("error code");
+        assert_eq!(u32::from(code), value);
+    }
+    assert!(ErrorCode::try_from(0).is_err(), "success");
+    assert!(ErrorCode::try_from(20).is_err(), "out of range");
+
+    assert_eq!(
+        ErrorCode::from(InvalidCellIndex::new(Some(0), "error")),
+        ErrorCode::CellInvalid
+    );
+    assert_eq!(
+        ErrorCode::from(InvalidResolution::new(Some(32), "error")),
+        ErrorCode::ResDomain
+    );
+    assert_eq!(
+        ErrorCode::from(InvalidUndirectedEdgeIndex::new(Some(0), "error")),
+        ErrorCode::UndirEdgeInvalid
+    );
+    assert_eq!(
+        ErrorCode::from(InvalidLatLng::new(f64::NAN, "error")),
+        ErrorCode::LatLngDomain
+    );
+    assert_eq!(
+        ErrorCode::from(InvalidGraticuleStep::new(f64::NAN, "error")),
+        ErrorCode::Domain
+    );
+    assert_eq!(
+        ErrorCode::from(CompactionError::DuplicateInput),
+        ErrorCode::DuplicateInput
+    );
+    assert_eq!(
+        ErrorCode::from(LocalIjError::Pentagon {
+            anchor: base_cell,
+            pentagon: base_cell
+        }),
+        ErrorCode::Pentagon
+    );
+    assert_eq!(
+        ErrorCode::from(LocalIjError::TooFar {
+            anchor: base_cell,
+            target: None
+        }),
+        ErrorCode::Failed
+    );
+    assert_eq!(
+        ErrorCode::from(LocalIjError::BaseCellOverlap {
+            anchor: base_cell,
+            neighbor: base_cell
+        }),
+        ErrorCode::Failed
+    );
+    assert_eq!(ErrorCode::from(ResolutionMismatch), ErrorCode::ResMismatch);
+}
+
+// Error code accessors agree with the conversions.
+#[test]
+fn code() {
+    let hex_grid_error = HexGridError::new("error");
+    let error = InvalidDirection::new(9, "error");
+    assert_eq!(error.code(), ErrorCode::from(error));
+
+    assert_eq!(hex_grid_error.code(), ErrorCode::Failed);
+    assert_eq!(
+        LocalIjError::HexGrid(hex_grid_error).code(),
+        hex_grid_error.code()
+    );
+    assert_eq!(
+        CompactionError::HeterogeneousResolution.code(),
+        ErrorCode::ResMismatch
+    );
+    assert_eq!(ResolutionMismatch.code(), ErrorCode::ResMismatch);
+
+    #[cfg(feature = "geo")]
+    assert_eq!(
+        InvalidGeometry::new(InvalidGeometryKind::Coordinate, "error").code(),
+        ErrorCode::Domain
+    );
+    #[cfg(feature = "geo")]
+    assert_eq!(
+        OutlinerError::DuplicateInput.code(),
+        ErrorCode::DuplicateInput
+    );
+}
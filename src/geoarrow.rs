@@ -0,0 +1,196 @@
+//! `GeoArrow` columns of cell and edge geometries.
+//!
+//! The arrays follow the `geoarrow.polygon` and `geoarrow.linestring` native
+//! encodings (with interleaved coordinates, in degrees and longitude first):
+//! they can be handed over as-is to an Arrow implementation (e.g. to write
+//! `GeoParquet` files), alongside the index column, without any per-row WKB
+//! encoding.
+//!
+//! They are built from a [`BoundaryBuffer`], which holds the same data in
+//! radians and without closing the rings.
+
+use crate::{BoundaryBuffer, CellIndex, DirectedEdgeIndex, LatLng};
+
+/// Cell boundaries, as a `GeoArrow` polygon array.
+///
+/// Each polygon is made of a single closed ring.
+///
+/// # Example
+///
+/// ```
+/// use h3o::{CellIndex, geoarrow::PolygonArray};
+///
+/// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+/// let polygons = index.grid_disk::<PolygonArray>(1);
+/// assert_eq!(polygons.len(), 7);
+/// assert_eq!(polygons.ring_offsets().len(), 8);
+/// # Ok::<(), h3o::error::InvalidCellIndex>(())
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct PolygonArray {
+    /// Start of the rings of every polygon, followed by the end of the last
+    /// one.
+    geom_offsets: Vec<i32>,
+    /// Start of the coordinates of every ring, followed by the end of the
+    /// last one.
+    ring_offsets: Vec<i32>,
+    /// Interleaved coordinates.
+    coords: Vec<f64>,
+}
+
+impl PolygonArray {
+    /// Encodes cell boundaries as polygons.
+    ///
+    /// # Panics
+    ///
+    /// If there are more coordinates than fit in 32-bit offsets.
+    #[must_use]
+    pub fn from_boundaries(boundaries: &BoundaryBuffer) -> Self {
+        let mut coords = Vec::with_capacity(
+            (boundaries.points().len() + boundaries.len()) * 2,
+        );
+        let mut ring_offsets = Vec::with_capacity(boundaries.len() + 1);
+        ring_offsets.push(0);
+        for boundary in boundaries.iter() {
+            push_coords(&mut coords, boundary);
+            // Rings must be closed.
+            push_coords(&mut coords, &boundary[..1]);
+            ring_offsets.push(offset(coords.len() / 2));
+        }
+        // One ring per polygon.
+        let geom_offsets = (0..=boundaries.len()).map(offset).collect();
+
+        Self {
+            geom_offsets,
+            ring_offsets,
+            coords,
+        }
+    }
+
+    /// Returns the number of polygons.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.geom_offsets.len() - 1
+    }
+
+    /// Returns true if the array contains no polygon.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the offsets delimiting the rings of every polygon.
+    ///
+    /// Starts with 0, and contains one more item than there are polygons.
+    #[must_use]
+    pub fn geom_offsets(&self) -> &[i32] {
+        &self.geom_offsets
+    }
+
+    /// Returns the offsets delimiting the coordinates of every ring.
+    ///
+    /// Starts with 0, and contains one more item than there are rings.
+    #[must_use]
+    pub fn ring_offsets(&self) -> &[i32] {
+        &self.ring_offsets
+    }
+
+    /// Returns the interleaved coordinates (`[x0, y0, x1, y1, …]`).
+    #[must_use]
+    pub fn coords(&self) -> &[f64] {
+        &self.coords
+    }
+}
+
+impl FromIterator<CellIndex> for PolygonArray {
+    fn from_iter<T: IntoIterator<Item = CellIndex>>(iter: T) -> Self {
+        Self::from_boundaries(&iter.into_iter().collect())
+    }
+}
+
+// -----------------------------------------------------------------------------
+
+/// Directed edges, as a `GeoArrow` linestring array.
+///
+/// # Example
+///
+/// ```
+/// use h3o::{CellIndex, geoarrow::LineStringArray};
+///
+/// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+/// let lines = index.edges().collect::<LineStringArray>();
+/// assert_eq!(lines.len(), 6);
+/// # Ok::<(), h3o::error::InvalidCellIndex>(())
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct LineStringArray {
+    /// Start of the coordinates of every linestring, followed by the end of
+    /// the last one.
+    geom_offsets: Vec<i32>,
+    /// Interleaved coordinates.
+    coords: Vec<f64>,
+}
+
+impl LineStringArray {
+    /// Encodes edge boundaries as linestrings.
+    ///
+    /// # Panics
+    ///
+    /// If there are more coordinates than fit in 32-bit offsets.
+    #[must_use]
+    pub fn from_boundaries(boundaries: &BoundaryBuffer) -> Self {
+        let mut coords = Vec::with_capacity(boundaries.points().len() * 2);
+        push_coords(&mut coords, boundaries.points());
+        let geom_offsets =
+            boundaries.offsets().iter().copied().map(offset).collect();
+
+        Self {
+            geom_offsets,
+            coords,
+        }
+    }
+
+    /// Returns the number of linestrings.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.geom_offsets.len() - 1
+    }
+
+    /// Returns true if the array contains no linestring.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the offsets delimiting the coordinates of every linestring.
+    ///
+    /// Starts with 0, and contains one more item than there are linestrings.
+    #[must_use]
+    pub fn geom_offsets(&self) -> &[i32] {
+        &self.geom_offsets
+    }
+
+    /// Returns the interleaved coordinates (`[x0, y0, x1, y1, …]`).
+    #[must_use]
+    pub fn coords(&self) -> &[f64] {
+        &self.coords
+    }
+}
+
+impl FromIterator<DirectedEdgeIndex> for LineStringArray {
+    fn from_iter<T: IntoIterator<Item = DirectedEdgeIndex>>(iter: T) -> Self {
+        Self::from_boundaries(&iter.into_iter().collect())
+    }
+}
+
+// -----------------------------------------------------------------------------
+
+/// Appends points as interleaved coordinates, in degrees.
+fn push_coords(coords: &mut Vec<f64>, points: &[LatLng]) {
+    coords.extend(points.iter().flat_map(|ll| [ll.lng(), ll.lat()]));
+}
+
+/// Converts a length into an Arrow offset.
+fn offset(value: usize) -> i32 {
+    i32::try_from(value).expect("offset overflow")
+}
@@ -1,4 +1,8 @@
-use crate::{error::InvalidGeometry, LatLng, Resolution};
+use crate::{
+    error::{InvalidGeometry, InvalidGeometryKind},
+    math::mul_add,
+    LatLng, Resolution,
+};
 use geo::{coord, LineString, Rect};
 use std::f64::consts::PI;
 
@@ -14,6 +18,7 @@ pub fn compute_from_ring(
     // Closed ring have at least 4 coordinate (e.g. triangle).
     if ring.0.len() < 4 {
         return Err(InvalidGeometry::new(
+            InvalidGeometryKind::TooFewCoordinates,
             "invalid ring (not enough coordinate)",
         ));
     }
@@ -29,12 +34,16 @@ pub fn compute_from_ring(
     for curr in ring {
         if !super::coord_is_valid(*curr) {
             return Err(InvalidGeometry::new(
+                InvalidGeometryKind::Coordinate,
                 "invalid coordinate (e.g. infinite)",
             ));
         }
 
-        let lng = (f64::from(u8::from(is_transmeridian && curr.x < 0.)) * 2.)
-            .mul_add(PI, curr.x);
+        let lng = mul_add(
+            f64::from(u8::from(is_transmeridian && curr.x < 0.)) * 2.,
+            PI,
+            curr.x,
+        );
         lng_range = get_min_max(lng, lng_range.0, lng_range.1);
         lat_range = get_min_max(curr.y, lat_range.0, lat_range.1);
     }
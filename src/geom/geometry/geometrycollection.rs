@@ -1,5 +1,9 @@
 use super::Geometry;
-use crate::{error::InvalidGeometry, geom::ToCells, CellIndex, Resolution};
+use crate::{
+    error::InvalidGeometry,
+    geom::{merge_cells_at, ToCells},
+    CellIndex, Resolution,
+};
 use std::boxed::Box;
 
 /// A collection of [`geo::Geometry`].
@@ -66,6 +70,11 @@ impl<'a> GeometryCollection<'a> {
                 .collect::<Result<Vec<_>, _>>()?,
         ))
     }
+
+    /// Returns the geometries of the collection.
+    pub(super) fn into_geometries(self) -> Vec<Geometry<'a>> {
+        self.0
+    }
 }
 
 impl From<GeometryCollection<'_>> for geo::GeometryCollection<f64> {
@@ -92,4 +101,13 @@ impl ToCells for GeometryCollection<'_> {
                 .flat_map(move |geometry| geometry.to_cells(resolution)),
         )
     }
+
+    fn to_cells_at(&self, resolutions: &[Resolution]) -> Vec<Vec<CellIndex>> {
+        merge_cells_at(
+            self.0
+                .iter()
+                .map(|geometry| geometry.to_cells_at(resolutions)),
+            resolutions.len(),
+        )
+    }
 }
@@ -1,5 +1,7 @@
 use crate::{
-    error::InvalidGeometry, geom::ToCells, CellIndex, LatLng, Resolution,
+    error::{InvalidGeometry, InvalidGeometryKind},
+    geom::ToCells,
+    CellIndex, LatLng, Resolution,
 };
 use geo::Coord;
 use std::boxed::Box;
@@ -74,7 +76,10 @@ impl Line {
         if !super::coord_is_valid(line.start)
             || !super::coord_is_valid(line.end)
         {
-            return Err(InvalidGeometry::new("start and end must be valid"));
+            return Err(InvalidGeometry::new(
+                InvalidGeometryKind::Coordinate,
+                "start and end must be valid",
+            ));
         }
         Ok(())
     }
@@ -1,5 +1,10 @@
 use super::line;
-use crate::{error::InvalidGeometry, geom::ToCells, CellIndex, Resolution};
+use crate::{
+    error::{InvalidGeometry, InvalidGeometryKind},
+    geom::ToCells,
+    grid, CellIndex, LatLng, Resolution, EARTH_RADIUS_KM,
+};
+use ahash::{HashMap, HashMapExt};
 use std::{borrow::Cow, boxed::Box};
 
 /// An ordered collection of two or more [`geo::Coord`]s, representing a
@@ -66,12 +71,58 @@ impl<'a> LineString<'a> {
         Self::check_coords(&line).map(|_| Self(Cow::Owned(line)))
     }
 
+    /// Computes the length, in meters, of the line string that lies within
+    /// each cell it crosses at the specified resolution.
+    ///
+    /// Unlike the `ToCells` implementation, which works in grid space, the
+    /// segments are traced as great circle arcs.
+    ///
+    /// Cells are returned in the order they are first crossed, and each cell
+    /// appears only once (lengths are summed if the line string comes back to
+    /// an already crossed cell).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{geom::LineString, Resolution};
+    ///
+    /// let line_string = geo::LineString::new(vec![
+    ///     geo::coord! { x: -0.5458558636632915, y: 47.47088771408784 },
+    ///     geo::coord! { x: 2.373611818843102,   y: 48.84548389122412 },
+    /// ]);
+    /// let line = LineString::from_degrees(line_string)?;
+    /// let lengths = line.cells_length_m(Resolution::Five).collect::<Vec<_>>();
+    /// # Ok::<(), h3o::error::InvalidGeometry>(())
+    /// ```
+    pub fn cells_length_m(
+        &self,
+        resolution: Resolution,
+    ) -> impl Iterator<Item = (CellIndex, f64)> {
+        let mut positions = HashMap::new();
+        let mut lengths = Vec::<(CellIndex, f64)>::new();
+
+        for line in self.0.lines() {
+            let (start, end) = line_endpoints(&line);
+            for (cell, length) in grid::arc_cells(start, end, resolution) {
+                let length = length * EARTH_RADIUS_KM * 1000.;
+                let position = *positions.entry(cell).or_insert_with(|| {
+                    lengths.push((cell, 0.));
+                    lengths.len() - 1
+                });
+                lengths[position].1 += length;
+            }
+        }
+
+        lengths.into_iter()
+    }
+
     // Check that the line's coordinates are finite.
     fn check_coords(
         line: &geo::LineString<f64>,
     ) -> Result<(), InvalidGeometry> {
         if !line.coords().all(|coord| super::coord_is_valid(*coord)) {
             return Err(InvalidGeometry::new(
+                InvalidGeometryKind::Coordinate,
                 "every coordinate of the line must be valid",
             ));
         }
@@ -104,3 +155,15 @@ impl ToCells for LineString<'_> {
         )
     }
 }
+
+// ----------------------------------------------------------------------------
+
+// Returns the coordinates of the start and end of the line.
+fn line_endpoints(line: &geo::Line<f64>) -> (LatLng, LatLng) {
+    // Expect valid coordinates, checked by `check_coords` before.
+    let start =
+        LatLng::from_radians(line.start.y, line.start.x).expect("valid start");
+    let end = LatLng::from_radians(line.end.y, line.end.x).expect("valid end");
+
+    (start, end)
+}
@@ -10,8 +10,11 @@ mod linestring;
 mod multilinestring;
 mod multipoint;
 mod multipolygon;
+mod partition;
 mod point;
 mod polygon;
+#[cfg(feature = "polyline")]
+mod polyline;
 mod rect;
 mod ring;
 mod triangle;
@@ -24,8 +27,11 @@ pub use linestring::LineString;
 pub use multilinestring::MultiLineString;
 pub use multipoint::MultiPoint;
 pub use multipolygon::MultiPolygon;
+pub use partition::Partition;
 pub use point::Point;
-pub use polygon::Polygon;
+pub use polygon::{is_polar, CellRelation, ContainmentMode, Polygon};
+#[cfg(feature = "polyline")]
+pub use polyline::{Polyline, Precision};
 pub use rect::Rect;
 pub use triangle::Triangle;
 
@@ -57,7 +63,7 @@ pub enum Geometry<'a> {
     /// [`geo::Coord`]s.
     Rect(Rect<'a>),
     /// A bounded area represented by three [`geo::Coord`] vertices.
-    Triangle(Triangle<'a>),
+    Triangle(Triangle),
 }
 
 impl<'a> Geometry<'a> {
@@ -164,6 +170,49 @@ impl<'a> Geometry<'a> {
             }
         })
     }
+
+    /// Splits the geometry into partitions, one per cell of the given
+    /// resolution it overlaps (e.g. [`Resolution::Zero`] for a split by base
+    /// cell).
+    ///
+    /// Polygons are clipped to the area covered by the descendants of each
+    /// cell (the pieces can be retrieved by converting a partition into a
+    /// [`geo::GeometryCollection`], in radians), and the coverage of a
+    /// partition is restricted to those descendants, thus every cell of the
+    /// coverage belongs to exactly one partition: the partitions can be
+    /// converted to cells independently and their coverages concatenated,
+    /// giving the same cells as [`ToCells::to_cells`] on the whole geometry.
+    ///
+    /// The partitions are sorted by cell, and some of them may have an empty
+    /// coverage.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geo::polygon;
+    /// use h3o::{Resolution, geom::{Geometry, Polygon, ToCells}};
+    ///
+    /// let p: geo::Polygon<f64> = polygon![
+    ///     (x: 37.58601939796671, y: 55.72992682544245),
+    ///     (x: 37.66530173673016, y: 55.72992682544245),
+    ///     (x: 37.66530173673016, y: 55.777641325418415),
+    ///     (x: 37.58601939796671, y: 55.777641325418415),
+    ///     (x: 37.58601939796671, y: 55.72992682544245),
+    /// ];
+    /// let geometry = Geometry::Polygon(Polygon::from_degrees(p)?);
+    /// let cells = geometry
+    ///     .partition(Resolution::Five)
+    ///     .iter()
+    ///     .flat_map(|partition| {
+    ///         partition.to_cells(Resolution::Nine).collect::<Vec<_>>()
+    ///     })
+    ///     .collect::<Vec<_>>();
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn partition(&self, resolution: Resolution) -> Vec<Partition<'a>> {
+        Partition::split(self.clone(), resolution)
+    }
 }
 
 impl From<Geometry<'_>> for geo::Geometry<f64> {
@@ -242,6 +291,25 @@ impl ToCells for Geometry<'_> {
             }
         }
     }
+
+    fn to_cells_at(&self, resolutions: &[Resolution]) -> Vec<Vec<CellIndex>> {
+        match *self {
+            Self::Point(ref point) => point.to_cells_at(resolutions),
+            Self::Line(ref line) => line.to_cells_at(resolutions),
+            Self::LineString(ref line) => line.to_cells_at(resolutions),
+            Self::Polygon(ref polygon) => polygon.to_cells_at(resolutions),
+            Self::MultiPoint(ref points) => points.to_cells_at(resolutions),
+            Self::MultiLineString(ref lines) => lines.to_cells_at(resolutions),
+            Self::MultiPolygon(ref polygons) => {
+                polygons.to_cells_at(resolutions)
+            }
+            Self::GeometryCollection(ref geometries) => {
+                geometries.to_cells_at(resolutions)
+            }
+            Self::Rect(ref rect) => rect.to_cells_at(resolutions),
+            Self::Triangle(ref triangle) => triangle.to_cells_at(resolutions),
+        }
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -70,6 +70,11 @@ impl MultiPoint {
                 .collect::<Result<Vec<_>, _>>()?,
         ))
     }
+
+    /// Returns the points of the collection.
+    pub(super) fn into_points(self) -> Vec<Point> {
+        self.0
+    }
 }
 
 impl From<MultiPoint> for geo::MultiPoint<f64> {
@@ -1,5 +1,9 @@
 use super::Polygon;
-use crate::{error::InvalidGeometry, geom::ToCells, CellIndex, Resolution};
+use crate::{
+    error::InvalidGeometry,
+    geom::{merge_cells_at, ToCells},
+    CellIndex, Resolution,
+};
 use std::boxed::Box;
 
 /// A collection of [`geo::Polygon`].
@@ -78,6 +82,11 @@ impl<'a> MultiPolygon<'a> {
                 .collect::<Result<Vec<_>, _>>()?,
         ))
     }
+
+    /// Returns the polygons of the collection.
+    pub(super) fn into_polygons(self) -> Vec<Polygon<'a>> {
+        self.0
+    }
 }
 
 impl From<MultiPolygon<'_>> for geo::MultiPolygon<f64> {
@@ -104,4 +113,13 @@ impl ToCells for MultiPolygon<'_> {
                 .flat_map(move |polygon| polygon.to_cells(resolution)),
         )
     }
+
+    fn to_cells_at(&self, resolutions: &[Resolution]) -> Vec<Vec<CellIndex>> {
+        merge_cells_at(
+            self.0
+                .iter()
+                .map(|polygon| polygon.to_cells_at(resolutions)),
+            resolutions.len(),
+        )
+    }
 }
@@ -0,0 +1,213 @@
+use super::{
+    line,
+    polygon::{self, CellRelation, ContainmentMode},
+    Geometry, Point, Polygon,
+};
+use crate::{geom::ToCells, CellIndex, LatLng, Resolution};
+use std::{
+    boxed::Box,
+    collections::{BTreeMap, BTreeSet},
+};
+
+/// A piece of a geometry, restricted to the descendants of a coarse cell.
+///
+/// Partitions are built by [`Geometry::partition`]: the coverage of a
+/// partition only contains descendants of its cell, thus partitions can be
+/// processed independently (e.g. on different workers) and their coverages
+/// concatenated without introducing duplicates.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Partition<'a> {
+    cell: CellIndex,
+    /// Polygons, clipped to the area covered by the descendants of the cell.
+    polygons: Vec<Polygon<'a>>,
+    /// Points located in the cell.
+    points: Vec<Point>,
+    /// Line segments whose coverage may reach the cell.
+    lines: Vec<geo::Line<f64>>,
+}
+
+impl<'a> Partition<'a> {
+    /// Returns the cell whose descendants make up the partition.
+    #[must_use]
+    pub const fn cell(&self) -> CellIndex {
+        self.cell
+    }
+
+    /// Initializes an empty partition.
+    const fn new(cell: CellIndex) -> Self {
+        Self {
+            cell,
+            polygons: Vec::new(),
+            points: Vec::new(),
+            lines: Vec::new(),
+        }
+    }
+
+    /// Splits a geometry into the partitions of the given resolution.
+    pub(super) fn split(
+        geometry: Geometry<'a>,
+        resolution: Resolution,
+    ) -> Vec<Self> {
+        let mut polygons = Vec::new();
+        let mut points = Vec::new();
+        let mut lines = Vec::new();
+        flatten(geometry, &mut polygons, &mut points, &mut lines);
+
+        let mut partitions = BTreeMap::new();
+        for polygon in &polygons {
+            let roots = polygon::traverse(
+                CellIndex::base_cells(),
+                resolution,
+                |cell| {
+                    polygon.descendants_relation(cell) != CellRelation::Disjoint
+                },
+                |cell| polygon.descendants_relation(cell),
+            );
+            for cell in roots {
+                if let Some(clipped) = polygon.clip(cell) {
+                    partitions
+                        .entry(cell)
+                        .or_insert_with(|| Self::new(cell))
+                        .polygons
+                        .push(clipped);
+                }
+            }
+        }
+        for point in points {
+            // The cells of a point, at any resolution, have the same parent.
+            let cell = to_latlng(point).to_cell(resolution);
+            partitions
+                .entry(cell)
+                .or_insert_with(|| Self::new(cell))
+                .points
+                .push(point);
+        }
+        for line in lines {
+            // Cells of the coverage may belong to a neighboring parent.
+            let roots = line::to_cells(line, resolution)
+                .flat_map(|cell| cell.grid_disk::<Vec<_>>(1))
+                .collect::<BTreeSet<_>>();
+            for cell in roots {
+                partitions
+                    .entry(cell)
+                    .or_insert_with(|| Self::new(cell))
+                    .lines
+                    .push(line);
+            }
+        }
+
+        partitions.into_values().collect()
+    }
+}
+
+/// The coordinates of the geometries are in radians.
+impl From<Partition<'_>> for geo::GeometryCollection<f64> {
+    fn from(value: Partition<'_>) -> Self {
+        value
+            .polygons
+            .into_iter()
+            .map(|polygon| geo::Geometry::Polygon(polygon.into()))
+            .chain(
+                value
+                    .points
+                    .into_iter()
+                    .map(|point| geo::Geometry::Point(point.into())),
+            )
+            .chain(value.lines.into_iter().map(geo::Geometry::Line))
+            .collect()
+    }
+}
+
+impl ToCells for Partition<'_> {
+    fn max_cells_count(&self, resolution: Resolution) -> usize {
+        self.polygons
+            .iter()
+            .map(|polygon| polygon.max_cells_count(resolution))
+            .chain(
+                self.lines
+                    .iter()
+                    .map(|&line| line::cells_count(line, resolution)),
+            )
+            .sum::<usize>()
+            + self.points.len()
+    }
+
+    /// This implementation only traverses the descendants of the partition
+    /// cell, thus nothing is returned for resolutions coarser than the
+    /// partition one.
+    fn to_cells(
+        &self,
+        resolution: Resolution,
+    ) -> Box<dyn Iterator<Item = CellIndex> + '_> {
+        let root = self.cell;
+        if resolution < root.resolution() {
+            return Box::new(std::iter::empty());
+        }
+
+        Box::new(
+            self.polygons
+                .iter()
+                .flat_map(move |polygon| {
+                    polygon::traverse(
+                        [root],
+                        resolution,
+                        |cell| {
+                            polygon.is_covering(cell, ContainmentMode::Center)
+                        },
+                        |cell| polygon.descendants_relation(cell),
+                    )
+                })
+                .chain(
+                    self.points.iter().map(move |&point| {
+                        to_latlng(point).to_cell(resolution)
+                    }),
+                )
+                .chain(
+                    self.lines
+                        .iter()
+                        .flat_map(move |&line| line::to_cells(line, resolution))
+                        .filter(move |cell| {
+                            cell.parent(root.resolution()) == Some(root)
+                        }),
+                ),
+        )
+    }
+}
+
+// -----------------------------------------------------------------------------
+
+/// Splits a geometry into its polygons, its points and its line segments.
+fn flatten<'a>(
+    geometry: Geometry<'a>,
+    polygons: &mut Vec<Polygon<'a>>,
+    points: &mut Vec<Point>,
+    lines: &mut Vec<geo::Line<f64>>,
+) {
+    match geometry {
+        Geometry::Point(point) => points.push(point),
+        Geometry::Line(line) => lines.push(line.into()),
+        Geometry::LineString(line) => {
+            lines.extend(geo::LineString::from(line).lines());
+        }
+        Geometry::Polygon(polygon) => polygons.push(polygon),
+        Geometry::MultiPoint(multi) => points.extend(multi.into_points()),
+        Geometry::MultiLineString(multi) => lines.extend(
+            geo::MultiLineString::from(multi)
+                .iter()
+                .flat_map(geo::LineString::lines),
+        ),
+        Geometry::MultiPolygon(multi) => polygons.extend(multi.into_polygons()),
+        Geometry::GeometryCollection(geometries) => {
+            for geometry in geometries.into_geometries() {
+                flatten(geometry, polygons, points, lines);
+            }
+        }
+        Geometry::Rect(rect) => polygons.push(rect.into_polygon()),
+        Geometry::Triangle(triangle) => polygons.push(triangle.into_polygon()),
+    }
+}
+
+/// Converts a point into a `LatLng`, the same way as `Point::to_cells`.
+fn to_latlng(point: Point) -> LatLng {
+    LatLng::try_from(point).expect("valid coordinate")
+}
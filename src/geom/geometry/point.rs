@@ -1,5 +1,5 @@
 use crate::{
-    error::{InvalidGeometry, InvalidLatLng},
+    error::{InvalidGeometry, InvalidGeometryKind, InvalidLatLng},
     geom::ToCells,
     CellIndex, LatLng, Resolution,
 };
@@ -59,7 +59,10 @@ impl Point {
     // Check that the point's coordinates are finite.
     fn check_coords(point: &geo::Point<f64>) -> Result<(), InvalidGeometry> {
         if !super::coord_is_valid(point.0) {
-            return Err(InvalidGeometry::new("x and y must be valid"));
+            return Err(InvalidGeometry::new(
+                InvalidGeometryKind::Coordinate,
+                "x and y must be valid",
+            ));
         }
         Ok(())
     }
@@ -1,10 +1,63 @@
 use super::{bbox, Geometry, Ring};
 use crate::{
-    error::InvalidGeometry, geom::ToCells, CellIndex, LatLng, Resolution,
+    error::{InvalidGeometry, InvalidGeometryKind},
+    geom::ToCells,
+    math::mul_add,
+    trace, CellIndex, LatLng, Resolution, TWO_PI,
 };
 use ahash::{HashSet, HashSetExt};
-use geo::{coord, Coord, CoordsIter};
-use std::{borrow::Cow, boxed::Box, cmp, collections::VecDeque};
+use geo::{coord, Contains, Coord, CoordsIter, Intersects};
+use std::{
+    borrow::Cow,
+    boxed::Box,
+    cmp,
+    collections::VecDeque,
+    f64::consts::{FRAC_PI_2, PI},
+};
+
+/// Spatial relationship between a cell and a polygon.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum CellRelation {
+    /// The cell and the polygon have no point in common.
+    Disjoint,
+    /// The cell and the polygon overlap, but the cell isn't fully contained.
+    Intersects,
+    /// The cell is entirely contained within the polygon.
+    Contains,
+}
+
+/// Containment mode used to decide whether a cell is part of a polygon
+/// coverage.
+///
+/// Those are modeled after the flags of H3's `polygonToCellsExperimental`
+/// (whose names are given below), but the results aren't checked against the
+/// reference implementation and may differ on edge cases.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum ContainmentMode {
+    /// Cells whose center is inside the polygon (`CONTAINMENT_CENTER`).
+    #[default]
+    Center,
+    /// Cells entirely inside the polygon (`CONTAINMENT_FULL`).
+    Full,
+    /// Cells overlapping the polygon (`CONTAINMENT_OVERLAPPING`).
+    Overlapping,
+    /// Cells whose bounding box overlaps the polygon
+    /// (`CONTAINMENT_OVERLAPPING_BBOX`).
+    OverlappingBbox,
+}
+
+/// Scale factor applied to the bounding box of a cell to cover its
+/// descendants.
+pub(super) const CHILD_SCALE_FACTOR: f64 = 1.4;
+
+/// Cells, at the finest resolution, containing the north and south poles.
+///
+/// Every coarser polar cell is one of their ancestors.
+const POLAR_CELLS: [u64; 2] = [0x8f0326233ab0399, 0x8ff29380e0d0cc4];
+
+// -----------------------------------------------------------------------------
 
 /// A bounded two-dimensional area.
 #[derive(Clone, Debug, PartialEq)]
@@ -128,6 +181,481 @@ impl<'a> Polygon<'a> {
         })
     }
 
+    /// Initialize a new polygon from the boundary of a cell.
+    pub(crate) fn from_cell(cell: CellIndex) -> Self {
+        let mut ring = cell
+            .boundary()
+            .iter()
+            .map(|ll| coord! { x: ll.lng_radians(), y: ll.lat_radians() })
+            .collect::<geo::LineString<f64>>();
+        ring.close();
+
+        Self {
+            exterior: Ring::from_radians(Cow::Owned(ring))
+                .expect("finite cell boundary"),
+            interiors: Vec::new(),
+        }
+    }
+
+    /// Computes the spatial relationship between the polygon and a cell.
+    ///
+    /// This is much cheaper than a full `to_cells`, and can be used to prune
+    /// traversals of the cell hierarchy.
+    ///
+    /// Note that, like the rest of the polygon algorithms, the edges of both
+    /// the polygon and the cell are considered as straight lines in the
+    /// latitude/longitude space. Polar cells are seen as the area between
+    /// their boundary and the pole.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geo::polygon;
+    /// use h3o::{CellIndex, geom::{CellRelation, Polygon}};
+    ///
+    /// let p: geo::Polygon<f64> = polygon![
+    ///     (x: 37.58601939796671, y: 55.72992682544245),
+    ///     (x: 37.66530173673016, y: 55.72992682544245),
+    ///     (x: 37.66530173673016, y: 55.777641325418415),
+    ///     (x: 37.58601939796671, y: 55.777641325418415),
+    ///     (x: 37.58601939796671, y: 55.72992682544245),
+    /// ];
+    /// let polygon = Polygon::from_degrees(p)?;
+    /// let cell = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// assert_eq!(polygon.relation(cell), CellRelation::Disjoint);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn relation(&self, cell: CellIndex) -> CellRelation {
+        let boundary = cell
+            .boundary()
+            .iter()
+            .map(|ll| coord! { x: ll.lng_radians(), y: ll.lat_radians() })
+            .collect::<Vec<_>>();
+
+        if is_polar(cell) {
+            return self.polar_relation(&boundary);
+        }
+        self.relation_with(&boundary)
+    }
+
+    /// Computes the cells covering the polygon, using the specified
+    /// containment mode.
+    ///
+    /// Unlike [`ToCells::to_cells`], the grid is traversed hierarchically
+    /// (starting from the base cells), which allows to skip or take whole
+    /// branches at coarser resolutions.
+    ///
+    /// The cells are returned in traversal order (i.e. grouped by coarse
+    /// ancestor), which is not guaranteed to be stable across versions.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geo::polygon;
+    /// use h3o::{Resolution, geom::{ContainmentMode, Polygon}};
+    ///
+    /// let p: geo::Polygon<f64> = polygon![
+    ///     (x: 37.58601939796671, y: 55.72992682544245),
+    ///     (x: 37.66530173673016, y: 55.72992682544245),
+    ///     (x: 37.66530173673016, y: 55.777641325418415),
+    ///     (x: 37.58601939796671, y: 55.777641325418415),
+    ///     (x: 37.58601939796671, y: 55.72992682544245),
+    /// ];
+    /// let polygon = Polygon::from_degrees(p)?;
+    /// let cells = polygon
+    ///     .to_cells_with_mode(Resolution::Seven, ContainmentMode::Overlapping)
+    ///     .collect::<Vec<_>>();
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn to_cells_with_mode(
+        &self,
+        resolution: Resolution,
+        mode: ContainmentMode,
+    ) -> impl Iterator<Item = CellIndex> + '_ {
+        trace::polyfill(self.rings().count(), resolution, || {
+            self.cells_with_mode(resolution, mode)
+        })
+    }
+
+    /// Classifies the candidate cells of the polygon coverage, at the given
+    /// resolution, according to their relationship with the polygon.
+    ///
+    /// Interior cells are labeled [`CellRelation::Contains`], boundary cells
+    /// [`CellRelation::Intersects`] and exterior cells
+    /// [`CellRelation::Disjoint`]: interior cells are the ones returned by
+    /// the [`ContainmentMode::Full`] mode, and interior and boundary cells
+    /// together are the ones returned by [`ContainmentMode::Overlapping`].
+    ///
+    /// Like [`Self::to_cells_with_mode`], the grid is traversed
+    /// hierarchically, thus the candidates are the cells of the branches
+    /// that may intersect the polygon: exterior cells are the ones close to
+    /// its boundary (and not the whole world).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geo::polygon;
+    /// use h3o::{Resolution, geom::{CellRelation, Polygon}};
+    ///
+    /// let p: geo::Polygon<f64> = polygon![
+    ///     (x: 37.58601939796671, y: 55.72992682544245),
+    ///     (x: 37.66530173673016, y: 55.72992682544245),
+    ///     (x: 37.66530173673016, y: 55.777641325418415),
+    ///     (x: 37.58601939796671, y: 55.777641325418415),
+    ///     (x: 37.58601939796671, y: 55.72992682544245),
+    /// ];
+    /// let polygon = Polygon::from_degrees(p)?;
+    /// let boundary = polygon
+    ///     .overlay(Resolution::Nine)
+    ///     .filter_map(|(cell, relation)| {
+    ///         (relation == CellRelation::Intersects).then_some(cell)
+    ///     })
+    ///     .collect::<Vec<_>>();
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn overlay(
+        &self,
+        resolution: Resolution,
+    ) -> impl Iterator<Item = (CellIndex, CellRelation)> + '_ {
+        traverse_relations(
+            CellIndex::base_cells(),
+            resolution,
+            |cell| self.relation(cell),
+            |cell| self.descendants_relation(cell),
+        )
+    }
+
+    /// Computes the coverage of the polygon, using the given containment
+    /// mode.
+    fn cells_with_mode(
+        &self,
+        resolution: Resolution,
+        mode: ContainmentMode,
+    ) -> impl Iterator<Item = CellIndex> + '_ {
+        traverse(
+            CellIndex::base_cells(),
+            resolution,
+            move |cell| self.is_covering(cell, mode),
+            |cell| self.descendants_relation(cell),
+        )
+    }
+
+    /// Computes the cells covering the polygon at several resolutions, in a
+    /// single traversal of the grid hierarchy.
+    fn cells_at(
+        &self,
+        resolutions: &[Resolution],
+        mode: ContainmentMode,
+    ) -> Vec<Vec<CellIndex>> {
+        let mut cells = vec![Vec::new(); resolutions.len()];
+        let Some(&finest) = resolutions.iter().max() else {
+            return cells;
+        };
+
+        // Depth-first traversal, cells are popped from the end.
+        let mut candidates = CellIndex::base_cells().collect::<Vec<_>>();
+        candidates.reverse();
+        while let Some(cell) = candidates.pop() {
+            let resolution = cell.resolution();
+            for (&target, cells) in resolutions.iter().zip(cells.iter_mut()) {
+                if target == resolution && self.is_covering(cell, mode) {
+                    cells.push(cell);
+                }
+            }
+            if resolution == finest {
+                continue;
+            }
+
+            match self.descendants_relation(cell) {
+                CellRelation::Disjoint => (),
+                CellRelation::Contains => {
+                    let targets = resolutions.iter().zip(cells.iter_mut());
+                    for (&target, cells) in
+                        targets.filter(|&(&r, _)| r > resolution)
+                    {
+                        cells.extend(cell.children(target));
+                    }
+                }
+                CellRelation::Intersects => {
+                    // Always set, since the finest resolution is finer.
+                    if let Some(child_resolution) = resolution.succ() {
+                        let start = candidates.len();
+                        candidates.extend(cell.children(child_resolution));
+                        candidates[start..].reverse();
+                    }
+                }
+            }
+        }
+
+        cells
+    }
+
+    /// Computes the spatial relationship between the polygon and a ring.
+    ///
+    /// The ring must be smaller than a hemisphere, and must not contain a
+    /// pole.
+    fn relation_with(&self, ring: &[Coord<f64>]) -> CellRelation {
+        // Work in a longitude space centered on the ring, to get rid of the
+        // antimeridian discontinuity.
+        let origin = ring[0].x;
+        let normalize = |coord: Coord<f64>| {
+            coord! {
+                x: origin + (coord.x - origin + PI).rem_euclid(TWO_PI) - PI,
+                y: coord.y,
+            }
+        };
+        let mut exterior = ring
+            .iter()
+            .copied()
+            .map(normalize)
+            .collect::<geo::LineString<f64>>();
+        exterior.close();
+        let (min, max) = exterior.coords().fold(
+            (exterior[0], exterior[0]),
+            |(min, max), coord| {
+                (
+                    coord! { x: min.x.min(coord.x), y: min.y.min(coord.y) },
+                    coord! { x: max.x.max(coord.x), y: max.y.max(coord.y) },
+                )
+            },
+        );
+        let shape = geo::Polygon::new(exterior, Vec::new());
+        let rings = || self.rings();
+
+        // Fast path: bounding boxes don't overlap (modulo a full turn).
+        let bbox = self.bbox();
+        let is_disjoint = max.y < bbox.min().y
+            || min.y > bbox.max().y
+            || [-TWO_PI, 0., TWO_PI].iter().all(|shift| {
+                max.x < bbox.min().x + shift || min.x > bbox.max().x + shift
+            });
+        if is_disjoint {
+            return CellRelation::Disjoint;
+        }
+
+        // If the ring and the polygon boundaries cross, they intersect.
+        let is_crossing =
+            rings().flat_map(geo::LineString::lines).any(|line| {
+                let line =
+                    geo::Line::new(normalize(line.start), normalize(line.end));
+                // Lines split by the normalization are on the other side of
+                // the globe, they can't cross the ring.
+                (line.start.x - line.end.x).abs() <= PI
+                    && shape
+                        .exterior()
+                        .lines()
+                        .any(|edge| edge.intersects(&line))
+            });
+        if is_crossing {
+            return CellRelation::Intersects;
+        }
+
+        // Otherwise, the ring is either entirely inside or entirely outside
+        // the polygon (but the later may be inside the former).
+        let has_vertex_within_ring = rings().any(|ring| {
+            ring.coords()
+                .any(|&coord| shape.contains(&normalize(coord)))
+        });
+        let first = coord! {
+            x: (ring[0].x + PI).rem_euclid(TWO_PI) - PI,
+            y: ring[0].y,
+        };
+        if self.contains(first) {
+            if has_vertex_within_ring {
+                // There is a hole inside the ring.
+                CellRelation::Intersects
+            } else {
+                CellRelation::Contains
+            }
+        } else if has_vertex_within_ring {
+            // The polygon is inside the ring.
+            CellRelation::Intersects
+        } else {
+            CellRelation::Disjoint
+        }
+    }
+
+    /// Computes the spatial relationship between the polygon and a polar
+    /// cell, given its boundary.
+    ///
+    /// A ring around a pole has no interior in the latitude/longitude space:
+    /// the cell is unrolled into the area between its boundary and the pole,
+    /// repeated over three turns to cover any longitude of the polygon.
+    fn polar_relation(&self, boundary: &[Coord<f64>]) -> CellRelation {
+        let pole = FRAC_PI_2.copysign(boundary[0].y);
+
+        // Fast path: the polygon doesn't reach the latitude of the cell.
+        let bbox = self.bbox();
+        let (min_lat, max_lat) = boundary
+            .iter()
+            .fold((f64::MAX, f64::MIN), |(min, max), coord| {
+                (min.min(coord.y), max.max(coord.y))
+            });
+        if (pole > 0. && bbox.max().y < min_lat)
+            || (pole < 0. && bbox.min().y > max_lat)
+        {
+            return CellRelation::Disjoint;
+        }
+
+        // Unwrap the longitudes of the boundary, which goes around the pole
+        // once, by counting the turns: the longitudes of the first turn are
+        // kept as is, to match the ones of the polygon exactly.
+        let mut turn = Vec::with_capacity(boundary.len());
+        let mut count = 0_i8;
+        for (i, &coord) in boundary.iter().enumerate() {
+            let delta = coord.x - boundary[i.saturating_sub(1)].x;
+            if delta > PI {
+                count -= 1;
+            } else if delta < -PI {
+                count += 1;
+            }
+            turn.push((coord, count));
+        }
+        // Go eastward, starting from the first turn.
+        if turn[turn.len() - 1].1 < 0 {
+            turn.reverse();
+        }
+        let first = turn[0];
+        let at = |coord: Coord<f64>, count: i8| {
+            coord! {
+                x: mul_add(f64::from(count - first.1), TWO_PI, coord.x),
+                y: coord.y,
+            }
+        };
+
+        // Three turns are enough to cover [-π, 2π], the longitudes of the
+        // (unwrapped) polygon.
+        let mut exterior = [-1, 0, 1]
+            .into_iter()
+            .flat_map(|shift| {
+                turn.iter()
+                    .map(move |&(coord, count)| at(coord, count + shift))
+            })
+            .collect::<Vec<_>>();
+        let (west, east) = (at(first.0, first.1 - 1), at(first.0, first.1 + 2));
+        exterior.extend([
+            east,
+            coord! { x: east.x, y: pole },
+            coord! { x: west.x, y: pole },
+        ]);
+        let shape = geo::Polygon::new(exterior.into(), Vec::new());
+
+        // Use continuous longitudes for transmeridian polygons, as done by
+        // the point-in-polygon test.
+        let is_transmeridian = bbox.max().x > PI;
+        let unwrap = |mut coord: Coord<f64>| {
+            if is_transmeridian && coord.x < 0. {
+                coord.x += TWO_PI;
+            }
+            coord
+        };
+        let rings = || self.rings();
+
+        let is_crossing =
+            rings().flat_map(geo::LineString::lines).any(|line| {
+                let line = geo::Line::new(unwrap(line.start), unwrap(line.end));
+                shape.exterior().lines().any(|edge| edge.intersects(&line))
+            });
+        if is_crossing {
+            return CellRelation::Intersects;
+        }
+
+        // Same reasoning as for the other cells.
+        let has_vertex_within_ring = rings().any(|ring| {
+            ring.coords().any(|&coord| shape.contains(&unwrap(coord)))
+        });
+        if self.contains(boundary[0]) {
+            if has_vertex_within_ring {
+                CellRelation::Intersects
+            } else {
+                CellRelation::Contains
+            }
+        } else if has_vertex_within_ring {
+            CellRelation::Intersects
+        } else {
+            CellRelation::Disjoint
+        }
+    }
+
+    /// Computes the spatial relationship between the polygon and the area
+    /// covered by the descendants of a cell.
+    pub(super) fn descendants_relation(&self, cell: CellIndex) -> CellRelation {
+        // The bounding box of a polar cell spans every longitude.
+        if is_polar(cell) {
+            return CellRelation::Intersects;
+        }
+        let ring = bbox_ring(cell, CHILD_SCALE_FACTOR);
+        // Same for the scaled bounding box of cells close to a pole: past
+        // half a turn, it can't be told apart from its complement.
+        if ring[2].x - ring[0].x >= PI {
+            return CellRelation::Intersects;
+        }
+        self.relation_with(&ring)
+    }
+
+    /// Clips the polygon to the area covered by the descendants of a cell.
+    ///
+    /// The clipped polygon contains the same descendant centers as the whole
+    /// one. Returns `None` if nothing is left.
+    pub(super) fn clip(&self, cell: CellIndex) -> Option<Polygon<'static>> {
+        let [mut min, _, mut max, _] = bbox_ring(cell, CHILD_SCALE_FACTOR);
+        // Past half a turn, the bounding box can't be told apart from its
+        // complement: only bound the latitudes, up to the pole.
+        if is_polar(cell) || max.x - min.x >= PI {
+            let pole = FRAC_PI_2.copysign(LatLng::from(cell).lat_radians());
+            let radius = cell.boundary().iter().fold(0., |radius, ll| {
+                (pole - ll.lat_radians()).abs().max(radius)
+            });
+            let bound = pole - (radius * CHILD_SCALE_FACTOR).copysign(pole);
+            (min, max) = (
+                coord! { x: f64::NEG_INFINITY, y: bound.min(pole) },
+                coord! { x: f64::INFINITY, y: bound.max(pole) },
+            );
+        }
+
+        Some(Polygon {
+            exterior: clip_ring(&self.exterior, min, max)?,
+            interiors: self
+                .interiors
+                .iter()
+                .filter_map(|ring| clip_ring(ring, min, max))
+                .collect(),
+        })
+    }
+
+    /// Returns true if the cell is part of the coverage, using the specified
+    /// containment mode.
+    pub(super) fn is_covering(
+        &self,
+        cell: CellIndex,
+        mode: ContainmentMode,
+    ) -> bool {
+        match mode {
+            ContainmentMode::Center => {
+                let ll = LatLng::from(cell);
+                self.contains(
+                    coord! { x: ll.lng_radians(), y: ll.lat_radians() },
+                )
+            }
+            ContainmentMode::Full => {
+                self.relation(cell) == CellRelation::Contains
+            }
+            ContainmentMode::Overlapping => {
+                self.relation(cell) != CellRelation::Disjoint
+            }
+            ContainmentMode::OverlappingBbox => {
+                // Fallback on the cell itself for polar cells.
+                let relation = if is_polar(cell) {
+                    self.relation(cell)
+                } else {
+                    self.relation_with(&bbox_ring(cell, 1.))
+                };
+                relation != CellRelation::Disjoint
+            }
+        }
+    }
+
     pub(super) const fn bbox(&self) -> geo::Rect<f64> {
         self.exterior.bbox()
     }
@@ -136,6 +664,11 @@ impl<'a> Polygon<'a> {
         self.exterior.geom()
     }
 
+    /// Returns the interior rings, followed by the exterior one.
+    pub(crate) fn rings(&self) -> impl Iterator<Item = &geo::LineString<f64>> {
+        self.interiors().chain(std::iter::once(self.exterior()))
+    }
+
     fn interiors(&self) -> impl Iterator<Item = &geo::LineString<f64>> {
         self.interiors.iter().map(Ring::geom)
     }
@@ -161,7 +694,10 @@ impl<'a> TryFrom<Geometry<'a>> for Polygon<'a> {
     fn try_from(value: Geometry<'a>) -> Result<Self, Self::Error> {
         match value {
             Geometry::Polygon(polygon) => Ok(polygon),
-            _ => Err(Self::Error::new("invalid type (polygon expected)")),
+            _ => Err(Self::Error::new(
+                InvalidGeometryKind::Type,
+                "invalid type (polygon expected)",
+            )),
         }
     }
 }
@@ -189,6 +725,12 @@ impl ToCells for Polygon<'_> {
         cmp::max(estimated_count, vertex_count) + POLYGON_TO_CELLS_BUFFER
     }
 
+    /// This implementation traverses the grid hierarchy once, sharing the work
+    /// between the resolutions.
+    fn to_cells_at(&self, resolutions: &[Resolution]) -> Vec<Vec<CellIndex>> {
+        self.cells_at(resolutions, ContainmentMode::Center)
+    }
+
     /// This implementation traces the polygon loop(s) in cartesian space with
     /// hexagons, tests them and their neighbors to be contained by the loop(s),
     /// and then any newly found hexagons are used to test again until no new
@@ -197,6 +739,18 @@ impl ToCells for Polygon<'_> {
         &self,
         resolution: Resolution,
     ) -> Box<dyn Iterator<Item = CellIndex> + '_> {
+        Box::new(trace::polyfill(self.rings().count(), resolution, || {
+            self.cells(resolution)
+        }))
+    }
+}
+
+impl Polygon<'_> {
+    /// Computes the coverage of the polygon.
+    fn cells(
+        &self,
+        resolution: Resolution,
+    ) -> impl Iterator<Item = CellIndex> + '_ {
         // One of the goals of the polygon_to_cells algorithm is that two
         // adjacent polygons with zero overlap have zero overlapping hexagons.
         // That the hexagons are uniquely assigned. There are a few approaches
@@ -223,8 +777,7 @@ impl ToCells for Polygon<'_> {
         #[allow(clippy::needless_collect)]
         // Compute the initial set of cell, using polygon edges.
         let edge_cells = self
-            .interiors()
-            .chain(std::iter::once(self.exterior()))
+            .rings()
             .flat_map(|ring| get_edge_cells(ring, resolution))
             .filter_map(|cell| seen.insert(cell).then_some(cell))
             .collect::<Vec<_>>();
@@ -243,7 +796,7 @@ impl ToCells for Polygon<'_> {
                     acc
                 });
 
-        Box::new(std::iter::from_fn(move || {
+        std::iter::from_fn(move || {
             while let Some(cell) = candidates.pop_front() {
                 let ll = LatLng::from(cell);
                 let coord = coord! { x: ll.lng_radians(), y: ll.lat_radians() };
@@ -258,12 +811,239 @@ impl ToCells for Polygon<'_> {
                 }
             }
             None
-        }))
+        })
     }
 }
 
 // ----------------------------------------------------------------------------
 
+/// Traverses the grid hierarchy depth-first, from the given root cells (e.g.
+/// the base cells) down to the target resolution, keeping the cells covering
+/// the shape (according to `is_covering`).
+///
+/// See `traverse_relations`.
+pub(super) fn traverse(
+    roots: impl IntoIterator<Item = CellIndex>,
+    resolution: Resolution,
+    is_covering: impl Fn(CellIndex) -> bool,
+    descendants_relation: impl Fn(CellIndex) -> CellRelation,
+) -> impl Iterator<Item = CellIndex> {
+    traverse_relations(
+        roots,
+        resolution,
+        move |cell| {
+            if is_covering(cell) {
+                CellRelation::Contains
+            } else {
+                CellRelation::Disjoint
+            }
+        },
+        descendants_relation,
+    )
+    .filter_map(|(cell, relation)| {
+        (relation != CellRelation::Disjoint).then_some(cell)
+    })
+}
+
+/// Traverses the grid hierarchy depth-first, from the given root cells (e.g.
+/// the base cells) down to the target resolution, labeling the cells with
+/// their relationship with the shape.
+///
+/// Branches disjoint from the shape are skipped, and branches contained in it
+/// are taken as a whole (labeled `Contains`): only the cells of the branches
+/// intersecting the shape boundary are tested (using `relation`) at the
+/// target resolution.
+pub(super) fn traverse_relations(
+    roots: impl IntoIterator<Item = CellIndex>,
+    resolution: Resolution,
+    relation: impl Fn(CellIndex) -> CellRelation,
+    descendants_relation: impl Fn(CellIndex) -> CellRelation,
+) -> impl Iterator<Item = (CellIndex, CellRelation)> {
+    // Depth-first traversal, cells are popped from the end.
+    let mut candidates = roots.into_iter().collect::<Vec<_>>();
+    candidates.reverse();
+    let mut branch: Box<dyn Iterator<Item = CellIndex>> =
+        Box::new(std::iter::empty());
+
+    std::iter::from_fn(move || loop {
+        if let Some(cell) = branch.next() {
+            return Some((cell, CellRelation::Contains));
+        }
+        let cell = candidates.pop()?;
+
+        if cell.resolution() == resolution {
+            return Some((cell, relation(cell)));
+        }
+        match descendants_relation(cell) {
+            CellRelation::Disjoint => (),
+            CellRelation::Contains => {
+                branch = Box::new(cell.children(resolution));
+            }
+            CellRelation::Intersects => {
+                // Always set, since the target resolution is finer.
+                if let Some(child_resolution) = cell.resolution().succ() {
+                    let start = candidates.len();
+                    candidates.extend(cell.children(child_resolution));
+                    candidates[start..].reverse();
+                }
+            }
+        }
+    })
+}
+
+/// Returns true if the cell contains a pole.
+pub fn is_polar(cell: CellIndex) -> bool {
+    POLAR_CELLS.into_iter().any(|pole| {
+        CellIndex::new_unchecked(pole).parent(cell.resolution()) == Some(cell)
+    })
+}
+
+/// Clips a ring to a rectangle, whose longitudes may be unbounded, using the
+/// Sutherland-Hodgman algorithm.
+///
+/// The clipping is done in the space where the ring is planar (i.e. with the
+/// longitudes of transmeridian rings unwrapped), so point containment is
+/// preserved within the rectangle. Returns `None` if nothing is left.
+fn clip_ring(
+    ring: &Ring<'_>,
+    min: Coord<f64>,
+    max: Coord<f64>,
+) -> Option<Ring<'static>> {
+    let bbox = ring.bbox();
+    let is_transmeridian = bbox.max().x > PI;
+    let (min, max) = if min.x.is_finite() {
+        // Move the rectangle over the ring, unless it overlaps on both sides.
+        let mut shifts = [-TWO_PI, 0., TWO_PI].into_iter().filter(|shift| {
+            max.x + shift >= bbox.min().x && min.x + shift <= bbox.max().x
+        });
+        match (shifts.next(), shifts.next()) {
+            (None, _) => return None,
+            (Some(shift), None) => (
+                coord! { x: min.x + shift, y: min.y },
+                coord! { x: max.x + shift, y: max.y },
+            ),
+            (Some(_), Some(_)) => (
+                coord! { x: f64::NEG_INFINITY, y: min.y },
+                coord! { x: f64::INFINITY, y: max.y },
+            ),
+        }
+    } else {
+        (min, max)
+    };
+
+    // Skip the closing coordinate.
+    let coords = &ring.geom().0;
+    let mut coords = coords[..coords.len() - 1]
+        .iter()
+        .map(|coord| {
+            coord! {
+                x: mul_add(
+                    f64::from(u8::from(is_transmeridian && coord.x < 0.)),
+                    TWO_PI,
+                    coord.x,
+                ),
+                y: coord.y,
+            }
+        })
+        .collect::<Vec<_>>();
+    for (is_lng, bound, is_min) in [
+        (true, min.x, true),
+        (true, max.x, false),
+        (false, min.y, true),
+        (false, max.y, false),
+    ] {
+        if !bound.is_finite() {
+            continue;
+        }
+        let value = |coord: Coord<f64>| if is_lng { coord.x } else { coord.y };
+        let is_inside = |coord| {
+            if is_min {
+                value(coord) >= bound
+            } else {
+                value(coord) <= bound
+            }
+        };
+        let crossing = |start: Coord<f64>, end: Coord<f64>| {
+            let ratio = (bound - value(start)) / (value(end) - value(start));
+            start + (end - start) * ratio
+        };
+
+        let mut clipped = Vec::with_capacity(coords.len() + 4);
+        for (i, &end) in coords.iter().enumerate() {
+            let start = coords[(i + coords.len() - 1) % coords.len()];
+            match (is_inside(start), is_inside(end)) {
+                (true, true) => clipped.push(end),
+                (true, false) => clipped.push(crossing(start, end)),
+                (false, true) => {
+                    clipped.push(crossing(start, end));
+                    clipped.push(end);
+                }
+                (false, false) => (),
+            }
+        }
+        coords = clipped;
+    }
+    if coords.len() < 3 {
+        return None;
+    }
+
+    // Wrap the longitudes back, after splitting the edges spanning more than
+    // half a turn (e.g. along a bound) which would otherwise be mistaken for
+    // transmeridian ones.
+    let mut clipped = geo::LineString::new(Vec::with_capacity(coords.len()));
+    for (i, &start) in coords.iter().enumerate() {
+        let end = coords[(i + 1) % coords.len()];
+        // The longitudes span at most a full turn: three parts are enough.
+        let parts = if (end.x - start.x).abs() < PI { 1 } else { 3 };
+        clipped.0.extend((0..parts).map(|part| {
+            let coord =
+                start + (end - start) * (f64::from(part) / f64::from(parts));
+            coord! {
+                x: mul_add(-f64::from(u8::from(coord.x > PI)), TWO_PI, coord.x),
+                y: coord.y,
+            }
+        }));
+    }
+    clipped.close();
+
+    Ring::from_radians(Cow::Owned(clipped)).ok()
+}
+
+/// Returns the bounding box of a cell, scaled by the given factor, as a ring.
+pub(super) fn bbox_ring(cell: CellIndex, scale: f64) -> [Coord<f64>; 4] {
+    let boundary = cell.boundary();
+    let origin = boundary[0].lng_radians();
+    let (min, max) = boundary.iter().fold(
+        (
+            coord! { x: f64::MAX, y: f64::MAX },
+            coord! { x: f64::MIN, y: f64::MIN },
+        ),
+        |(min, max), ll| {
+            // Keep the longitudes continuous across the antimeridian.
+            let lng = origin
+                + (ll.lng_radians() - origin + PI).rem_euclid(TWO_PI)
+                - PI;
+            (
+                coord! { x: min.x.min(lng), y: min.y.min(ll.lat_radians()) },
+                coord! { x: max.x.max(lng), y: max.y.max(ll.lat_radians()) },
+            )
+        },
+    );
+    let center = (min + max) / 2.;
+    let half = (max - min) * (scale / 2.);
+    let (min, max) = (
+        coord! { x: center.x - half.x, y: (center.y - half.y).max(-FRAC_PI_2) },
+        coord! { x: center.x + half.x, y: (center.y + half.y).min(FRAC_PI_2) },
+    );
+
+    [
+        min,
+        coord! { x: max.x, y: min.y },
+        max,
+        coord! { x: min.x, y: max.y },
+    ]
+}
+
 // Return the cell indexes that traces the ring outline.
 fn get_edge_cells(
     ring: &geo::LineString<f64>,
@@ -290,7 +1070,7 @@ fn get_edge_cells(
 }
 
 // Return the next round of candidates from the given cell.
-fn add_candidates(
+pub(super) fn add_candidates(
     cell: CellIndex,
     candidates: &mut VecDeque<CellIndex>,
     seen: &mut HashSet<CellIndex>,
@@ -327,7 +1107,10 @@ fn add_candidates(
 
 /// Returns an estimated number of hexagons that trace the cartesian-projected
 /// line.
-fn line_hex_estimate(line: &geo::Line<f64>, resolution: Resolution) -> u64 {
+pub(super) fn line_hex_estimate(
+    line: &geo::Line<f64>,
+    resolution: Resolution,
+) -> u64 {
     // Get the area of the pentagon as the maximally-distorted area possible
     const PENT_DIAMETER_RADS: [f64; 16] = [
         0.32549355508382627,
@@ -0,0 +1,226 @@
+use super::line;
+use crate::{
+    error::{InvalidGeometry, InvalidGeometryKind},
+    geom::ToCells,
+    CellIndex, Resolution,
+};
+use std::boxed::Box;
+
+/// A path between locations, stored as a Google encoded polyline.
+///
+/// Coordinates are decoded on the fly when computing the cells, rather than
+/// being materialized as a line string first.
+///
+/// See the [format documentation](https://developers.google.com/maps/documentation/utilities/polylinealgorithm)
+/// for more details.
+///
+/// Note that the `ToCells` implementation suffers from the same limitation
+/// that [`LineString`](super::LineString).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Polyline<'a> {
+    encoded: &'a str,
+    precision: Precision,
+}
+
+/// Number of decimal digits of the coordinates of an encoded polyline.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Precision {
+    /// Five digits, used by Google Maps.
+    #[default]
+    Five,
+    /// Six digits, used by OSRM or Valhalla.
+    Six,
+}
+
+impl Precision {
+    const fn factor(self) -> f64 {
+        match self {
+            Self::Five => 1e5,
+            Self::Six => 1e6,
+        }
+    }
+}
+
+impl<'a> Polyline<'a> {
+    /// Initialize a new polyline from its encoded form.
+    ///
+    /// # Errors
+    ///
+    /// [`InvalidGeometry`] if the polyline is malformed, or contains less than
+    /// two points or invalid coordinates.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::geom::{Polyline, Precision};
+    ///
+    /// let encoded = "_p~iF~ps|U_ulLnnqC_mqNvxq`@";
+    /// let polyline = Polyline::new(encoded, Precision::Five)?;
+    /// # Ok::<(), h3o::error::InvalidGeometry>(())
+    /// ```
+    pub fn new(
+        encoded: &'a str,
+        precision: Precision,
+    ) -> Result<Self, InvalidGeometry> {
+        let mut count = 0;
+        let mut decoder = Decoder::new(encoded, precision);
+        while let Some(coord) = decoder.try_next()? {
+            if !(coord.y.abs() <= 90. && coord.x.abs() <= 180.) {
+                return Err(InvalidGeometry::new(
+                    InvalidGeometryKind::Coordinate,
+                    "every coordinate of the polyline must be valid",
+                ));
+            }
+            count += 1;
+        }
+        if count < 2 {
+            return Err(InvalidGeometry::new(
+                InvalidGeometryKind::TooFewCoordinates,
+                "a polyline must have at least two points",
+            ));
+        }
+
+        Ok(Self { encoded, precision })
+    }
+
+    /// Returns the coordinates of the polyline, in radians.
+    fn coords(&self) -> impl Iterator<Item = geo::Coord<f64>> + 'a {
+        let mut decoder = Decoder::new(self.encoded, self.precision);
+        std::iter::from_fn(move || {
+            // Validated at construction.
+            let coord = decoder.try_next().expect("valid polyline")?;
+            Some(geo::coord! {
+                x: coord.x.to_radians(),
+                y: coord.y.to_radians(),
+            })
+        })
+    }
+
+    /// Returns the segments of the polyline, in radians.
+    fn lines(&self) -> impl Iterator<Item = geo::Line<f64>> + 'a {
+        let mut coords = self.coords();
+        let first = coords.next();
+        coords.scan(first, |start, end| {
+            let line = geo::Line::new((*start)?, end);
+            *start = Some(end);
+            Some(line)
+        })
+    }
+}
+
+impl From<Polyline<'_>> for geo::LineString<f64> {
+    fn from(value: Polyline<'_>) -> Self {
+        let mut decoder = Decoder::new(value.encoded, value.precision);
+        std::iter::from_fn(|| decoder.try_next().expect("valid polyline"))
+            .collect()
+    }
+}
+
+impl ToCells for Polyline<'_> {
+    fn max_cells_count(&self, resolution: Resolution) -> usize {
+        self.lines()
+            .map(|line| line::cells_count(line, resolution))
+            .sum()
+    }
+
+    fn to_cells(
+        &self,
+        resolution: Resolution,
+    ) -> Box<dyn Iterator<Item = CellIndex> + '_> {
+        Box::new(
+            self.lines()
+                .flat_map(move |line| line::to_cells(line, resolution)),
+        )
+    }
+}
+
+// -----------------------------------------------------------------------------
+
+/// Decoder of encoded polylines, yielding coordinates in degrees.
+struct Decoder<'a> {
+    bytes: std::slice::Iter<'a, u8>,
+    factor: f64,
+    lat: i64,
+    lng: i64,
+}
+
+impl<'a> Decoder<'a> {
+    fn new(encoded: &'a str, precision: Precision) -> Self {
+        Self {
+            bytes: encoded.as_bytes().iter(),
+            factor: precision.factor(),
+            lat: 0,
+            lng: 0,
+        }
+    }
+
+    /// Decodes the next coordinate, if any.
+    fn try_next(&mut self) -> Result<Option<geo::Coord<f64>>, InvalidGeometry> {
+        let Some(lat) = self.next_value()? else {
+            return Ok(None);
+        };
+        let lng = self.next_value()?.ok_or_else(|| {
+            InvalidGeometry::new(
+                InvalidGeometryKind::Encoding,
+                "truncated polyline coordinate",
+            )
+        })?;
+        self.lat = self.lat.checked_add(lat).ok_or_else(overflow)?;
+        self.lng = self.lng.checked_add(lng).ok_or_else(overflow)?;
+
+        // Out of range values are rejected afterward, precision loss is moot.
+        #[allow(clippy::cast_precision_loss)]
+        let (lat, lng) = (self.lat as f64, self.lng as f64);
+        Ok(Some(geo::coord! {
+            x: lng / self.factor,
+            y: lat / self.factor,
+        }))
+    }
+
+    /// Decodes the next (delta-encoded) value, if any.
+    fn next_value(&mut self) -> Result<Option<i64>, InvalidGeometry> {
+        let mut value = 0_u64;
+        let mut shift = 0;
+        loop {
+            let Some(&byte) = self.bytes.next() else {
+                return if shift == 0 {
+                    Ok(None)
+                } else {
+                    Err(InvalidGeometry::new(
+                        InvalidGeometryKind::Encoding,
+                        "truncated polyline value",
+                    ))
+                };
+            };
+            if !(63..=126).contains(&byte) || shift > 60 {
+                return Err(InvalidGeometry::new(
+                    InvalidGeometryKind::Encoding,
+                    "invalid polyline value",
+                ));
+            }
+            let chunk = u64::from(byte - 63);
+            value |= (chunk & 0x1f) << shift;
+            shift += 5;
+            if chunk < 0x20 {
+                break;
+            }
+        }
+
+        // Zigzag decoding, the shifted value always fits.
+        #[allow(clippy::cast_possible_wrap)]
+        let magnitude = (value >> 1) as i64;
+        Ok(Some(if value & 1 == 1 {
+            !magnitude
+        } else {
+            magnitude
+        }))
+    }
+}
+
+const fn overflow() -> InvalidGeometry {
+    InvalidGeometry::new(
+        InvalidGeometryKind::Encoding,
+        "polyline coordinate overflow",
+    )
+}
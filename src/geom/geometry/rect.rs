@@ -1,7 +1,8 @@
+use super::polygon::{self, CHILD_SCALE_FACTOR};
 use crate::{
     error::InvalidGeometry,
-    geom::{Polygon, ToCells},
-    CellIndex, Resolution,
+    geom::{CellRelation, ContainmentMode, Polygon, ToCells},
+    trace, CellIndex, Resolution, TWO_PI,
 };
 use std::boxed::Box;
 
@@ -10,7 +11,7 @@ use std::boxed::Box;
 #[derive(Clone, Debug, PartialEq)]
 pub struct Rect<'a>(Polygon<'a>);
 
-impl Rect<'_> {
+impl<'a> Rect<'a> {
     /// Initialize a new rectangle from a rect whose coordinates are in radians.
     ///
     /// # Errors
@@ -56,6 +57,47 @@ impl Rect<'_> {
     pub fn from_degrees(rect: geo::Rect<f64>) -> Result<Self, InvalidGeometry> {
         Ok(Self(Polygon::from_degrees(rect.to_polygon())?))
     }
+
+    /// Returns the rectangle as a polygon.
+    pub(super) fn into_polygon(self) -> Polygon<'a> {
+        self.0
+    }
+
+    /// Computes the spatial relationship between the rectangle and the area
+    /// covered by the descendants of a cell.
+    ///
+    /// Being axis-aligned, the rectangle is its own bounding box: comparing
+    /// bounding boxes is enough, no need to test the edges.
+    fn descendants_relation(&self, cell: CellIndex) -> CellRelation {
+        // The bounding box of a polar cell spans every longitude.
+        if polygon::is_polar(cell) {
+            return CellRelation::Intersects;
+        }
+
+        let ring = polygon::bbox_ring(cell, CHILD_SCALE_FACTOR);
+        let (min, max) = (ring[0], ring[2]);
+        let bbox = self.0.bbox();
+        if max.y < bbox.min().y || min.y > bbox.max().y {
+            return CellRelation::Disjoint;
+        }
+
+        // Test modulo a full turn, to handle the antimeridian.
+        let shifts = [-TWO_PI, 0., TWO_PI];
+        let is_inside = min.y > bbox.min().y && max.y < bbox.max().y;
+        if is_inside
+            && shifts.iter().any(|shift| {
+                min.x > bbox.min().x + shift && max.x < bbox.max().x + shift
+            })
+        {
+            return CellRelation::Contains;
+        }
+        if shifts.iter().any(|shift| {
+            max.x >= bbox.min().x + shift && min.x <= bbox.max().x + shift
+        }) {
+            return CellRelation::Intersects;
+        }
+        CellRelation::Disjoint
+    }
 }
 
 impl From<Rect<'_>> for geo::Rect<f64> {
@@ -73,6 +115,17 @@ impl ToCells for Rect<'_> {
         &self,
         resolution: Resolution,
     ) -> Box<dyn Iterator<Item = CellIndex> + '_> {
-        self.0.to_cells(resolution)
+        Box::new(trace::polyfill(1, resolution, || {
+            polygon::traverse(
+                CellIndex::base_cells(),
+                resolution,
+                |cell| self.0.is_covering(cell, ContainmentMode::Center),
+                move |cell| self.descendants_relation(cell),
+            )
+        }))
+    }
+
+    fn to_cells_at(&self, resolutions: &[Resolution]) -> Vec<Vec<CellIndex>> {
+        self.0.to_cells_at(resolutions)
     }
 }
@@ -1,5 +1,5 @@
 use super::bbox;
-use crate::{error::InvalidGeometry, TWO_PI};
+use crate::{error::InvalidGeometry, math::mul_add, TWO_PI};
 use geo::{Coord, Intersects};
 use std::{borrow::Cow, f64::consts::PI};
 
@@ -108,7 +108,7 @@ impl<'a> Ring<'a> {
             // and traversing the same percent in the longitudinal direction
             // of `a` to `b`.
             let ratio = (coord.y - start.y) / (end.y - start.y);
-            let mut test_lng = (end.x - start.x).mul_add(ratio, start.x);
+            let mut test_lng = mul_add(end.x - start.x, ratio, start.x);
             test_lng +=
                 f64::from(u8::from(is_transmeridian && test_lng < 0.)) * TWO_PI;
 
@@ -1,16 +1,35 @@
+use super::{bbox, polygon};
 use crate::{
-    error::InvalidGeometry,
-    geom::{Polygon, ToCells},
-    CellIndex, Resolution,
+    error::{InvalidGeometry, InvalidGeometryKind},
+    geom::{CellRelation, Polygon, ToCells},
+    math::mul_add,
+    trace, CellIndex, LatLng, Resolution, EARTH_RADIUS_KM, TWO_PI,
 };
-use geo::CoordsIter;
-use std::boxed::Box;
+use ahash::{HashSet, HashSetExt};
+use geo::{coord, Coord};
+use std::{
+    boxed::Box,
+    cmp,
+    collections::VecDeque,
+    f64::consts::{FRAC_PI_2, PI},
+};
+
+/// Upper bound of the distance between the center of a cell and the centers
+/// of its descendants, relative to the longest edge at its resolution.
+///
+/// The measured ratio stays below 1.04, this leaves some margin.
+const DESCENDANTS_RADIUS_FACTOR: f64 = 1.2;
 
 /// A bounded 2D area whose three vertices are defined by [`geo::Coord`]s.
 #[derive(Clone, Debug, PartialEq)]
-pub struct Triangle<'a>(Polygon<'a>);
+pub struct Triangle {
+    /// Vertices, in radians.
+    vertices: [Coord<f64>; 3],
+    /// Bounding box, with continuous longitudes.
+    bbox: geo::Rect<f64>,
+}
 
-impl Triangle<'_> {
+impl Triangle {
     /// Initialize a new triangle from a triangle whose coordinates are in
     /// radians.
     ///
@@ -35,7 +54,25 @@ impl Triangle<'_> {
     pub fn from_radians(
         triangle: geo::Triangle<f64>,
     ) -> Result<Self, InvalidGeometry> {
-        Ok(Self(Polygon::from_triangle(triangle)?))
+        let vertices = triangle.to_array();
+        if !vertices.into_iter().all(super::coord_is_valid) {
+            return Err(InvalidGeometry::new(
+                InvalidGeometryKind::Coordinate,
+                "invalid coordinate (e.g. infinite)",
+            ));
+        }
+
+        // Like rings, edges spanning more than half a turn cross the
+        // antimeridian.
+        let is_transmeridian =
+            edges(&vertices).any(|(start, end)| (start.x - end.x).abs() > PI);
+        let [a, b, c] = vertices.map(|coord| unwrap(coord, is_transmeridian));
+        let bbox = geo::Rect::new(
+            coord! { x: a.x.min(b.x).min(c.x), y: a.y.min(b.y).min(c.y) },
+            coord! { x: a.x.max(b.x).max(c.x), y: a.y.max(b.y).max(c.y) },
+        );
+
+        Ok(Self { vertices, bbox })
     }
 
     /// Initialize a new triangle from a triangle whose coordinates are in
@@ -62,29 +99,315 @@ impl Triangle<'_> {
     pub fn from_degrees(
         triangle: geo::Triangle<f64>,
     ) -> Result<Self, InvalidGeometry> {
-        Ok(Self(Polygon::from_degrees(triangle.to_polygon())?))
+        let [a, b, c] = triangle.to_array().map(|coord| {
+            coord! { x: coord.x.to_radians(), y: coord.y.to_radians() }
+        });
+        Self::from_radians(geo::Triangle::new(a, b, c))
+    }
+
+    /// Returns the triangle as a polygon.
+    pub(super) fn into_polygon(self) -> Polygon<'static> {
+        Polygon::from_triangle(self.into()).expect("valid triangle")
     }
-}
 
-impl From<Triangle<'_>> for geo::Triangle<f64> {
-    fn from(value: Triangle<'_>) -> Self {
-        let coords = value.0.exterior();
-        // 3 vertex + 1 to close the loop.
-        debug_assert_eq!(coords.coords_count(), 4);
+    /// Returns the vertices of the triangle, counter-clockwise.
+    ///
+    /// Transmeridian triangles are shifted eastward to get continuous
+    /// longitudes (as done by the point-in-polygon test).
+    fn vertices(&self) -> [Coord<f64>; 3] {
+        let is_transmeridian = self.bbox.max().x > PI;
+        let [a, b, c] =
+            self.vertices.map(|coord| unwrap(coord, is_transmeridian));
 
-        Self::new(coords[0], coords[1], coords[2])
+        if cross(a, b, c) < 0. {
+            [a, c, b]
+        } else {
+            [a, b, c]
+        }
+    }
+
+    /// Returns the finest resolution whose cells are larger than the triangle.
+    fn root_resolution(&self) -> Resolution {
+        let [a, b, c] = self.corners();
+        let extent = a
+            .distance_rads(b)
+            .max(b.distance_rads(c))
+            .max(c.distance_rads(a));
+
+        Resolution::range(Resolution::Zero, Resolution::Fifteen)
+            .rev()
+            .find(|res| res.edge_length_rads() >= extent)
+            .unwrap_or(Resolution::Zero)
+    }
+
+    /// Returns the cells, at the given resolution, from which the grid
+    /// hierarchy is traversed.
+    ///
+    /// Instead of starting from the base cells, start from cells larger than
+    /// the triangle: only a handful of them (found by flooding the
+    /// neighborhood of a vertex) can have descendants within the triangle.
+    fn roots(
+        &self,
+        vertices: &[Coord<f64>; 3],
+        resolution: Resolution,
+    ) -> Vec<CellIndex> {
+        let start = self.corners()[0].to_cell(resolution);
+        let mut roots = vec![start];
+        let mut visited = HashSet::from_iter([start]);
+        let mut candidates = vec![start];
+        while let Some(cell) = candidates.pop() {
+            for neighbor in cell.grid_disk::<Vec<_>>(1) {
+                if visited.insert(neighbor)
+                    && self.descendants_relation(neighbor, vertices)
+                        != CellRelation::Disjoint
+                {
+                    roots.push(neighbor);
+                    candidates.push(neighbor);
+                }
+            }
+        }
+
+        roots
+    }
+
+    /// Computes the coverage of the triangle by flooding the neighborhood of
+    /// its edges (like `Polygon::to_cells` does), for coverages too shallow to
+    /// benefit from the hierarchy.
+    fn flood(
+        &self,
+        vertices: [Coord<f64>; 3],
+        resolution: Resolution,
+    ) -> impl Iterator<Item = CellIndex> + '_ {
+        // Cells along the edges, from which the neighborhood is flooded.
+        let mut edge_cells = edges(&vertices)
+            .flat_map(|(start, end)| {
+                let line = geo::Line::new(start, end);
+                // At most ~4e7 samples (half the globe at resolution 15).
+                let count = u32::try_from(polygon::line_hex_estimate(
+                    &line, resolution,
+                ))
+                .expect("edge cell count fits in 32 bits");
+                (0..count).map(move |i| {
+                    let coord = start
+                        + line.delta() * (f64::from(i) / f64::from(count));
+                    LatLng::from_radians(coord.y, coord.x)
+                        .expect("finite edge coordinate")
+                        .to_cell(resolution)
+                })
+            })
+            .collect::<Vec<_>>();
+        edge_cells.sort_unstable();
+        edge_cells.dedup();
+
+        let mut seen = HashSet::with_capacity(self.max_cells_count(resolution));
+        let mut candidates = VecDeque::new();
+        let mut scratchpad = [0; 7];
+        for cell in edge_cells {
+            polygon::add_candidates(
+                cell,
+                &mut candidates,
+                &mut seen,
+                &mut scratchpad,
+            );
+        }
+
+        std::iter::from_fn(move || {
+            while let Some(cell) = candidates.pop_front() {
+                if self.contains(cell, &vertices) {
+                    polygon::add_candidates(
+                        cell,
+                        &mut candidates,
+                        &mut seen,
+                        &mut scratchpad,
+                    );
+                    return Some(cell);
+                }
+            }
+            None
+        })
+    }
+
+    /// Returns the vertices of the triangle, as coordinates.
+    fn corners(&self) -> [LatLng; 3] {
+        self.vertices.map(|coord| {
+            LatLng::from_radians(coord.y, coord.x).expect("finite vertex")
+        })
+    }
+
+    /// Returns true if the center of the cell is inside the triangle.
+    ///
+    /// Centers lying exactly on an edge are assigned to one side only, so
+    /// that triangles sharing that edge don't both contain them.
+    fn contains(&self, cell: CellIndex, vertices: &[Coord<f64>; 3]) -> bool {
+        let ll = LatLng::from(cell);
+        let is_transmeridian = self.bbox.max().x > PI;
+        let center = unwrap(
+            coord! { x: ll.lng_radians(), y: ll.lat_radians() },
+            is_transmeridian,
+        );
+
+        edges(vertices).all(|(start, end)| {
+            let side = cross(start, end, center);
+            if side > 0. {
+                true
+            } else if side < 0. {
+                false
+            } else {
+                // Break the tie as if the center was moved slightly west
+                // (then slightly north, for horizontal edges).
+                end.y > start.y || (end.y >= start.y && end.x > start.x)
+            }
+        })
+    }
+
+    /// Computes the spatial relationship between the triangle and the area
+    /// covered by the descendants of a cell.
+    ///
+    /// The triangle being convex, the bounding box of the descendant centers
+    /// is tested against its edges directly, without building any ring.
+    fn descendants_relation(
+        &self,
+        cell: CellIndex,
+        vertices: &[Coord<f64>; 3],
+    ) -> CellRelation {
+        let Some(ring) = descendants_bbox(cell) else {
+            return CellRelation::Intersects;
+        };
+        let bbox = self.bbox;
+        let mut relation = CellRelation::Disjoint;
+        // Test modulo a full turn, to handle the antimeridian.
+        for shift in [-TWO_PI, 0., TWO_PI] {
+            let corners =
+                ring.map(|corner| coord! { x: corner.x + shift, y: corner.y });
+            let (min, max) = (corners[0], corners[2]);
+            let is_disjoint = max.x < bbox.min().x
+                || min.x > bbox.max().x
+                || max.y < bbox.min().y
+                || min.y > bbox.max().y
+                // Separated by an edge: every corner is strictly outside.
+                || edges(vertices).any(|(start, end)| {
+                    corners.iter().all(|&corner| cross(start, end, corner) < 0.)
+                });
+            if is_disjoint {
+                continue;
+            }
+            let is_inside = corners.iter().all(|&corner| {
+                edges(vertices)
+                    .all(|(start, end)| cross(start, end, corner) > 0.)
+            });
+            if is_inside {
+                return CellRelation::Contains;
+            }
+            relation = CellRelation::Intersects;
+        }
+
+        relation
+    }
+}
+
+impl From<Triangle> for geo::Triangle<f64> {
+    fn from(value: Triangle) -> Self {
+        let [a, b, c] = value.vertices;
+        Self::new(a, b, c)
     }
 }
 
-impl ToCells for Triangle<'_> {
+impl ToCells for Triangle {
     fn max_cells_count(&self, resolution: Resolution) -> usize {
-        self.0.max_cells_count(resolution)
+        // Same bound as for a polygon (see `Polygon::max_cells_count`).
+        const POLYGON_TO_CELLS_BUFFER: usize = 12;
+
+        let estimated_count = bbox::hex_estimate(&self.bbox, resolution);
+        cmp::max(estimated_count, 3) + POLYGON_TO_CELLS_BUFFER
     }
 
+    /// This implementation tests the cell centers against the edges of the
+    /// triangle, and traverses the grid hierarchy from cells larger than the
+    /// triangle when the coverage is at least four resolutions finer.
+    ///
+    /// Only the latter is significantly faster than [`Polygon::to_cells`]:
+    /// for shallower coverages, the cost is dominated by the cell center
+    /// computations, shared by both.
     fn to_cells(
         &self,
         resolution: Resolution,
     ) -> Box<dyn Iterator<Item = CellIndex> + '_> {
-        self.0.to_cells(resolution)
+        // Below that depth, flooding the neighborhood of the edges is faster
+        // than going through the hierarchy.
+        const MIN_DEPTH: u8 = 4;
+
+        let root_resolution = self.root_resolution();
+        let vertices = self.vertices();
+        if u8::from(resolution) < u8::from(root_resolution) + MIN_DEPTH {
+            return Box::new(trace::polyfill(1, resolution, || {
+                self.flood(vertices, resolution)
+            }));
+        }
+
+        Box::new(trace::polyfill(1, resolution, || {
+            let roots = self.roots(&vertices, root_resolution);
+            polygon::traverse(
+                roots,
+                resolution,
+                move |cell| self.contains(cell, &vertices),
+                move |cell| self.descendants_relation(cell, &vertices),
+            )
+        }))
+    }
+}
+
+/// Returns the edges of the triangle.
+fn edges(
+    vertices: &[Coord<f64>; 3],
+) -> impl Iterator<Item = (Coord<f64>, Coord<f64>)> + '_ {
+    (0..3).map(|i| (vertices[i], vertices[(i + 1) % 3]))
+}
+
+/// Returns the bounding box, as a ring, of the centers of the descendants of
+/// a cell.
+///
+/// The bounding box is derived from the center of the cell alone (which is
+/// much cheaper than computing its boundary), and `None` is returned when the
+/// descendants may reach a pole.
+fn descendants_bbox(cell: CellIndex) -> Option<[Coord<f64>; 4]> {
+    let center = LatLng::from(cell);
+    let radius = cell.resolution().edge_length_max_km() / EARTH_RADIUS_KM
+        * DESCENDANTS_RADIUS_FACTOR;
+    let (lat, lng) = (center.lat_radians(), center.lng_radians());
+    if lat.abs() + radius >= FRAC_PI_2 {
+        return None;
     }
+    // Largest longitude difference within a circle on the sphere.
+    let half_width = (radius.sin() / lat.cos()).asin();
+
+    let (min, max) = (
+        coord! { x: lng - half_width, y: lat - radius },
+        coord! { x: lng + half_width, y: lat + radius },
+    );
+    Some([
+        min,
+        coord! { x: max.x, y: min.y },
+        max,
+        coord! { x: min.x, y: max.y },
+    ])
+}
+
+/// Shifts the coordinate eastward, by a full turn, if it's on the western
+/// side of a transmeridian shape.
+fn unwrap(mut coord: Coord<f64>, is_transmeridian: bool) -> Coord<f64> {
+    if is_transmeridian && coord.x < 0. {
+        coord.x += TWO_PI;
+    }
+    coord
+}
+
+/// Returns the cross product of `(end - start)` and `(point - start)`.
+///
+/// Positive when `point` is on the left of the `start -> end` line.
+fn cross(start: Coord<f64>, end: Coord<f64>, point: Coord<f64>) -> f64 {
+    mul_add(
+        end.x - start.x,
+        point.y - start.y,
+        -(end.y - start.y) * (point.x - start.x),
+    )
 }
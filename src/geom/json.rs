@@ -1,5 +1,5 @@
 use super::{Geometry, GeometryCollection};
-use crate::error::InvalidGeometry;
+use crate::error::{InvalidGeometry, InvalidGeometryKind};
 
 impl TryFrom<&geojson::Geometry> for Geometry<'_> {
     type Error = InvalidGeometry;
@@ -18,7 +18,12 @@ impl TryFrom<&geojson::Feature> for Geometry<'_> {
         value
             .geometry
             .as_ref()
-            .ok_or_else(|| Self::Error::new("geometryless feature"))
+            .ok_or_else(|| {
+                Self::Error::new(
+                    InvalidGeometryKind::Type,
+                    "geometryless feature",
+                )
+            })
             // A GeoJSON geometry can always be mapped to GeoRust geometry.
             .map(|geometry| {
                 geo::Geometry::try_from(&geometry.value).expect("geometry")
@@ -27,14 +27,25 @@ mod json;
 mod ring_hierarchy;
 mod to_geo;
 mod to_h3;
+#[cfg(feature = "topojson")]
+mod topojson;
 mod vertex_graph;
+mod viewport;
 
 use ring_hierarchy::RingHierarchy;
+use to_h3::merge_cells_at;
 use vertex_graph::VertexGraph;
 
+pub(crate) use geometry::is_polar;
 pub use geometry::{
-    Geometry, GeometryCollection, Line, LineString, MultiLineString,
-    MultiPoint, MultiPolygon, Point, Polygon, Rect, Triangle,
+    CellRelation, ContainmentMode, Geometry, GeometryCollection, Line,
+    LineString, MultiLineString, MultiPoint, MultiPolygon, Partition, Point,
+    Polygon, Rect, Triangle,
 };
+#[cfg(feature = "polyline")]
+pub use geometry::{Polyline, Precision};
 pub use to_geo::ToGeo;
 pub use to_h3::ToCells;
+#[cfg(feature = "topojson")]
+pub use topojson::Topology;
+pub use viewport::Viewport;
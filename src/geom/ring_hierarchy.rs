@@ -1,5 +1,6 @@
+use crate::math::mul_add;
 use geo::{Contains, Coord, LineString, MultiPolygon, Polygon};
-use std::iter::Peekable;
+use std::{f64::consts::PI, iter::Peekable};
 
 /// A rings hierarchy.
 pub struct RingHierarchy {
@@ -49,8 +50,19 @@ pub struct RingHierarchy {
 }
 
 impl RingHierarchy {
-    /// Builds a new hierarchy of rings.
+    /// Builds a new hierarchy of rings, whose coordinates are in degrees.
     pub fn new(rings: Vec<LineString<f64>>) -> Self {
+        Self::with_half_turn(rings, 180.)
+    }
+
+    /// Builds a new hierarchy of rings, whose coordinates are in radians.
+    pub fn new_radians(rings: Vec<LineString<f64>>) -> Self {
+        Self::with_half_turn(rings, PI)
+    }
+
+    /// Builds a new hierarchy of rings, using the given unit for half a turn
+    /// (i.e. 180° or π).
+    fn with_half_turn(rings: Vec<LineString<f64>>, half_turn: f64) -> Self {
         let is_assigned = vec![false; rings.len()];
 
         // Compute the hierarchy matrix.
@@ -63,11 +75,13 @@ impl RingHierarchy {
                 }
 
                 let r1 = LineString::new(
-                    r1.coords().map(adjust_coordinate).collect(),
+                    r1.coords()
+                        .map(|coord| adjust_coordinate(coord, half_turn))
+                        .collect(),
                 );
                 // We are guaranteed not to overlap, so just test the first
                 // point.
-                let r2 = adjust_coordinate(&r2.0[0]);
+                let r2 = adjust_coordinate(&r2.0[0], half_turn);
                 // Need to convert to Polygon to have the right `contains`
                 // algorithm.
                 if Polygon::new(r1, vec![]).contains(&r2) {
@@ -204,9 +218,9 @@ impl From<RingHierarchy> for MultiPolygon<f64> {
 // -----------------------------------------------------------------------------
 
 // Adjusts coordinates to handle transmeridian crossing.
-fn adjust_coordinate(coord: &Coord) -> Coord {
+fn adjust_coordinate(coord: &Coord, half_turn: f64) -> Coord {
     Coord {
-        x: f64::from(u8::from(coord.x < 0.) * 2).mul_add(180., coord.x),
+        x: mul_add(f64::from(u8::from(coord.x < 0.) * 2), half_turn, coord.x),
         y: coord.y,
     }
 }
@@ -2,7 +2,10 @@ use super::VertexGraph;
 use crate::{
     error::OutlinerError, CellIndex, DirectedEdgeIndex, LatLng, VertexIndex,
 };
-use geo::{Coord, Line, LineString, MultiPolygon, Point, Polygon};
+use geo::{
+    Coord, Line, LineString, MultiLineString, MultiPoint, MultiPolygon, Point,
+    Polygon,
+};
 use std::convert::Infallible;
 
 /// A trait to trace the outline of an H3 object.
@@ -33,6 +36,32 @@ where
     /// ```
     fn to_geom(self, use_degrees: bool) -> Result<Self::Output, Self::Error>;
 
+    /// Creates a geometry describing the outline(s), in radians.
+    ///
+    /// Equivalent to `to_geom(false)`: coordinates are computed directly in
+    /// radians, without a round-trip through degrees.
+    ///
+    /// # Errors
+    ///
+    /// Error conditions depend on the implementation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{CellIndex, Resolution, geom::ToGeo};
+    ///
+    /// let index = CellIndex::try_from(0x089283470803ffff)?;
+    /// let cells = index.children(Resolution::Twelve).collect::<Vec<_>>();
+    /// let geom = cells.to_geom_radians()?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    fn to_geom_radians(self) -> Result<Self::Output, Self::Error>
+    where
+        Self: Sized,
+    {
+        self.to_geom(false)
+    }
+
     /// Creates a `GeoJSON` geometry describing the outline(s).
     ///
     /// # Errors
@@ -60,17 +89,26 @@ where
 
 impl<T> ToGeo for T
 where
-    T: IntoIterator<Item = CellIndex>,
+    T: IntoIterator,
+    T::Item: sealed::CollectionToGeo,
+    geojson::Value:
+        for<'a> From<&'a <T::Item as sealed::CollectionToGeo>::Output>,
 {
-    type Error = OutlinerError;
-    type Output = MultiPolygon<f64>;
+    type Error = <T::Item as sealed::CollectionToGeo>::Error;
+    type Output = <T::Item as sealed::CollectionToGeo>::Output;
 
-    /// Creates a [`MultiPolygon`] describing the outline(s) of a set of cells.
+    /// Creates a geometry describing a collection of H3 objects:
+    ///
+    /// - a [`MultiPolygon`] describing the outline(s) of a set of cells.
+    /// - a [`MultiLineString`] made of the lines of a set of directed edges.
+    /// - a [`MultiPoint`] made of the points of a set of vertexes.
     ///
     /// # Errors
     ///
-    /// All cell indexes must be unique and have the same resolution, otherwise
-    /// [`OutlinerError`] is returned.
+    /// For cells, all indexes must be unique and have the same resolution,
+    /// otherwise [`OutlinerError`] is returned.
+    ///
+    /// Edges and vertexes conversion cannot fail.
     ///
     /// # Example
     ///
@@ -80,36 +118,19 @@ where
     /// let index = CellIndex::try_from(0x089283470803ffff)?;
     /// let cells = index.children(Resolution::Twelve).collect::<Vec<_>>();
     /// let geom = cells.to_geom(true)?;
+    ///
+    /// let edges = index.edges().collect::<Vec<_>>();
+    /// let lines = edges.to_geom(true).expect("cannot fail");
+    ///
+    /// let vertexes = index.vertexes().collect::<Vec<_>>();
+    /// let points = vertexes.to_geom(true).expect("cannot fail");
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     fn to_geom(self, use_degrees: bool) -> Result<Self::Output, Self::Error> {
-        let res: Result<MultiPolygon<f64>, Self::Error> =
-            VertexGraph::from_cells(self).map(Into::into);
-
-        if use_degrees {
-            return res;
-        }
-
-        res.map(|mut multipolygon| {
-            for polygon in &mut multipolygon {
-                polygon.exterior_mut(|exterior| {
-                    for coord in exterior.coords_mut() {
-                        coord.x = coord.x.to_radians();
-                        coord.y = coord.y.to_radians();
-                    }
-                });
-                polygon.interiors_mut(|interiors| {
-                    for interior in interiors.iter_mut() {
-                        for coord in interior.coords_mut() {
-                            coord.x = coord.x.to_radians();
-                            coord.y = coord.y.to_radians();
-                        }
-                    }
-                });
-            }
-
-            multipolygon
-        })
+        <T::Item as sealed::CollectionToGeo>::collection_to_geom(
+            self,
+            use_degrees,
+        )
     }
 }
 
@@ -133,14 +154,11 @@ impl ToGeo for CellIndex {
     /// # Ok::<(), h3o::error::InvalidCellIndex>(())
     /// ```
     fn to_geom(self, use_degrees: bool) -> Result<Self::Output, Self::Error> {
-        let mut boundary: LineString = self.boundary().into();
-
-        if !use_degrees {
-            for coord in boundary.coords_mut() {
-                coord.x = coord.x.to_radians();
-                coord.y = coord.y.to_radians();
-            }
-        }
+        let boundary = self
+            .boundary()
+            .iter()
+            .map(|ll| ll.to_coord(use_degrees))
+            .collect::<LineString>();
 
         Ok(Polygon::new(boundary, Vec::new()))
     }
@@ -166,19 +184,15 @@ impl ToGeo for DirectedEdgeIndex {
     /// # Ok::<(), h3o::error::InvalidDirectedEdgeIndex>(())
     /// ```
     fn to_geom(self, use_degrees: bool) -> Result<Self::Output, Self::Error> {
-        let mut coords: Vec<Coord<f64>> =
-            self.boundary().iter().copied().map(Into::into).collect();
+        let coords: Vec<Coord<f64>> = self
+            .boundary()
+            .iter()
+            .map(|ll| ll.to_coord(use_degrees))
+            .collect();
 
         // We only have two point (start and end) as boundary for an edge.
         assert_eq!(coords.len(), 2);
 
-        if !use_degrees {
-            coords[0].x = coords[0].x.to_radians();
-            coords[0].y = coords[0].y.to_radians();
-            coords[1].x = coords[1].x.to_radians();
-            coords[1].y = coords[1].y.to_radians();
-        }
-
         Ok(Line::new(coords[0], coords[1]))
     }
 }
@@ -203,13 +217,86 @@ impl ToGeo for VertexIndex {
     /// # Ok::<(), h3o::error::InvalidVertexIndex>(())
     /// ```
     fn to_geom(self, use_degrees: bool) -> Result<Self::Output, Self::Error> {
-        let mut coord: Coord<f64> = LatLng::from(self).into();
+        Ok(LatLng::from(self).to_coord(use_degrees).into())
+    }
+}
+
+// -----------------------------------------------------------------------------
+
+mod sealed {
+    use super::{
+        CellIndex, DirectedEdgeIndex, Infallible, LineString, MultiLineString,
+        MultiPoint, MultiPolygon, OutlinerError, ToGeo, VertexGraph,
+        VertexIndex,
+    };
+    use crate::trace;
+
+    /// H3 objects whose collections can be converted into a geometry.
+    pub trait CollectionToGeo: Sized {
+        /// Output geometry type.
+        type Output;
+        /// The type returned in the event of an outlining error.
+        type Error;
 
-        if !use_degrees {
-            coord.x = coord.x.to_radians();
-            coord.y = coord.y.to_radians();
+        /// Creates a geometry describing the collection.
+        fn collection_to_geom(
+            items: impl IntoIterator<Item = Self>,
+            use_degrees: bool,
+        ) -> Result<Self::Output, Self::Error>;
+    }
+
+    impl CollectionToGeo for CellIndex {
+        type Error = OutlinerError;
+        type Output = MultiPolygon<f64>;
+
+        fn collection_to_geom(
+            items: impl IntoIterator<Item = Self>,
+            use_degrees: bool,
+        ) -> Result<Self::Output, Self::Error> {
+            trace::outline(items, |cells| {
+                VertexGraph::from_cells(cells)
+                    .map(|graph| graph.into_multipolygon(use_degrees))
+            })
+        }
+    }
+
+    impl CollectionToGeo for DirectedEdgeIndex {
+        type Error = Infallible;
+        type Output = MultiLineString<f64>;
+
+        fn collection_to_geom(
+            items: impl IntoIterator<Item = Self>,
+            use_degrees: bool,
+        ) -> Result<Self::Output, Self::Error> {
+            let lines = items
+                .into_iter()
+                .map(|edge| {
+                    let Ok(line) = edge.to_geom(use_degrees);
+                    LineString::from(line)
+                })
+                .collect();
+
+            Ok(MultiLineString::new(lines))
         }
+    }
 
-        Ok(coord.into())
+    impl CollectionToGeo for VertexIndex {
+        type Error = Infallible;
+        type Output = MultiPoint<f64>;
+
+        fn collection_to_geom(
+            items: impl IntoIterator<Item = Self>,
+            use_degrees: bool,
+        ) -> Result<Self::Output, Self::Error> {
+            let points = items
+                .into_iter()
+                .map(|vertex| {
+                    let Ok(point) = vertex.to_geom(use_degrees);
+                    point
+                })
+                .collect();
+
+            Ok(MultiPoint::new(points))
+        }
     }
 }
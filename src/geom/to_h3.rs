@@ -46,4 +46,50 @@ pub trait ToCells {
         &self,
         resolution: Resolution,
     ) -> Box<dyn Iterator<Item = CellIndex> + '_>;
+
+    /// Computes the coverage of the input at several resolutions at once.
+    ///
+    /// Returns one list of cells per requested resolution, in the same order.
+    /// Areal geometries share the work between the resolutions, instead of
+    /// computing each coverage from scratch.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use geo::polygon;
+    /// use h3o::{Resolution, geom::{Polygon, ToCells}};
+    ///
+    /// let p: geo::Polygon<f64> = polygon![
+    ///     (x: 37.58601939796671, y: 55.72992682544245),
+    ///     (x: 37.66530173673016, y: 55.72992682544245),
+    ///     (x: 37.66530173673016, y: 55.777641325418415),
+    ///     (x: 37.58601939796671, y: 55.777641325418415),
+    ///     (x: 37.58601939796671, y: 55.72992682544245),
+    /// ];
+    /// let polygon = Polygon::from_degrees(p)?;
+    /// let resolutions = Resolution::range(Resolution::Five, Resolution::Ten)
+    ///     .collect::<Vec<_>>();
+    /// let pyramid = polygon.to_cells_at(&resolutions);
+    /// assert_eq!(pyramid.len(), resolutions.len());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    fn to_cells_at(&self, resolutions: &[Resolution]) -> Vec<Vec<CellIndex>> {
+        resolutions
+            .iter()
+            .map(|&resolution| self.to_cells(resolution).collect())
+            .collect()
+    }
+}
+
+/// Merges the multi-resolution coverages of several geometries.
+pub fn merge_cells_at(
+    coverages: impl Iterator<Item = Vec<Vec<CellIndex>>>,
+    count: usize,
+) -> Vec<Vec<CellIndex>> {
+    coverages.fold(vec![Vec::new(); count], |mut acc, coverage| {
+        for (cells, other) in acc.iter_mut().zip(coverage) {
+            cells.extend(other);
+        }
+        acc
+    })
 }
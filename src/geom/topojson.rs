@@ -0,0 +1,330 @@
+use crate::{
+    CellIndex, DirectedEdgeIndex, Direction, LatLng, UndirectedEdgeIndex,
+    VertexIndex,
+};
+use ahash::{HashMap, HashMapExt};
+use serde_json::{json, Value};
+
+/// A set of cells, as a `TopoJSON` topology.
+///
+/// The boundaries of the cells are split into arcs at the junctions (i.e. the
+/// vertices shared by several cells), and each arc between two adjacent cells
+/// is shared instead of being duplicated: combined with the quantization of
+/// the coordinates, this makes for an output about 2.4 times lighter than the
+/// `GeoJSON` equivalent (with 10,000 quantization steps).
+///
+/// This falls short of the sixfold reduction usually expected from `TopoJSON`:
+/// inside a coverage, every vertex is shared by three cells, thus an arc can't
+/// span more than a single edge, and every cell still carries its own ring of
+/// arc references and its index. Leaving the indexes out (see
+/// [`Self::to_compact_json`]) brings the gain to about 2.8 times, but the arcs
+/// alone already weigh more than a sixth of the `GeoJSON` output.
+///
+/// Every cell is a `Polygon` of the `cells` object, identified by its index.
+///
+/// # Example
+///
+/// ```
+/// use h3o::{CellIndex, geom::Topology};
+///
+/// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+/// let topology = index.grid_disk::<Topology>(1);
+/// assert_eq!(topology.arc_count(), 18);
+///
+/// let json = topology.to_json(Some(10_000)).to_string();
+/// # Ok::<(), h3o::error::InvalidCellIndex>(())
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Topology {
+    /// Cells, sorted, with the arcs of their ring (reversed arcs are
+    /// referenced by their one's complement).
+    cells: Vec<(CellIndex, Vec<i64>)>,
+    /// Arcs, as list of coordinates.
+    arcs: Vec<Vec<LatLng>>,
+}
+
+impl Topology {
+    /// Returns the number of distinct arcs.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{CellIndex, geom::Topology};
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let topology = std::iter::once(index).collect::<Topology>();
+    /// // Without junction, the whole ring is a single arc.
+    /// assert_eq!(topology.arc_count(), 1);
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    #[must_use]
+    pub const fn arc_count(&self) -> usize {
+        self.arcs.len()
+    }
+
+    /// Serializes the topology into a `TopoJSON` object.
+    ///
+    /// When `quantization` is set, coordinates are quantized on a grid of
+    /// `quantization` steps per dimension (clamped to at least 2) spanning the
+    /// bounding box of the cells, and the arcs are delta-encoded. Otherwise,
+    /// the coordinates are written as-is, in degrees.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{CellIndex, geom::Topology};
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let topology = index.grid_disk::<Topology>(2);
+    /// let json = topology.to_json(Some(1_000_000));
+    /// assert_eq!(json["type"], "Topology");
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    #[must_use]
+    pub fn to_json(&self, quantization: Option<u32>) -> Value {
+        self.serialize(quantization, true)
+    }
+
+    /// Serializes the topology into a `TopoJSON` object, without cell indexes.
+    ///
+    /// Same as [`Self::to_json`], except that the geometries aren't identified:
+    /// they follow the order of [`Self::cells`] instead, which saves about 15%
+    /// on large coverages.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{CellIndex, geom::Topology};
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let topology = index.grid_disk::<Topology>(2);
+    /// let json = topology.to_compact_json(Some(10_000));
+    /// let geometries = &json["objects"]["cells"]["geometries"];
+    /// assert!(geometries[0].get("id").is_none());
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    #[must_use]
+    pub fn to_compact_json(&self, quantization: Option<u32>) -> Value {
+        self.serialize(quantization, false)
+    }
+
+    /// Returns the cells of the topology, sorted, in the order of the
+    /// geometries.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{CellIndex, geom::Topology};
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let topology = vec![index, index].into_iter().collect::<Topology>();
+    /// assert_eq!(topology.cells().collect::<Vec<_>>(), vec![index]);
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    pub fn cells(&self) -> impl Iterator<Item = CellIndex> + '_ {
+        self.cells.iter().map(|&(cell, _)| cell)
+    }
+
+    /// Serializes the topology, with or without the cell indexes as IDs.
+    fn serialize(&self, quantization: Option<u32>, with_ids: bool) -> Value {
+        let geometries = self
+            .cells
+            .iter()
+            .map(|&(cell, ref ring)| {
+                let mut geometry = json!({
+                    "type": "Polygon",
+                    "arcs": [ring],
+                });
+                if with_ids {
+                    geometry["id"] = json!(cell.to_string());
+                }
+                geometry
+            })
+            .collect::<Vec<_>>();
+        let mut topology = json!({
+            "type": "Topology",
+            "objects": {
+                "cells": {
+                    "type": "GeometryCollection",
+                    "geometries": geometries,
+                },
+            },
+        });
+
+        let Some((min, max)) = self.bbox() else {
+            topology["arcs"] = json!([]);
+            return topology;
+        };
+        topology["bbox"] = json!([min.0, min.1, max.0, max.1]);
+        // Scale of the quantization grid (whose origin is the bottom-left
+        // corner).
+        let scale = quantization.map(|quantization| {
+            let steps = f64::from(quantization.max(2) - 1);
+            let scale = |min: f64, max: f64| {
+                if max > min {
+                    (max - min) / steps
+                } else {
+                    1.
+                }
+            };
+            (scale(min.0, max.0), scale(min.1, max.1))
+        });
+        if let Some(scale) = scale {
+            topology["transform"] = json!({
+                "scale": [scale.0, scale.1],
+                "translate": [min.0, min.1],
+            });
+        }
+        topology["arcs"] = self
+            .arcs
+            .iter()
+            .map(|arc| {
+                scale.map_or_else(
+                    || {
+                        arc.iter()
+                            .map(|ll| json!([ll.lng(), ll.lat()]))
+                            .collect()
+                    },
+                    |scale| quantize(arc, min, scale),
+                )
+            })
+            .collect();
+
+        topology
+    }
+
+    /// Returns the bounding box of the arcs, in degrees.
+    fn bbox(&self) -> Option<BBox> {
+        self.arcs.iter().flatten().fold(None, |bbox, ll| {
+            let (x, y) = (ll.lng(), ll.lat());
+            Some(bbox.map_or(((x, y), (x, y)), |(min, max)| {
+                ((x.min(min.0), y.min(min.1)), (x.max(max.0), y.max(max.1)))
+            }))
+        })
+    }
+}
+
+impl FromIterator<CellIndex> for Topology {
+    fn from_iter<T: IntoIterator<Item = CellIndex>>(iter: T) -> Self {
+        let mut cells = iter.into_iter().collect::<Vec<_>>();
+        cells.sort_unstable();
+        cells.dedup();
+
+        // Number of cells around each vertex: the vertices shared by several
+        // cells are the junctions, where the arcs start and end.
+        let mut counts = HashMap::new();
+        for vertex in cells.iter().flat_map(|cell| cell.vertexes()) {
+            *counts.entry(vertex).or_insert(0_u8) += 1;
+        }
+        let junction = |vertex: &VertexIndex| counts[vertex] > 1;
+
+        let mut arcs = Vec::new();
+        let mut ids = HashMap::new();
+        let cells = cells
+            .into_iter()
+            .map(|cell| {
+                // Edges, with their start vertex and whether it's a junction.
+                let mut edges = cell
+                    .edges()
+                    .map(|edge| {
+                        let vertex = Direction::from(edge.edge()).vertex(cell);
+                        let is_junction =
+                            cell.vertex(vertex).as_ref().is_some_and(junction);
+                        (edge, u8::from(vertex), is_junction)
+                    })
+                    .collect::<Vec<_>>();
+                // Sort the edges by start vertex, to follow the boundary, and
+                // start from a junction (if any).
+                edges.sort_unstable_by_key(|&(_, vertex, _)| vertex);
+                let start = edges
+                    .iter()
+                    .position(|&(_, _, is_junction)| is_junction)
+                    .unwrap_or_default();
+                edges.rotate_left(start);
+
+                // Split the ring into runs of edges between junctions.
+                let mut runs = Vec::<Vec<DirectedEdgeIndex>>::new();
+                for (edge, _, is_junction) in edges {
+                    match runs.last_mut() {
+                        Some(run) if !is_junction => run.push(edge),
+                        _ => runs.push(vec![edge]),
+                    }
+                }
+
+                let ring = runs
+                    .into_iter()
+                    .map(|run| {
+                        let next_id =
+                            i64::try_from(arcs.len()).expect("too many arcs");
+                        let &[edge] = run.as_slice() else {
+                            // Longer runs only border the current cell.
+                            arcs.push(run_boundary(&run));
+                            return next_id;
+                        };
+                        let undirected = UndirectedEdgeIndex::from(edge);
+                        let id = *ids.entry(undirected).or_insert_with(|| {
+                            // Arcs follow the canonical direction.
+                            let (canonical, _) = undirected.directed_edges();
+                            arcs.push(canonical.boundary().to_vec());
+                            next_id
+                        });
+                        if undirected.directed_edges().0 == edge {
+                            id
+                        } else {
+                            !id
+                        }
+                    })
+                    .collect();
+
+                (cell, ring)
+            })
+            .collect();
+
+        Self { cells, arcs }
+    }
+}
+
+// -----------------------------------------------------------------------------
+
+/// Minimum and maximum corners of a bounding box.
+type BBox = ((f64, f64), (f64, f64));
+
+/// Quantizes and delta-encodes an arc.
+fn quantize(arc: &[LatLng], origin: (f64, f64), scale: (f64, f64)) -> Value {
+    #[allow(clippy::cast_possible_truncation)] // Bounded by the steps count.
+    let points = arc.iter().map(|ll| {
+        (
+            ((ll.lng() - origin.0) / scale.0).round() as i64,
+            ((ll.lat() - origin.1) / scale.1).round() as i64,
+        )
+    });
+
+    let mut positions = Vec::with_capacity(arc.len());
+    let mut previous = (0, 0);
+    for point in points {
+        let delta = (point.0 - previous.0, point.1 - previous.1);
+        // Skip the points that are merged by the quantization.
+        if delta != (0, 0) || positions.is_empty() {
+            positions.push(json!([delta.0, delta.1]));
+        }
+        previous = point;
+    }
+    // An arc needs at least two positions.
+    if positions.len() < 2 {
+        positions.push(json!([0, 0]));
+    }
+
+    Value::Array(positions)
+}
+
+/// Returns the coordinates of a run of consecutive edges.
+fn run_boundary(run: &[DirectedEdgeIndex]) -> Vec<LatLng> {
+    let mut coords = Vec::new();
+    for edge in run {
+        let boundary = edge.boundary();
+        // Consecutive edges share their junction vertex.
+        let skip = usize::from(!coords.is_empty());
+        coords.extend(boundary.iter().skip(skip));
+    }
+    coords
+}
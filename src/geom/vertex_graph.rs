@@ -165,6 +165,53 @@ impl VertexGraph {
         self.nodes.is_empty()
     }
 
+    /// Consumes the graph into the [`MultiPolygon`] formed by its outline, in
+    /// degrees or in radians.
+    pub fn into_multipolygon(mut self, use_degrees: bool) -> MultiPolygon<f64> {
+        // No vertex, no shape.
+        if self.is_empty() {
+            return MultiPolygon::new(Vec::new());
+        }
+
+        let mut rings = Vec::new();
+        let mut coords = Vec::new();
+
+        while !self.is_empty() {
+            let (&from, to) =
+                self.nodes.iter().next().expect("non-empty graph");
+            let mut node = Node {
+                from: from.0,
+                to: to[0].vertex,
+            };
+            loop {
+                coords.push(node.from.to_coord(use_degrees));
+                let to = node.to;
+                self.remove(&node);
+                match self.get_from_vertex(to) {
+                    Some(next_node) => node = next_node,
+                    None => break,
+                }
+            }
+            assert!(coords.len() >= 4);
+            rings.push(LineString::new(coords.clone()));
+            coords.clear();
+        }
+
+        // If we have a single ring, the resulting shape is obvious.
+        if rings.len() == 1 {
+            return MultiPolygon::new(vec![Polygon::new(
+                rings.swap_remove(0),
+                Vec::new(),
+            )]);
+        }
+
+        if use_degrees {
+            RingHierarchy::new(rings).into()
+        } else {
+            RingHierarchy::new_radians(rings).into()
+        }
+    }
+
     /// Lookup edges starting from `coord`.
     fn get_mut(
         &mut self,
@@ -207,46 +254,3 @@ impl VertexGraph {
         nodes
     }
 }
-
-impl From<VertexGraph> for MultiPolygon<f64> {
-    fn from(mut value: VertexGraph) -> Self {
-        // No vertex, no shape.
-        if value.is_empty() {
-            return Self::new(Vec::new());
-        }
-
-        let mut rings = Vec::new();
-        let mut coords = Vec::new();
-
-        while !value.is_empty() {
-            let (&from, to) =
-                value.nodes.iter().next().expect("non-empty graph");
-            let mut node = Node {
-                from: from.0,
-                to: to[0].vertex,
-            };
-            loop {
-                coords.push(node.from.into());
-                let to = node.to;
-                value.remove(&node);
-                match value.get_from_vertex(to) {
-                    Some(next_node) => node = next_node,
-                    None => break,
-                }
-            }
-            assert!(coords.len() >= 4);
-            rings.push(LineString::new(coords.clone()));
-            coords.clear();
-        }
-
-        // If we have a single ring, the resulting shape is obvious.
-        if rings.len() == 1 {
-            return Self::new(vec![Polygon::new(
-                rings.swap_remove(0),
-                Vec::new(),
-            )]);
-        }
-
-        RingHierarchy::new(rings).into()
-    }
-}
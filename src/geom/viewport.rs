@@ -0,0 +1,214 @@
+use crate::{
+    error::{InvalidGeometry, InvalidGeometryKind},
+    geom::{ContainmentMode, Polygon},
+    math::{asinh, atan, cos, exp2, log2, mul_add, sinh, tan},
+    resolution::TILE_SIZE,
+    CellIndex, LatLng, Resolution,
+};
+use std::f64::consts::PI;
+
+/// Maximum zoom level supported by web maps.
+const MAX_ZOOM: f64 = 30.;
+
+/// Targeted width, in pixels, of the cells displayed in a viewport.
+const CELL_SIZE_PX: f64 = 32.;
+
+/// Latitude limit, in degrees, of the web-mercator projection (which makes the
+/// world square).
+const MAX_LATITUDE: f64 = 85.051_128_779_806_59;
+
+/// A web-mercator map viewport, as displayed by web map frontends.
+///
+/// # Example
+///
+/// ```
+/// use h3o::{geom::Viewport, LatLng};
+///
+/// let center = LatLng::new(48.864716, 2.349014)?;
+/// let viewport = Viewport::new(center, 12., 1024, 768)?;
+/// let cells = viewport.to_cells(viewport.resolution());
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Viewport {
+    center: LatLng,
+    zoom: f64,
+    width: u32,
+    height: u32,
+}
+
+impl Viewport {
+    /// Initializes a new viewport, from its center, its zoom level and its
+    /// size in pixels.
+    ///
+    /// # Errors
+    ///
+    /// [`InvalidGeometry`] if the zoom level isn't within `[0; 30]` or if the
+    /// viewport is empty.
+    pub fn new(
+        center: LatLng,
+        zoom: f64,
+        width: u32,
+        height: u32,
+    ) -> Result<Self, InvalidGeometry> {
+        if !(0. ..=MAX_ZOOM).contains(&zoom) {
+            return Err(InvalidGeometry::new(
+                InvalidGeometryKind::Parameter,
+                "invalid zoom level",
+            ));
+        }
+        if width == 0 || height == 0 {
+            return Err(InvalidGeometry::new(
+                InvalidGeometryKind::Parameter,
+                "empty viewport",
+            ));
+        }
+
+        Ok(Self {
+            center,
+            zoom,
+            width,
+            height,
+        })
+    }
+
+    /// Returns the resolution whose cells are about 32 pixels wide at the
+    /// center of the viewport.
+    ///
+    /// Centers beyond the limits of the projection (e.g. the poles) are
+    /// handled as if they were on the edge of the map.
+    #[must_use]
+    pub fn resolution(&self) -> Resolution {
+        // Features are stretched by `1 / cos(lat)` by the projection, which
+        // is equivalent to zooming in.
+        // The stretch is unbounded near the poles, hence the clamping.
+        let max_lat = MAX_LATITUDE.to_radians();
+        let lat = self.center.lat_radians().clamp(-max_lat, max_lat);
+        let stretch = -log2(cos(lat));
+        Resolution::for_zoom(self.zoom + stretch, CELL_SIZE_PX)
+    }
+
+    /// Returns the visible area, as longitude/latitude rectangles in degrees.
+    ///
+    /// Viewports crossing the antimeridian are split in two.
+    #[must_use]
+    pub fn bounds(&self) -> Vec<geo::Rect<f64>> {
+        let world = TILE_SIZE * exp2(self.zoom);
+        let (x, y) = project(self.center, world);
+        let (half_width, half_height) =
+            (f64::from(self.width) / 2., f64::from(self.height) / 2.);
+
+        // Latitudes are clamped by the projection, longitudes wrap around.
+        let north = unproject_y((y - half_height).max(0.), world);
+        let south = unproject_y((y + half_height).min(world), world);
+        if 2. * half_width >= world {
+            return vec![rect(-180., 180., south, north)];
+        }
+        let west = mul_add((x - half_width) / world, 360., -180.);
+        let east = mul_add((x + half_width) / world, 360., -180.);
+
+        if west < -180. {
+            vec![
+                rect(west + 360., 180., south, north),
+                rect(-180., east, south, north),
+            ]
+        } else if east > 180. {
+            vec![
+                rect(west, 180., south, north),
+                rect(-180., east - 360., south, north),
+            ]
+        } else {
+            vec![rect(west, east, south, north)]
+        }
+    }
+
+    /// Computes the cells, at the given resolution, visible (if only partly)
+    /// in the viewport.
+    ///
+    /// Cells are returned sorted and without duplicates.
+    ///
+    /// When the whole world is visible, every cell is returned (including the
+    /// polar ones, beyond the limits of the projection).
+    #[must_use]
+    pub fn to_cells(&self, resolution: Resolution) -> Vec<CellIndex> {
+        if self.is_whole_world() {
+            return CellIndex::base_cells()
+                .flat_map(|cell| cell.children(resolution))
+                .collect();
+        }
+
+        let mut cells = self
+            .bounds()
+            .into_iter()
+            .flat_map(split)
+            // Bounds are always finite, thus valid.
+            .filter_map(|bounds| {
+                Polygon::from_degrees(bounds.to_polygon()).ok()
+            })
+            .flat_map(|polygon| {
+                polygon
+                    .to_cells_with_mode(
+                        resolution,
+                        ContainmentMode::Overlapping,
+                    )
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        cells.sort_unstable();
+        cells.dedup();
+
+        cells
+    }
+
+    /// Returns true if the viewport shows the whole web-mercator world.
+    fn is_whole_world(&self) -> bool {
+        let world = TILE_SIZE * exp2(self.zoom);
+        let (_, y) = project(self.center, world);
+        let (half_width, half_height) =
+            (f64::from(self.width) / 2., f64::from(self.height) / 2.);
+
+        2. * half_width >= world
+            && y - half_height <= 0.
+            && y + half_height >= world
+    }
+}
+
+/// Splits a rectangle more than 180° wide in two halves.
+///
+/// Wider polygons would be read as crossing the antimeridian, i.e. covering
+/// the complement of the rectangle.
+fn split(bounds: geo::Rect<f64>) -> Vec<geo::Rect<f64>> {
+    let (min, max) = (bounds.min(), bounds.max());
+    if max.x - min.x <= 180. {
+        return vec![bounds];
+    }
+    let middle = mul_add(max.x - min.x, 0.5, min.x);
+
+    vec![
+        rect(min.x, middle, min.y, max.y),
+        rect(middle, max.x, min.y, max.y),
+    ]
+}
+
+/// Projects a coordinate onto a web-mercator world of the given size, in
+/// pixels.
+fn project(ll: LatLng, world: f64) -> (f64, f64) {
+    let x = (ll.lng() + 180.) / 360. * world;
+    let lat = ll.lat_radians();
+    let y = (1. - asinh(tan(lat)) / PI) / 2. * world;
+
+    (x, y.clamp(0., world))
+}
+
+/// Returns the latitude, in degrees, of a pixel row of a web-mercator world.
+fn unproject_y(y: f64, world: f64) -> f64 {
+    atan(sinh(PI * (1. - 2. * y / world))).to_degrees()
+}
+
+/// Builds a longitude/latitude rectangle from its edges, in degrees.
+fn rect(west: f64, east: f64, south: f64, north: f64) -> geo::Rect<f64> {
+    geo::Rect::new(
+        geo::coord! { x: west, y: south },
+        geo::coord! { x: east, y: north },
+    )
+}
@@ -0,0 +1,53 @@
+//! Graph representations of the H3 grid.
+//!
+//! Cells are the nodes of the graph, and the neighboring relationships between
+//! cells are the edges.
+
+#[cfg(feature = "petgraph")]
+mod petgraph;
+mod search;
+
+use crate::{CellIndex, CellSet, DirectedEdgeIndex, LatLng, Resolution};
+
+#[cfg(feature = "petgraph")]
+pub use self::petgraph::{to_csr, to_graph};
+pub use search::{astar, dijkstra};
+
+/// A graph whose nodes are cells, and edges are neighboring relationships.
+///
+/// Only the blocked cells predicate is required, the other methods use the
+/// regular grid by default.
+pub trait GridGraph {
+    /// Returns true if the cell cannot be traversed (i.e. it's not part of the
+    /// graph).
+    fn is_blocked(&self, cell: CellIndex) -> bool;
+
+    /// Returns the candidate neighbors of a cell.
+    ///
+    /// Blocked neighbors are filtered out by the graph algorithms.
+    fn neighbors(&self, cell: CellIndex) -> impl Iterator<Item = CellIndex> {
+        cell.edges().map(DirectedEdgeIndex::destination)
+    }
+
+    /// Returns the cost of moving from a cell to one of its neighbors.
+    ///
+    /// Defaults to the great circle distance between the cell centers, in
+    /// radians.
+    fn weight(&self, from: CellIndex, to: CellIndex) -> f64 {
+        LatLng::from(from).distance_rads(LatLng::from(to))
+    }
+}
+
+/// The set of cells, and only them, are part of the graph.
+impl GridGraph for CellSet {
+    fn is_blocked(&self, cell: CellIndex) -> bool {
+        !self.contains(cell)
+    }
+}
+
+/// Every cell, at the given resolution, is part of the graph.
+impl GridGraph for Resolution {
+    fn is_blocked(&self, cell: CellIndex) -> bool {
+        cell.resolution() != *self
+    }
+}
@@ -0,0 +1,127 @@
+use crate::{CellIndex, DirectedEdgeIndex};
+use ahash::HashMap;
+use petgraph::{csr::Csr, graph::UnGraph};
+
+/// Builds an undirected graph from a set of cells.
+///
+/// Each cell is a node of the graph, and neighboring cells are connected by an
+/// edge (weighted by `weight`). Since the graph is undirected, `weight` is
+/// called only once per pair of neighbors, with one of the two directed edges.
+///
+/// Any source of cells can be used, such as a [`CellSet`](crate::CellSet) or
+/// every cell at a given resolution within a region (e.g. using
+/// `geom::Rect::to_cells`). Duplicates are ignored, and only cells sharing the
+/// same resolution can be connected.
+///
+/// # Example
+///
+/// ```
+/// use h3o::{graph, CellIndex};
+///
+/// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+/// let graph = graph::to_graph(
+///     index.grid_disk::<Vec<_>>(1),
+///     |edge| edge.length_m(),
+/// );
+/// assert_eq!(graph.node_count(), 7);
+/// assert_eq!(graph.edge_count(), 12);
+/// # Ok::<(), h3o::error::InvalidCellIndex>(())
+/// ```
+pub fn to_graph<W>(
+    cells: impl IntoIterator<Item = CellIndex>,
+    mut weight: impl FnMut(DirectedEdgeIndex) -> W,
+) -> UnGraph<CellIndex, W> {
+    let cells = sorted_cells(cells);
+    let mut graph = UnGraph::with_capacity(cells.len(), cells.len() * 3);
+    let nodes = cells
+        .iter()
+        .map(|&cell| (cell, graph.add_node(cell)))
+        .collect::<HashMap<_, _>>();
+
+    for (&cell, node) in cells.iter().zip(graph.node_indices()) {
+        for edge in cell.edges() {
+            let destination = edge.destination();
+            // Only add the edge once, from the smallest cell.
+            if cell < destination {
+                if let Some(&neighbor) = nodes.get(&destination) {
+                    graph.add_edge(node, neighbor, weight(edge));
+                }
+            }
+        }
+    }
+
+    graph
+}
+
+/// Builds a compressed sparse row graph from a set of cells.
+///
+/// Each cell is a node of the graph, and neighboring cells are connected by
+/// two edges, one in each direction (weighted by `weight`).
+///
+/// Since `Csr` requires default-constructible node weights, the nodes don't
+/// carry their cell: instead, the cell of the node `i` is the `i`-th cell of
+/// the returned list.
+///
+/// # Example
+///
+/// ```
+/// use h3o::{graph, CellIndex};
+///
+/// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+/// let (graph, cells) = graph::to_csr(
+///     index.grid_disk::<Vec<_>>(1),
+///     |_| (),
+/// );
+/// assert_eq!(graph.node_count(), 7);
+/// assert_eq!(graph.edge_count(), 24);
+/// # Ok::<(), h3o::error::InvalidCellIndex>(())
+/// ```
+pub fn to_csr<W: Clone>(
+    cells: impl IntoIterator<Item = CellIndex>,
+    mut weight: impl FnMut(DirectedEdgeIndex) -> W,
+) -> (Csr<(), W>, Vec<CellIndex>) {
+    let cells = sorted_cells(cells);
+    let nodes = cells
+        .iter()
+        .enumerate()
+        .map(|(i, &cell)| (cell, node_id(i)))
+        .collect::<HashMap<_, _>>();
+
+    // Csr requires edges sorted by source, then by destination.
+    let mut edges = Vec::with_capacity(cells.len() * 6);
+    for (i, cell) in cells.iter().enumerate() {
+        let start = edges.len();
+        edges.extend(cell.edges().filter_map(|edge| {
+            nodes
+                .get(&edge.destination())
+                .map(|&neighbor| (node_id(i), neighbor, weight(edge)))
+        }));
+        edges[start..].sort_unstable_by_key(|&(_, neighbor, _)| neighbor);
+    }
+
+    (build_csr(&edges, cells.len()), cells)
+}
+
+/// Builds a `Csr` with `count` nodes from a list of sorted edges.
+fn build_csr<W: Clone>(edges: &[(u32, u32, W)], count: usize) -> Csr<(), W> {
+    let mut graph = Csr::from_sorted_edges(edges).expect("sorted edges");
+    // The nodes without neighbors at the end of the list must be added
+    // explicitly.
+    while graph.node_count() < count {
+        graph.add_node(());
+    }
+    graph
+}
+
+/// Returns the cells, sorted and deduplicated.
+fn sorted_cells(cells: impl IntoIterator<Item = CellIndex>) -> Vec<CellIndex> {
+    let mut cells = cells.into_iter().collect::<Vec<_>>();
+    cells.sort_unstable();
+    cells.dedup();
+    cells
+}
+
+/// Converts a node position into a `Csr` node identifier.
+fn node_id(position: usize) -> u32 {
+    u32::try_from(position).expect("too many nodes")
+}
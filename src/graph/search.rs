@@ -0,0 +1,151 @@
+use super::GridGraph;
+use crate::CellIndex;
+use ahash::{HashMap, HashMapExt};
+use std::{cmp::Ordering, collections::BinaryHeap};
+
+/// Computes the shortest path between two cells, using the A* algorithm.
+///
+/// The `heuristic` estimates the cost from a cell to the goal and must never
+/// overestimate it (e.g. with the default weights, the great circle distance
+/// to the goal center is a good fit).
+///
+/// Returns the path (including both ends) and its total cost, or `None` if
+/// the goal is not reachable.
+///
+/// Note that on an infinite graph (e.g. a whole [`Resolution`](crate::Resolution)
+/// with blocked cells cutting the goal off), the search may not terminate.
+///
+/// # Example
+///
+/// ```
+/// use h3o::{graph, CellIndex, LatLng, Resolution};
+///
+/// let src = CellIndex::try_from(0x8a1fb46622dffff)?;
+/// let dst = CellIndex::try_from(0x8a1fb46622d7fff)?;
+/// let goal = LatLng::from(dst);
+/// let (path, cost) = graph::astar(&Resolution::Ten, src, dst, |cell| {
+///     LatLng::from(cell).distance_rads(goal)
+/// }).expect("path");
+/// assert_eq!(path, vec![src, dst]);
+/// # Ok::<(), h3o::error::InvalidCellIndex>(())
+/// ```
+pub fn astar<G: GridGraph>(
+    graph: &G,
+    start: CellIndex,
+    goal: CellIndex,
+    mut heuristic: impl FnMut(CellIndex) -> f64,
+) -> Option<(Vec<CellIndex>, f64)> {
+    if graph.is_blocked(start) || graph.is_blocked(goal) {
+        return None;
+    }
+
+    // Best known cost, and the previous cell on the path, for each cell.
+    let mut visited = HashMap::new();
+    let mut queue = BinaryHeap::new();
+    visited.insert(start, (0., None));
+    queue.push(State {
+        priority: heuristic(start),
+        cost: 0.,
+        cell: start,
+    });
+
+    while let Some(State { cost, cell, .. }) = queue.pop() {
+        if cell == goal {
+            return Some((build_path(&visited, goal), cost));
+        }
+        // Skip outdated entries, a shorter path has already been found.
+        if visited.get(&cell).is_some_and(|&(best, _)| cost > best) {
+            continue;
+        }
+
+        for neighbor in graph.neighbors(cell) {
+            if graph.is_blocked(neighbor) {
+                continue;
+            }
+            let next = cost + graph.weight(cell, neighbor);
+            if visited.get(&neighbor).is_none_or(|&(best, _)| next < best) {
+                visited.insert(neighbor, (next, Some(cell)));
+                queue.push(State {
+                    priority: next + heuristic(neighbor),
+                    cost: next,
+                    cell: neighbor,
+                });
+            }
+        }
+    }
+
+    None
+}
+
+/// Computes the shortest path between two cells, using Dijkstra's algorithm.
+///
+/// Returns the path (including both ends) and its total cost, or `None` if
+/// the goal is not reachable.
+///
+/// # Example
+///
+/// ```
+/// use h3o::{graph, CellIndex, CellSet};
+///
+/// let src = CellIndex::try_from(0x8a1fb46622dffff)?;
+/// let dst = CellIndex::try_from(0x8a1fb46622d7fff)?;
+/// let cells = src.grid_disk::<CellSet>(2);
+/// let (path, cost) = graph::dijkstra(&cells, src, dst).expect("path");
+/// assert_eq!(path, vec![src, dst]);
+/// # Ok::<(), h3o::error::InvalidCellIndex>(())
+/// ```
+pub fn dijkstra<G: GridGraph>(
+    graph: &G,
+    start: CellIndex,
+    goal: CellIndex,
+) -> Option<(Vec<CellIndex>, f64)> {
+    astar(graph, start, goal, |_| 0.)
+}
+
+/// Rebuilds the path leading to `goal`.
+fn build_path(
+    visited: &HashMap<CellIndex, (f64, Option<CellIndex>)>,
+    goal: CellIndex,
+) -> Vec<CellIndex> {
+    let mut path = vec![goal];
+    let mut current = goal;
+    while let Some(&(_, Some(previous))) = visited.get(&current) {
+        path.push(previous);
+        current = previous;
+    }
+    path.reverse();
+    path
+}
+
+// -----------------------------------------------------------------------------
+
+/// A cell in the search queue.
+struct State {
+    /// Estimated total cost of a path going through the cell.
+    priority: f64,
+    /// Cost of the path from the start to the cell.
+    cost: f64,
+    /// The cell.
+    cell: CellIndex,
+}
+
+impl Ord for State {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Reversed, to turn the max-heap into a min-heap.
+        other.priority.total_cmp(&self.priority)
+    }
+}
+
+impl PartialOrd for State {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for State {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for State {}
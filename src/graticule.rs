@@ -0,0 +1,160 @@
+use crate::{error::InvalidGraticuleStep, grid, CellIndex, LatLng, Resolution};
+use std::f64::consts::FRAC_PI_2;
+
+/// A graticule: meridians and parallels drawn at regular intervals.
+///
+/// Lines are drawn at every multiple of the steps, meridians going from pole
+/// to pole and parallels around the globe (the poles themselves are reached
+/// by the meridians).
+///
+/// # Example
+///
+/// ```
+/// use h3o::{Graticule, Resolution};
+///
+/// let graticule = Graticule::new(10., 10.)?;
+/// let cells = graticule.to_cells(Resolution::Two).collect::<Vec<_>>();
+/// # Ok::<(), h3o::error::InvalidGraticuleStep>(())
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Graticule {
+    /// Interval between two parallels, in degrees.
+    lat_step: f64,
+    /// Interval between two meridians, in degrees.
+    lng_step: f64,
+}
+
+impl Graticule {
+    /// Smallest accepted step, in degrees.
+    ///
+    /// That's about 1km at the equator, already tens of thousands of lines.
+    pub const MIN_STEP: f64 = 0.01;
+
+    /// Initializes a new graticule with a parallel every `lat_step` degrees of
+    /// latitude and a meridian every `lng_step` degrees of longitude.
+    ///
+    /// # Errors
+    ///
+    /// [`InvalidGraticuleStep`] when one (or both) steps is not a finite
+    /// number of at least [`Self::MIN_STEP`] degrees.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::Graticule;
+    ///
+    /// assert!(Graticule::new(15., 30.).is_ok());
+    /// assert!(Graticule::new(0., 30.).is_err());
+    /// ```
+    pub fn new(
+        lat_step: f64,
+        lng_step: f64,
+    ) -> Result<Self, InvalidGraticuleStep> {
+        // Also rejects NaN.
+        if !(lat_step.is_finite() && lat_step >= Self::MIN_STEP) {
+            return Err(InvalidGraticuleStep::new(
+                lat_step,
+                "invalid latitude step",
+            ));
+        }
+        if !(lng_step.is_finite() && lng_step >= Self::MIN_STEP) {
+            return Err(InvalidGraticuleStep::new(
+                lng_step,
+                "invalid longitude step",
+            ));
+        }
+
+        Ok(Self { lat_step, lng_step })
+    }
+
+    /// Returns the latitudes of the parallels, in degrees.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::Graticule;
+    ///
+    /// let graticule = Graticule::new(45., 90.)?;
+    /// assert_eq!(graticule.parallels().collect::<Vec<_>>(), vec![-45., 0., 45.]);
+    /// # Ok::<(), h3o::error::InvalidGraticuleStep>(())
+    /// ```
+    pub fn parallels(self) -> impl Iterator<Item = f64> {
+        // Poles aren't lines, they are covered by the meridians.
+        multiples(self.lat_step, -90., 90.).filter(|lat| lat.abs() < 90.)
+    }
+
+    /// Returns the longitudes of the meridians, in degrees.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::Graticule;
+    ///
+    /// let graticule = Graticule::new(45., 90.)?;
+    /// assert_eq!(
+    ///     graticule.meridians().collect::<Vec<_>>(),
+    ///     vec![-180., -90., 0., 90.]
+    /// );
+    /// # Ok::<(), h3o::error::InvalidGraticuleStep>(())
+    /// ```
+    pub fn meridians(self) -> impl Iterator<Item = f64> {
+        // The antimeridian is drawn once, at -180°.
+        multiples(self.lng_step, -180., 180.).filter(|&lng| lng < 180.)
+    }
+
+    /// Computes the cells, at the given resolution, intersected by the lines
+    /// of the graticule.
+    ///
+    /// Cells are returned sorted and without duplicates.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{Graticule, Resolution};
+    ///
+    /// let graticule = Graticule::new(30., 30.)?;
+    /// let cells = graticule.to_cells(Resolution::One).collect::<Vec<_>>();
+    /// # Ok::<(), h3o::error::InvalidGraticuleStep>(())
+    /// ```
+    pub fn to_cells(
+        self,
+        resolution: Resolution,
+    ) -> impl Iterator<Item = CellIndex> {
+        let meridians = self.meridians().flat_map(move |lng| {
+            let lng = lng.to_radians();
+            let (south, equator, north) = (
+                LatLng::new_unchecked(-FRAC_PI_2, lng),
+                LatLng::new_unchecked(0., lng),
+                LatLng::new_unchecked(FRAC_PI_2, lng),
+            );
+            // Split in two arcs, the great circle between two antipodes being
+            // undefined.
+            grid::arc_cells(south, equator, resolution)
+                .into_iter()
+                .chain(grid::arc_cells(equator, north, resolution))
+        });
+        let parallels = self.parallels().flat_map(move |lat| {
+            grid::parallel_cells(lat.to_radians(), resolution)
+        });
+
+        let mut cells = meridians
+            .chain(parallels)
+            .map(|(cell, _)| cell)
+            .collect::<Vec<_>>();
+        cells.sort_unstable();
+        cells.dedup();
+
+        cells.into_iter()
+    }
+}
+
+/// Returns the multiples of `step` within `[min, max]`.
+fn multiples(step: f64, min: f64, max: f64) -> impl Iterator<Item = f64> {
+    // Steps are bounded by `Graticule::MIN_STEP`, no overflow here.
+    #[allow(clippy::cast_possible_truncation)]
+    let (first, last) =
+        ((min / step).ceil() as i64, (max / step).floor() as i64);
+
+    #[allow(clippy::cast_precision_loss)] // Line count fits in 52 bits.
+    (first..=last).map(move |k| k as f64 * step)
+}
@@ -0,0 +1,148 @@
+//! Great circle arc (and parallel) tracing.
+
+use crate::{
+    math::{cos, mul_add},
+    CellIndex, LatLng, Resolution, TWO_PI,
+};
+use std::f64::consts::PI;
+
+/// Number of samples taken per (average) cell edge length.
+///
+/// Must be small enough to never step over a whole cell, even where cells are
+/// the most distorted.
+const SAMPLES_PER_EDGE: f64 = 4.;
+
+/// Precision, in radians, of the cell boundaries crossing (~6µm).
+const TOLERANCE: f64 = 1e-12;
+
+/// Traces the cells crossed by the great circle arc from `start` to `end`.
+///
+/// Returns the cells, in the order they are crossed, along with the length
+/// (in radians) of the section of the arc that lies within each of them.
+///
+/// Note that a cell may appear more than once, if the arc leaves it and comes
+/// back later.
+pub fn arc_cells(
+    start: LatLng,
+    end: LatLng,
+    resolution: Resolution,
+) -> Vec<(CellIndex, f64)> {
+    let distance = start.distance_rads(end);
+    trace(
+        |fraction| start.interpolate(end, fraction),
+        distance,
+        resolution,
+    )
+}
+
+/// Traces the cells crossed by the parallel at latitude `lat` (in radians),
+/// eastward from the antimeridian.
+///
+/// Returns the cells, in the order they are crossed, along with the length
+/// (in radians) of the section of the parallel that lies within each of them.
+pub fn parallel_cells(
+    lat: f64,
+    resolution: Resolution,
+) -> Vec<(CellIndex, f64)> {
+    let distance = TWO_PI * cos(lat).abs();
+    trace(
+        |fraction| LatLng::new_unchecked(lat, mul_add(fraction, TWO_PI, -PI)),
+        distance,
+        resolution,
+    )
+}
+
+/// Traces the cells crossed by a curve of the given length (in radians).
+///
+/// `curve` maps a fraction of the curve (in `[0; 1]`) to the point located
+/// there.
+fn trace(
+    curve: impl Fn(f64) -> LatLng,
+    distance: f64,
+    resolution: Resolution,
+) -> Vec<(CellIndex, f64)> {
+    let origin = curve(0.).to_cell(resolution);
+    if distance < TOLERANCE {
+        return vec![(origin, distance)];
+    }
+
+    let mut tracer = Tracer {
+        curve,
+        resolution,
+        distance,
+        cells: Vec::new(),
+        run_start: 0.,
+    };
+
+    let step = resolution.edge_length_rads() / SAMPLES_PER_EDGE;
+    // Truncate on purpose, the value is positive and reasonably small.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let count = (distance / step).ceil().max(1.) as u64;
+
+    let mut prev = (0., origin);
+    for i in 1..=count {
+        #[allow(clippy::cast_precision_loss)] // Sample count fits in 52 bits.
+        let fraction = i as f64 / count as f64;
+        let cell = tracer.cell_at(fraction);
+        if cell != prev.1 {
+            tracer.split(prev, (fraction, cell));
+        }
+        prev = (fraction, cell);
+    }
+    tracer
+        .cells
+        .push((prev.1, (1. - tracer.run_start) * distance));
+
+    tracer.cells
+}
+
+// -----------------------------------------------------------------------------
+
+/// State of a curve tracing.
+struct Tracer<F> {
+    /// Point located at a given fraction of the curve.
+    curve: F,
+    /// Resolution of the traced cells.
+    resolution: Resolution,
+    /// Length of the curve, in radians.
+    distance: f64,
+
+    /// Cells crossed so far, with their lengths.
+    cells: Vec<(CellIndex, f64)>,
+    /// Position (as a fraction of the curve) where the current cell starts.
+    run_start: f64,
+}
+
+impl<F> Tracer<F>
+where
+    F: Fn(f64) -> LatLng,
+{
+    /// Returns the cell containing the point at `fraction` of the curve.
+    fn cell_at(&self, fraction: f64) -> CellIndex {
+        (self.curve)(fraction).to_cell(self.resolution)
+    }
+
+    /// Finds, by bisection, where the curve moves from cell `a` to cell `b`.
+    ///
+    /// Any cell found in between is recorded as well.
+    fn split(&mut self, a: (f64, CellIndex), b: (f64, CellIndex)) {
+        if (b.0 - a.0) * self.distance < TOLERANCE {
+            let boundary = a.0 + (b.0 - a.0) / 2.;
+            self.cells
+                .push((a.1, (boundary - self.run_start) * self.distance));
+            self.run_start = boundary;
+            return;
+        }
+
+        let middle = a.0 + (b.0 - a.0) / 2.;
+        let cell = self.cell_at(middle);
+        if cell == a.1 {
+            self.split((middle, cell), b);
+        } else if cell == b.1 {
+            self.split(a, (middle, cell));
+        } else {
+            self.split(a, (middle, cell));
+            self.split((middle, cell), b);
+        }
+    }
+}
@@ -0,0 +1,50 @@
+/// Distance, in grid cells, between two cells.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+#[allow(clippy::exhaustive_enums)] // A distance is either exact or not.
+pub enum GridDistance {
+    /// Exact distance, as computed on the grid.
+    Exact(i32),
+    /// Estimated distance, derived from the great circle distance between the
+    /// cell centers.
+    Approximate(i32),
+}
+
+impl GridDistance {
+    /// Returns the distance, regardless of its exactness.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::GridDistance;
+    ///
+    /// assert_eq!(GridDistance::Exact(3).value(), 3);
+    /// assert_eq!(GridDistance::Approximate(3).value(), 3);
+    /// ```
+    #[must_use]
+    pub const fn value(self) -> i32 {
+        match self {
+            Self::Exact(value) | Self::Approximate(value) => value,
+        }
+    }
+
+    /// Returns true if the distance is exact.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::GridDistance;
+    ///
+    /// assert!(GridDistance::Exact(3).is_exact());
+    /// assert!(!GridDistance::Approximate(3).is_exact());
+    /// ```
+    #[must_use]
+    pub const fn is_exact(self) -> bool {
+        matches!(self, Self::Exact(_))
+    }
+}
+
+impl From<GridDistance> for i32 {
+    fn from(value: GridDistance) -> Self {
+        value.value()
+    }
+}
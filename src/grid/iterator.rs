@@ -1,5 +1,7 @@
 use crate::{CellIndex, Direction};
 use ahash::{HashSet, HashSetExt};
+#[cfg(feature = "arrayvec")]
+use arrayvec::ArrayVec;
 use std::collections::VecDeque;
 
 /// Direction to the next ring.
@@ -83,6 +85,45 @@ impl Iterator for DiskDistancesSafe {
 
 // -----------------------------------------------------------------------------
 
+/// Indexes within k distance of the origin, in a fixed-capacity array.
+///
+/// Same traversal (and order) as `DiskDistancesSafe`, but the output doubles as
+/// the queue, since cells are appended ring by ring, and the deduplication is
+/// a linear scan.
+///
+/// The capacity `N` must be at least `max_grid_disk_size(k)`.
+#[cfg(feature = "arrayvec")]
+pub fn disk_safe_array<const N: usize>(
+    origin: CellIndex,
+    k: u32,
+) -> ArrayVec<CellIndex, N> {
+    let mut cells = ArrayVec::new();
+    cells.push(origin);
+
+    // Cells of the outermost ring are in `start..end`.
+    let mut start = 0;
+    for _ in 0..k {
+        let end = cells.len();
+        for i in start..end {
+            let cell = cells[i];
+            for direction in DIRECTIONS {
+                if let Some((neighbor, _)) =
+                    super::neighbor_rotations(cell, direction, 0)
+                {
+                    if !cells.contains(&neighbor) {
+                        cells.push(neighbor);
+                    }
+                }
+            }
+        }
+        start = end;
+    }
+
+    cells
+}
+
+// -----------------------------------------------------------------------------
+
 /// Fallible, but faster, iterator over indexes within k distance of the origin.
 pub struct DiskDistancesUnsafe {
     /// Starting point.
@@ -1,5 +1,17 @@
 mod algo;
+mod arc;
+mod distance;
 mod iterator;
+mod sector;
+mod simplify;
+mod track;
 
 pub use algo::{direction_for_neighbor, neighbor_rotations};
+pub use arc::{arc_cells, parallel_cells};
+pub use distance::GridDistance;
+#[cfg(feature = "arrayvec")]
+pub use iterator::disk_safe_array;
 pub use iterator::{DiskDistancesSafe, DiskDistancesUnsafe, RingUnsafe};
+pub use sector::sector_cells;
+pub use simplify::simplify_path;
+pub use track::trace_to_cells;
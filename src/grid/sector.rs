@@ -0,0 +1,153 @@
+//! Sector (i.e. disk wedge) queries.
+
+use crate::{CellIndex, LatLng, TWO_PI};
+use ahash::{HashSet, HashSetExt};
+use std::f64::consts::{FRAC_PI_2, PI};
+
+/// Returns the cells within grid distance `k` of `origin` whose centers lie in
+/// the bearing range going clockwise from `min` to `max` (in radians).
+///
+/// Only the cells overlapping the sector are visited: the search spreads from
+/// the origin through those cells, and stops at the ones farther than `k`
+/// (disks spanning a large part of the sphere are filtered as a whole).
+///
+/// The origin is always included, and cells are returned ring by ring.
+pub fn sector_cells(
+    origin: CellIndex,
+    k: u32,
+    min: f64,
+    max: f64,
+) -> Vec<CellIndex> {
+    let sector = Sector::new(LatLng::from(origin), min, max);
+    // Over large distances, bearings no longer bound the cells as on a plane
+    // and the search may miss some of them.
+    let radius = f64::from(k) * 2. * origin.resolution().edge_length_rads();
+
+    (radius < FRAC_PI_2)
+        .then(|| search(origin, k, &sector))
+        .flatten()
+        // Grid distances can't be computed across pentagon distortion either.
+        .unwrap_or_else(|| filter_disk(origin, k, &sector))
+}
+
+/// Filters the whole disk by bearing.
+fn filter_disk(origin: CellIndex, k: u32, sector: &Sector) -> Vec<CellIndex> {
+    let mut cells = origin
+        .grid_disk_distances_safe(k)
+        .filter(|&(cell, _)| {
+            cell == origin
+                || sector.contains(sector.azimuth(LatLng::from(cell)))
+        })
+        .collect::<Vec<_>>();
+    cells.sort_by_key(|&(_, distance)| distance);
+
+    cells.into_iter().map(|(cell, _)| cell).collect()
+}
+
+/// Spreads from the origin through the cells overlapping the sector.
+///
+/// The number of steps taken to reach a cell may exceed its grid distance
+/// (the shortest path can go through cells outside of the sector), hence the
+/// ring of every cell is given by its grid distance instead.
+///
+/// Returns `None` if a grid distance can't be computed.
+fn search(
+    origin: CellIndex,
+    k: u32,
+    sector: &Sector,
+) -> Option<Vec<CellIndex>> {
+    let mut cells = vec![(origin, 0)];
+    let mut visited = HashSet::new();
+    visited.insert(origin);
+    // Cells reached at the last step, within distance `k`, that overlap the
+    // sector.
+    let mut frontier = vec![origin];
+    while !frontier.is_empty() {
+        let mut next = Vec::new();
+        for cell in frontier {
+            for neighbor in cell.grid_disk_safe(1) {
+                if !visited.insert(neighbor) || !sector.overlaps(neighbor) {
+                    continue;
+                }
+                let distance =
+                    u32::try_from(origin.grid_distance(neighbor).ok()?)
+                        .expect("positive grid distance");
+                if distance > k {
+                    continue;
+                }
+                if sector.contains(sector.azimuth(LatLng::from(neighbor))) {
+                    cells.push((neighbor, distance));
+                }
+                next.push(neighbor);
+            }
+        }
+        frontier = next;
+    }
+    cells.sort_by_key(|&(_, distance)| distance);
+
+    Some(cells.into_iter().map(|(cell, _)| cell).collect())
+}
+
+// -----------------------------------------------------------------------------
+
+/// A bearing range, seen from an origin.
+struct Sector {
+    /// Apex of the sector.
+    origin: LatLng,
+    /// Start bearing, in `[0; 2π)`.
+    start: f64,
+    /// Angular width, clockwise from `start`.
+    width: f64,
+}
+
+impl Sector {
+    fn new(origin: LatLng, min: f64, max: f64) -> Self {
+        let width = if max - min >= TWO_PI {
+            TWO_PI
+        } else {
+            (max - min).rem_euclid(TWO_PI)
+        };
+
+        Self {
+            origin,
+            start: min.rem_euclid(TWO_PI),
+            width,
+        }
+    }
+
+    /// Returns the bearing of `ll` from the origin, in `[0; 2π)`.
+    fn azimuth(&self, ll: LatLng) -> f64 {
+        self.origin.azimuth(&ll).rem_euclid(TWO_PI)
+    }
+
+    /// Tests if a bearing (in `[0; 2π)`) falls in the sector.
+    fn contains(&self, azimuth: f64) -> bool {
+        (azimuth - self.start).rem_euclid(TWO_PI) <= self.width
+    }
+
+    /// Tests if the sector goes through a cell (that doesn't contain the
+    /// origin).
+    fn overlaps(&self, cell: CellIndex) -> bool {
+        // Bearings range covered by the cell, as offsets from the bearing of
+        // its center (the cell is convex and doesn't contain the origin, so
+        // the range is given by its vertices).
+        let center = self.azimuth(LatLng::from(cell));
+        let (low, high) = cell.boundary().iter().fold(
+            (0_f64, 0_f64),
+            |(low, high), &vertex| {
+                let mut offset = self.azimuth(vertex) - center;
+                if offset > PI {
+                    offset -= TWO_PI;
+                } else if offset < -PI {
+                    offset += TWO_PI;
+                }
+                (low.min(offset), high.max(offset))
+            },
+        );
+        let cell_start = (center + low).rem_euclid(TWO_PI);
+
+        // Either the cell range starts in the sector, or the other way around.
+        self.contains(cell_start)
+            || (self.start - cell_start).rem_euclid(TWO_PI) <= high - low
+    }
+}
@@ -0,0 +1,131 @@
+use crate::{
+    math::{hypot, mul_add},
+    CellIndex,
+};
+
+/// sin(60°)
+const SIN60: f64 = 0.866_025_403_784_438_6;
+
+/// Simplifies a path of cells into a sparser sequence of waypoints.
+///
+/// Cells lying within `tolerance` (in cells, i.e. the distance between two
+/// neighboring cell centers) of the straight line, in local IJ space, between
+/// the surrounding kept cells are removed (using the Ramer-Douglas-Peucker
+/// algorithm), as long as [`CellIndex::grid_path_cells`] between those kept
+/// cells gives back the removed ones.
+///
+/// Thus the simplification is lossless: the first and last cells are always
+/// kept, and rebuilding the grid path between every pair of consecutive
+/// waypoints gives back the original path, cell for cell. The tolerance only
+/// bounds how far from a waypoint-to-waypoint line the removed cells may be
+/// (e.g. an infinite tolerance gives the sparsest waypoints).
+///
+/// # Example
+///
+/// ```
+/// use h3o::{CellIndex, LatLng, Resolution};
+///
+/// let src = LatLng::new(48.8584, 2.2945)?.to_cell(Resolution::Nine);
+/// let dst = LatLng::new(48.8606, 2.3376)?.to_cell(Resolution::Nine);
+/// let path = src.grid_path_cells(dst)?.collect::<Result<Vec<_>, _>>()?;
+///
+/// let waypoints = h3o::simplify_path(&path, 0.5);
+/// assert_eq!(waypoints, vec![src, dst]);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[must_use]
+pub fn simplify_path(cells: &[CellIndex], tolerance: f64) -> Vec<CellIndex> {
+    if cells.len() <= 2 {
+        return cells.to_vec();
+    }
+
+    let mut keep = vec![false; cells.len()];
+    keep[0] = true;
+    keep[cells.len() - 1] = true;
+
+    // Explicit stack, paths may be very long.
+    let mut segments = vec![(0, cells.len() - 1)];
+    while let Some((start, end)) = segments.pop() {
+        // Neighbors can't be simplified any further.
+        if end - start < 2 {
+            continue;
+        }
+        if let Some(offset) = split_point(&cells[start..=end], tolerance) {
+            let middle = start + offset;
+            keep[middle] = true;
+            segments.push((start, middle));
+            segments.push((middle, end));
+        }
+    }
+
+    cells
+        .iter()
+        .zip(keep)
+        .filter_map(|(&cell, keep)| keep.then_some(cell))
+        .collect()
+}
+
+/// Returns the offset of the cell where the path must be split, if it cannot
+/// be summarized by its endpoints.
+fn split_point(path: &[CellIndex], tolerance: f64) -> Option<usize> {
+    let first = path[0];
+    let middle = path.len() / 2;
+
+    let Some(coords) = path
+        .iter()
+        .map(|cell| hex2d(*cell, first))
+        .collect::<Option<Vec<_>>>()
+    else {
+        return Some(middle);
+    };
+
+    let (start, end) = (coords[0], coords[coords.len() - 1]);
+    let (offset, distance) = coords[1..coords.len() - 1]
+        .iter()
+        .map(|&point| distance_to_segment(point, start, end))
+        .enumerate()
+        .max_by(|lhs, rhs| lhs.1.total_cmp(&rhs.1))?;
+
+    // Keep everything on NaN tolerance.
+    (distance > tolerance || tolerance.is_nan() || !is_grid_path(path))
+        .then_some(offset + 1)
+}
+
+/// Tests if the path is the one given by `grid_path_cells` between its
+/// endpoints.
+fn is_grid_path(path: &[CellIndex]) -> bool {
+    let (first, last) = (path[0], path[path.len() - 1]);
+
+    first.grid_path_cells(last).is_ok_and(|cells| {
+        cells.map(Result::ok).eq(path.iter().copied().map(Some))
+    })
+}
+
+/// Returns the 2D cartesian coordinates of a cell, in the local IJ space of
+/// the origin.
+fn hex2d(cell: CellIndex, origin: CellIndex) -> Option<(f64, f64)> {
+    let ij = cell.to_local_ij(origin).ok()?;
+    let (i, j) = (f64::from(ij.i()), f64::from(ij.j()));
+
+    Some((mul_add(-0.5, j, i), j * SIN60))
+}
+
+/// Returns the distance between a point and a segment.
+fn distance_to_segment(
+    point: (f64, f64),
+    start: (f64, f64),
+    end: (f64, f64),
+) -> f64 {
+    let (dx, dy) = (end.0 - start.0, end.1 - start.1);
+    let (px, py) = (point.0 - start.0, point.1 - start.1);
+    let length2 = mul_add(dx, dx, dy * dy);
+
+    // Closed paths have a degenerate segment.
+    let t = if length2 == 0. {
+        0.
+    } else {
+        (mul_add(px, dx, py * dy) / length2).clamp(0., 1.)
+    };
+
+    hypot(mul_add(-t, dx, px), mul_add(-t, dy, py))
+}
@@ -0,0 +1,127 @@
+use super::arc_cells;
+use crate::{CellIndex, LatLng, Resolution, EARTH_RADIUS_KM};
+
+/// Converts an ordered sequence of GPS points into contiguous paths of cells.
+///
+/// Consecutive points closer than `max_gap_km` are joined by the cells crossed
+/// by the great circle arc between them (interpolating over the gaps in the
+/// sampling), while farther ones start a new path.
+///
+/// Every returned path is contiguous (consecutive cells are neighbors, unless
+/// no grid path exists between them, e.g. across some pentagons) and, to
+/// absorb the GPS noise, one-cell detours lasting a single sample (e.g. a
+/// point jittering over a cell boundary) are removed. Actual U-turns are
+/// kept.
+///
+/// # Example
+///
+/// ```
+/// use h3o::{LatLng, Resolution};
+///
+/// let points = [
+///     LatLng::new(48.8584, 2.2945)?,
+///     LatLng::new(48.8606, 2.3376)?,
+///     // Tunnel, no signal for a while.
+///     LatLng::new(48.8009, 2.4519)?,
+/// ];
+/// let paths = h3o::trace_to_cells(points, Resolution::Nine, 5.);
+/// assert_eq!(paths.len(), 2);
+/// # Ok::<(), h3o::error::InvalidLatLng>(())
+/// ```
+#[must_use]
+pub fn trace_to_cells(
+    points: impl IntoIterator<Item = LatLng>,
+    resolution: Resolution,
+    max_gap_km: f64,
+) -> Vec<Vec<CellIndex>> {
+    let max_gap = max_gap_km / EARTH_RADIUS_KM;
+    let mut paths = Vec::new();
+    let mut path = Path::default();
+    let mut previous: Option<LatLng> = None;
+
+    for (sample, point) in points.into_iter().enumerate() {
+        match previous {
+            Some(start) if start.distance_rads(point) <= max_gap => {
+                for (cell, _) in arc_cells(start, point, resolution) {
+                    path.push(cell, sample);
+                }
+            }
+            _ => {
+                if !path.is_empty() {
+                    paths.push(std::mem::take(&mut path).into_cells());
+                }
+                path.push(point.to_cell(resolution), sample);
+            }
+        }
+        previous = Some(point);
+    }
+    if !path.is_empty() {
+        paths.push(path.into_cells());
+    }
+
+    paths
+}
+
+/// A contiguous path of cells, being traced.
+#[derive(Debug, Default)]
+struct Path {
+    /// Cells, with the sample during which they were entered.
+    cells: Vec<(CellIndex, usize)>,
+    /// Number of leading cells that can no longer be removed.
+    fixed: usize,
+}
+
+impl Path {
+    const fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    fn into_cells(self) -> Vec<CellIndex> {
+        self.cells.into_iter().map(|(cell, _)| cell).collect()
+    }
+
+    /// Appends a cell, reached during the given sample.
+    fn push(&mut self, cell: CellIndex, sample: usize) {
+        let Some(&(last, _)) = self.cells.last() else {
+            self.cells.push((cell, sample));
+            return;
+        };
+        if last == cell {
+            return;
+        }
+
+        // Arcs going exactly through a vertex may skip a cell: bridge the
+        // hole.
+        if last.is_neighbor_with(cell) != Ok(true) {
+            let steps = last.grid_path_cells(cell).ok().and_then(|cells| {
+                cells.skip(1).collect::<Result<Vec<_>, _>>().ok()
+            });
+            if let Some(steps) = steps {
+                for step in steps {
+                    self.push_step(step, sample);
+                }
+                return;
+            }
+        }
+        self.push_step(cell, sample);
+    }
+
+    /// Appends a neighbor of the last cell, removing the jitter.
+    ///
+    /// Going back to the previous cell cancels the last one only if it was
+    /// entered during the previous sample at the earliest (longer stays are
+    /// U-turns), and never unwinds the path any further.
+    fn push_step(&mut self, cell: CellIndex, sample: usize) {
+        let len = self.cells.len();
+        if len >= 2
+            && len > self.fixed
+            && self.cells[len - 2].0 == cell
+            && sample - self.cells[len - 1].1 <= 1
+        {
+            self.cells.pop();
+            self.fixed = self.cells.len();
+        } else {
+            self.cells.push((cell, sample));
+        }
+    }
+}
@@ -1,22 +1,26 @@
-use super::{Children, Compact, GridPathCells, Triangle};
+use super::{
+    Children, Compact, EdgeLengthStats, GridPathCells, Triangle, UnionCompacted,
+};
 use crate::{
-    coord::{CoordIJ, CoordIJK, FaceIJK, LocalIJK, Overage},
+    coord::{CoordIJ, CoordIJK, FaceIJK, LocalIJK, Overage, Vec3d},
     error::{
-        CompactionError, HexGridError, InvalidCellIndex, LocalIjError,
-        ResolutionMismatch,
+        CompactionError, InvalidCellIndex, LocalIjError, ResolutionMismatch,
     },
-    grid,
+    face, grid,
     index::{bits, IndexMode},
-    resolution, BaseCell, Boundary, DirectedEdgeIndex, Direction, Edge,
-    ExtendedResolution, FaceSet, LatLng, LocalIJ, Resolution, Vertex,
-    VertexIndex, CCW, CW, DEFAULT_CELL_INDEX, DIRECTION_BITSIZE,
-    EARTH_RADIUS_KM, NUM_HEX_VERTS, NUM_PENT_VERTS,
+    max_grid_disk_size, resolution, trace, BaseCell, Boundary,
+    DirectedEdgeIndex, Direction, Edge, ExtendedResolution, Face, FaceSet,
+    GridDistance, LatLng, LocalIJ, Resolution, Vertex, VertexIndex, CCW, CW,
+    DEFAULT_CELL_INDEX, DIRECTION_BITSIZE, EARTH_RADIUS_KM, NUM_HEX_VERTS,
+    NUM_PENT_VERTS,
 };
+#[cfg(feature = "arrayvec")]
+use arrayvec::ArrayVec;
 use either::Either;
 use std::{
     cmp::Ordering,
     fmt, iter,
-    num::{NonZeroU64, NonZeroU8},
+    num::{NonZeroU32, NonZeroU64, NonZeroU8},
     str::FromStr,
 };
 
@@ -121,13 +125,14 @@ const FAILED_DIRECTIONS: u64 =
 const fn validate_direction(
     origin_dir: u8,
     index_dir: u8,
+    error: LocalIjError,
 ) -> Result<(), LocalIjError> {
     let offset = origin_dir * 7 + index_dir;
     if (FAILED_DIRECTIONS & (1 << offset)) != 0 {
         // TODO: We may be unfolding the pentagon incorrectly in
         // this case; return an error code until this is guaranteed
         // to be correct.
-        return Err(LocalIjError::Pentagon);
+        return Err(error);
     }
     Ok(())
 }
@@ -173,6 +178,12 @@ const BASE_CELL: BaseCell = BaseCell::new_unchecked(2);
 /// - [H3 Index Inspector](https://observablehq.com/@nrabinowitz/h3-index-inspector?collection=@nrabinowitz/h3)
 #[derive(Clone, Copy, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "diesel",
+    derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow),
+    diesel(sql_type = diesel::sql_types::BigInt),
+    diesel(sql_type = diesel::sql_types::Text)
+)]
 #[repr(transparent)]
 pub struct CellIndex(NonZeroU64);
 
@@ -257,6 +268,43 @@ impl CellIndex {
         self.area_km2() * 1000. * 1000.
     }
 
+    /// Computes the minimum, maximum and average length of the edges of this
+    /// H3 cell, in radians.
+    ///
+    /// Real cells can deviate substantially from the average edge length of
+    /// their resolution, especially near the icosahedron vertices.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let index = h3o::CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let stats = index.edge_length_stats();
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    #[must_use]
+    pub fn edge_length_stats(self) -> EdgeLengthStats {
+        EdgeLengthStats::new(self.edges().map(DirectedEdgeIndex::length_rads))
+    }
+
+    /// Computes the distortion of this H3 cell, as the ratio of its longest
+    /// edge to its shortest one.
+    ///
+    /// A perfectly regular cell has a distortion of 1, the cells the most
+    /// distorted are found near the pentagons.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let index = h3o::CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// assert!(index.distortion() < 1.1);
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    #[must_use]
+    pub fn distortion(self) -> f64 {
+        let stats = self.edge_length_stats();
+        stats.max / stats.min
+    }
+
     /// Finds all icosahedron faces intersected this cell index
     ///
     /// # Example
@@ -327,6 +375,63 @@ impl CellIndex {
         faces
     }
 
+    /// Returns true if this cell straddles an icosahedron edge, i.e. it
+    /// intersects more than one icosahedron face.
+    ///
+    /// This is computed from the cell boundary: the cell crosses an edge if
+    /// its vertices don't all lie on the same face (a vertex lying on an edge
+    /// belongs to both faces, and is thus ignored). Cells merely touching an
+    /// edge don't cross it.
+    ///
+    /// The crossed faces are given by [`Self::icosahedron_faces`]. Note that
+    /// pentagons, centered on the icosahedron vertices, always cross edges.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let index = h3o::CellIndex::try_from(0x851c000bfffffff)?;
+    /// assert!(index.crosses_face_edge());
+    /// assert_eq!(index.icosahedron_faces().len(), 2);
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    #[must_use]
+    pub fn crosses_face_edge(self) -> bool {
+        // Tolerance, in squared distance, to tell apart the two closest face
+        // centers of a vertex.
+        const EPSILON: f64 = 1e-12;
+
+        // Centered on an icosahedron vertex, whereas their vertices may all
+        // lie on the edges.
+        if self.is_pentagon() {
+            return true;
+        }
+
+        let mut faces = FaceSet::new();
+        for ll in self.boundary().iter() {
+            // A point lies on the face whose center is the closest one.
+            let v3d = Vec3d::from(*ll);
+            let (closest, first, second) = face::CENTER_POINT
+                .iter()
+                .enumerate()
+                .map(|(i, center)| (i, v3d.distance(center)))
+                .fold(
+                    (0, f64::MAX, f64::MAX),
+                    |(closest, first, second), (i, distance)| {
+                        if distance < first {
+                            (i, distance, first)
+                        } else {
+                            (closest, first, second.min(distance))
+                        }
+                    },
+                );
+            if second - first > EPSILON {
+                faces.insert(Face::new_unchecked(closest));
+            }
+        }
+
+        faces.len() > 1
+    }
+
     /// Returns true if this index represents a pentagonal cell.
     ///
     /// # Example
@@ -631,6 +736,26 @@ impl CellIndex {
         Children::new(self, resolution)
     }
 
+    /// Returns the children one resolution down (empty at the finest
+    /// resolution), in a fixed-capacity array.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let index = h3o::CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let children = index.children_array();
+    /// assert_eq!(children.len(), 7);
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    #[cfg(feature = "arrayvec")]
+    #[must_use]
+    pub fn children_array(self) -> ArrayVec<Self, 7> {
+        self.resolution()
+            .succ()
+            .map(|resolution| self.children(resolution).collect())
+            .unwrap_or_default()
+    }
+
     /// Compresses a set of unique cell indexes all at the same resolution.
     ///
     /// The indexes are compressed by pruning full child branches to the parent
@@ -665,7 +790,45 @@ impl CellIndex {
     pub fn compact(
         indexes: impl IntoIterator<Item = Self>,
     ) -> Result<impl Iterator<Item = Self>, CompactionError> {
-        Compact::new(indexes)
+        trace::compaction(|| Compact::new(indexes))
+    }
+
+    /// Merges sorted streams of compacted cells into their compacted union.
+    ///
+    /// Each stream must be sorted in hierarchical order (a cell comes before
+    /// its descendants, and after the cells preceding it at its resolution),
+    /// as returned by [`Self::compact`] or [`crate::FrozenCellSet::iter`].
+    /// Under that assumption, the streams are consumed lazily and only a
+    /// bounded amount of memory (independent of the input sizes) is used.
+    ///
+    /// The output is sorted the same way, cells covered by another one are
+    /// discarded and complete groups of siblings (possibly spread across
+    /// several streams) are replaced by their parent.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{CellIndex, Resolution};
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let (even, odd): (Vec<_>, Vec<_>) = index
+    ///     .children(Resolution::Eleven)
+    ///     .enumerate()
+    ///     .partition(|(i, _)| i % 2 == 0);
+    /// let streams = [even, odd]
+    ///     .map(|cells| cells.into_iter().map(|(_, cell)| cell));
+    ///
+    /// let cells = CellIndex::union_compacted(streams).collect::<Vec<_>>();
+    /// assert_eq!(cells, vec![index]);
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    pub fn union_compacted<I>(
+        streams: impl IntoIterator<Item = I>,
+    ) -> impl Iterator<Item = Self>
+    where
+        I: IntoIterator<Item = Self>,
+    {
+        UnionCompacted::new(streams.into_iter().map(IntoIterator::into_iter))
     }
 
     /// Computes the exact size of the uncompacted set of cells.
@@ -733,6 +896,214 @@ impl CellIndex {
         }
     }
 
+    /// Returns true if the coordinate lies within the cell.
+    ///
+    /// The check relies on the indexing function (e.g. the coordinate is
+    /// indexed into this cell at its resolution), which is both faster and more
+    /// consistent than a point-in-polygon test on the cell boundary.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{CellIndex, LatLng};
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// assert!(index.contains_latlng(LatLng::from(index)));
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    #[must_use]
+    pub fn contains_latlng(self, ll: LatLng) -> bool {
+        ll.to_cell(self.resolution()) == self
+    }
+
+    /// Returns true if the two cells overlap in the hierarchy.
+    ///
+    /// Cells can have different resolutions: they overlap if they are equal or
+    /// if one is an ancestor of the other.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{CellIndex, Resolution};
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let parent = index.parent(Resolution::Five).expect("parent");
+    /// assert!(index.overlaps(parent));
+    /// assert!(parent.overlaps(index));
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    #[must_use]
+    pub fn overlaps(self, other: Self) -> bool {
+        if self.resolution() <= other.resolution() {
+            other.parent(self.resolution()) == Some(self)
+        } else {
+            self.parent(other.resolution()) == Some(other)
+        }
+    }
+
+    /// Returns true if the boundaries of the two cells intersect.
+    ///
+    /// Unlike [`Self::overlaps`], this relies on the actual geometry of the
+    /// cells, and thus also catches the cases where a cell crosses into a
+    /// neighbor of its parent. Cells that merely touch (e.g. share an edge or
+    /// a vertex) are considered as intersecting.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::CellIndex;
+    ///
+    /// let src = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let dst = CellIndex::try_from(0x8a1fb46622d7fff)?;
+    /// assert!(src.intersects_boundary(dst));
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    #[cfg(feature = "geo")]
+    #[must_use]
+    pub fn intersects_boundary(self, other: Self) -> bool {
+        if self.overlaps(other) {
+            return true;
+        }
+        // A polar cell can't be turned into a polygon, but it can still be
+        // tested against one.
+        let (cell, other) = if crate::geom::is_polar(self) {
+            (other, self)
+        } else {
+            (self, other)
+        };
+        // Two polar cells that don't overlap are at opposite poles.
+        !crate::geom::is_polar(cell)
+            && crate::geom::Polygon::from_cell(cell).relation(other)
+                != crate::geom::CellRelation::Disjoint
+    }
+
+    /// Computes the great circle distance, in radians, between the given
+    /// coordinate and the closest edge of the cell boundary.
+    ///
+    /// The coordinate can be either inside or outside the cell.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{CellIndex, LatLng};
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let distance = index.boundary_distance_rads(LatLng::from(index));
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    #[must_use]
+    pub fn boundary_distance_rads(self, ll: LatLng) -> f64 {
+        let boundary = self.boundary();
+
+        (0..boundary.len())
+            .map(|i| {
+                let j = (i + 1) % boundary.len();
+                ll.distance_to_arc_rads(boundary[i], boundary[j])
+            })
+            .fold(f64::INFINITY, f64::min)
+    }
+
+    /// Computes the great circle distance, in kilometers, between the given
+    /// coordinate and the closest edge of the cell boundary.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{CellIndex, LatLng};
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let distance = index.boundary_distance_km(LatLng::from(index));
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    #[must_use]
+    pub fn boundary_distance_km(self, ll: LatLng) -> f64 {
+        self.boundary_distance_rads(ll) * EARTH_RADIUS_KM
+    }
+
+    /// Computes the great circle distance, in meters, between the given
+    /// coordinate and the closest edge of the cell boundary.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{CellIndex, LatLng};
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let distance = index.boundary_distance_m(LatLng::from(index));
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    #[must_use]
+    pub fn boundary_distance_m(self, ll: LatLng) -> f64 {
+        self.boundary_distance_km(ll) * 1000.
+    }
+
+    /// Returns the shard, out of `count`, the cell belongs to.
+    ///
+    /// Shorthand for [`Self::shard_at`] using [`Resolution::Three`], which has
+    /// enough cells (41,162) to balance the load across thousands of shards.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::CellIndex;
+    /// use std::num::NonZeroU32;
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let count = NonZeroU32::new(16).expect("non-zero");
+    /// assert_eq!(index.shard(count), 9);
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    #[must_use]
+    pub fn shard(self, count: NonZeroU32) -> u32 {
+        self.shard_at(Resolution::Three, count)
+    }
+
+    /// Returns the shard, out of `count`, the cell belongs to, keyed by its
+    /// ancestor at the given resolution.
+    ///
+    /// The descendants of a cell at `resolution` all end up in the same shard,
+    /// which keeps nearby cells together while spreading the coarse cells
+    /// evenly across the shards. Cells coarser than `resolution` are keyed by
+    /// themselves.
+    ///
+    /// The scheme is stable across versions, and simple enough to be
+    /// reimplemented elsewhere:
+    /// 1. the key is the 64-bit index of the ancestor;
+    /// 2. it is hashed using the `SplitMix64` finalizer;
+    /// 3. the hash is mapped to `[0, count)` by taking the high 64 bits of
+    ///    `hash * count` (as a 128-bit product).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{CellIndex, Resolution};
+    /// use std::num::NonZeroU32;
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let parent = index.parent(Resolution::Five).expect("parent");
+    /// let count = NonZeroU32::new(64).expect("non-zero");
+    /// assert_eq!(
+    ///     index.shard_at(Resolution::Five, count),
+    ///     parent.shard_at(Resolution::Five, count),
+    /// );
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    #[must_use]
+    pub fn shard_at(self, resolution: Resolution, count: NonZeroU32) -> u32 {
+        let key = u64::from(self.parent(resolution).unwrap_or(self));
+
+        // SplitMix64 finalizer.
+        let mut hash = key;
+        hash = (hash ^ (hash >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        hash = (hash ^ (hash >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        hash ^= hash >> 31;
+
+        // Strictly less than `count`, thus no truncation.
+        #[allow(clippy::cast_possible_truncation)]
+        let shard = ((u128::from(hash) * u128::from(count.get())) >> 64) as u32;
+        shard
+    }
+
     /// Returns all the base cell indexes.
     ///
     /// # Example
@@ -799,6 +1170,23 @@ impl CellIndex {
         })
     }
 
+    /// Returns all of the directed edges from the current index, in a
+    /// fixed-capacity array.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let index = h3o::CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let edges = index.edges_array();
+    /// assert_eq!(edges.len(), 6);
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    #[cfg(feature = "arrayvec")]
+    #[must_use]
+    pub fn edges_array(self) -> ArrayVec<DirectedEdgeIndex, 6> {
+        self.edges().collect()
+    }
+
     /// Get the specified vertex of this cell.
     ///
     /// # Example
@@ -931,6 +1319,22 @@ impl CellIndex {
         })
     }
 
+    /// Returns all vertexes for the current cell, in a fixed-capacity array.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let index = h3o::CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let vertexes = index.vertexes_array();
+    /// assert_eq!(vertexes.len(), 6);
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    #[cfg(feature = "arrayvec")]
+    #[must_use]
+    pub fn vertexes_array(self) -> ArrayVec<VertexIndex, 6> {
+        self.vertexes().collect()
+    }
+
     /// Produce cells within grid distance `k` of the cell.
     ///
     /// This function is a convenience helper that tries
@@ -954,6 +1358,83 @@ impl CellIndex {
             .unwrap_or_else(|| self.grid_disk_safe(k).collect())
     }
 
+    /// Produce cells within grid distance `k` of the cell, in a fixed-capacity
+    /// array.
+    ///
+    /// Returns `None` if the capacity `N` is smaller than
+    /// [`max_grid_disk_size`](crate::max_grid_disk_size)`(k)`.
+    ///
+    /// Like [`Self::grid_disk`], this function tries [`Self::grid_disk_fast`]
+    /// first, but its fallback (when pentagons are involved) doesn't allocate
+    /// either: it's a breadth-first traversal using the array itself as queue,
+    /// quadratic in the number of cells.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let index = h3o::CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let cells = index.grid_disk_array::<19>(2).expect("large enough");
+    /// assert_eq!(cells.len(), 19);
+    /// assert!(index.grid_disk_array::<18>(2).is_none());
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    #[cfg(feature = "arrayvec")]
+    #[must_use]
+    pub fn grid_disk_array<const N: usize>(
+        self,
+        k: u32,
+    ) -> Option<ArrayVec<Self, N>> {
+        let fits =
+            usize::try_from(max_grid_disk_size(k)).is_ok_and(|size| size <= N);
+
+        fits.then(|| {
+            self.grid_disk_fast(k)
+                .collect::<Option<_>>()
+                .unwrap_or_else(|| grid::disk_safe_array(self, k))
+        })
+    }
+
+    /// Produce cells within grid distance `k` of the cell, reporting whether
+    /// pentagon distortion was encountered.
+    ///
+    /// Like [`Self::grid_disk`], this function tries [`Self::grid_disk_fast`]
+    /// first and then fallback on [`Self::grid_disk_safe`] if the former fails.
+    /// When the fallback is used, the ring (i.e. the grid distance from the
+    /// origin) where the distortion was detected is returned alongside the
+    /// cells.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let index = h3o::CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let (cells, distortion) = index.grid_disk_checked::<Vec<_>>(2);
+    /// assert_eq!(distortion, None);
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    #[must_use]
+    pub fn grid_disk_checked<T>(self, k: u32) -> (T, Option<u32>)
+    where
+        T: FromIterator<Self>,
+    {
+        let mut count = 0;
+        let cells = self
+            .grid_disk_fast(k)
+            .inspect(|cell| count += u64::from(cell.is_some()))
+            .collect::<Option<T>>();
+
+        cells.map_or_else(
+            || {
+                // The cells are produced ring by ring: the number of cells
+                // successfully produced tells us where the failure occurred.
+                let ring = (0..k)
+                    .find(|&ring| count < max_grid_disk_size(ring))
+                    .unwrap_or(k);
+                (self.grid_disk_safe(k).collect(), Some(ring))
+            },
+            |cells| (cells, None),
+        )
+    }
+
     /// Safe but slow version of [`Self::grid_disk_fast`].
     ///
     /// # Example
@@ -1083,6 +1564,43 @@ impl CellIndex {
         Either::Left(grid::DiskDistancesUnsafe::new(self, k))
     }
 
+    /// Produce cells within grid distance `k` of the cell whose centers fall in
+    /// the bearing range from `azimuth_min` to `azimuth_max`.
+    ///
+    /// Bearings are in degrees, clockwise from the north, and the range goes
+    /// clockwise from `azimuth_min` to `azimuth_max`: it wraps around the north
+    /// when `azimuth_max` is smaller than `azimuth_min`, and covers the whole
+    /// disk when it spans 360° or more. The cell itself is always included.
+    ///
+    /// Only the cells the sector goes through are visited, which makes this
+    /// cheaper than filtering the whole disk for narrow sectors (except for
+    /// disks spanning a large part of the globe, which are filtered as a
+    /// whole).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let index = h3o::CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// // Field of view of an antenna facing east.
+    /// let cells = index.grid_sector::<Vec<_>>(10, 60., 120.);
+    /// assert!(cells.len() < h3o::max_grid_disk_size(10) as usize / 4);
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    #[must_use]
+    pub fn grid_sector<T>(self, k: u32, azimuth_min: f64, azimuth_max: f64) -> T
+    where
+        T: FromIterator<Self>,
+    {
+        grid::sector_cells(
+            self,
+            k,
+            azimuth_min.to_radians(),
+            azimuth_max.to_radians(),
+        )
+        .into_iter()
+        .collect()
+    }
+
     /// Takes an list of cell indexes and a max `k-ring` and returns a stream of
     /// cell indexes sorted first by the original cell index and then by the
     /// grid `k-ring` (0 to max).
@@ -1152,8 +1670,8 @@ impl CellIndex {
     /// This function may fail to find the distance between two indexes, for
     /// example if they are very far apart. It may also fail when finding
     /// distances for indexes on opposite sides of a pentagon.
-    /// In such case, [`LocalIjError::Pentagon`] or [`LocalIjError::HexGrid`] is
-    /// returned.
+    /// In such case, [`LocalIjError::TooFar`], [`LocalIjError::Pentagon`] or
+    /// [`LocalIjError::HexGrid`] is returned.
     ///
     /// [`LocalIjError::ResolutionMismatch`] if the source and destination
     /// indexes don't have the same resolution.
@@ -1175,6 +1693,123 @@ impl CellIndex {
         Ok(src.coord().distance(dst.coord()))
     }
 
+    /// Produces the grid distance between the two indexes, falling back on an
+    /// estimate when the exact distance cannot be computed.
+    ///
+    /// The estimate is derived from the great circle distance between the
+    /// cell centers and the largest distance between neighboring centers at
+    /// this resolution, rounded down: it's a lower bound of the grid distance.
+    ///
+    /// # Errors
+    ///
+    /// [`ResolutionMismatch`] if the source and destination indexes don't have
+    /// the same resolution.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{CellIndex, GridDistance};
+    ///
+    /// let src = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let dst = CellIndex::try_from(0x8a1fb46622d7fff)?;
+    /// assert_eq!(src.grid_distance_approx(dst)?, GridDistance::Exact(1));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn grid_distance_approx(
+        self,
+        to: Self,
+    ) -> Result<GridDistance, ResolutionMismatch> {
+        if self.resolution() != to.resolution() {
+            return Err(ResolutionMismatch);
+        }
+        if let Ok(distance) = self.grid_distance(to) {
+            return Ok(GridDistance::Exact(distance));
+        }
+
+        // Neighboring hexagon centers are `sqrt(3) * edge length` apart: using
+        // the longest edge gives the largest step a path can make, so the
+        // number of steps is bounded by below.
+        let spacing = self.resolution().edge_length_max_km() * 3_f64.sqrt();
+        let distance = LatLng::from(self).distance_km(LatLng::from(to));
+        // Truncate on purpose, the value is positive and bounded by the
+        // number of cells along a great circle.
+        #[allow(clippy::cast_possible_truncation)]
+        let estimate = (distance / spacing).floor() as i32;
+
+        Ok(GridDistance::Approximate(estimate))
+    }
+
+    /// Returns the cell containing the midpoint of the great circle arc
+    /// between the centers of the two cells.
+    ///
+    /// Shorthand for [`Self::interpolate`] with `t = 0.5`.
+    ///
+    /// # Errors
+    ///
+    /// [`ResolutionMismatch`] if the two indexes don't have the same
+    /// resolution.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::CellIndex;
+    ///
+    /// let src = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let dst = CellIndex::try_from(0x8a1fb46622d7fff)?;
+    /// let midpoint = src.midpoint(dst)?;
+    /// assert!(midpoint == src || midpoint == dst);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn midpoint(self, other: Self) -> Result<Self, ResolutionMismatch> {
+        self.interpolate(other, 0.5)
+    }
+
+    /// Returns the cell containing the point located at `t` of the way along
+    /// the great circle arc from the center of the cell to the center of
+    /// `other`.
+    ///
+    /// `t` is clamped to `[0; 1]` (`0` gives back the cell, `1` gives
+    /// `other`), and `NaN` is treated as `0`.
+    ///
+    /// The arc between antipodal cells is not well defined: any great circle
+    /// may be picked.
+    ///
+    /// # Errors
+    ///
+    /// [`ResolutionMismatch`] if the two indexes don't have the same
+    /// resolution.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{CellIndex, LatLng, Resolution};
+    ///
+    /// let paris = LatLng::new(48.864716, 2.349014)?.to_cell(Resolution::Six);
+    /// let rome = LatLng::new(41.902782, 12.496366)?.to_cell(Resolution::Six);
+    /// // Progressive reveal of the route, in 10 steps.
+    /// let steps = (0..=10)
+    ///     .map(|i| paris.interpolate(rome, f64::from(i) / 10.))
+    ///     .collect::<Result<Vec<_>, _>>()?;
+    /// assert_eq!(steps.first(), Some(&paris));
+    /// assert_eq!(steps.last(), Some(&rome));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn interpolate(
+        self,
+        other: Self,
+        t: f64,
+    ) -> Result<Self, ResolutionMismatch> {
+        let resolution = self.resolution();
+        if resolution != other.resolution() {
+            return Err(ResolutionMismatch);
+        }
+
+        let t = if t.is_nan() { 0. } else { t.clamp(0., 1.) };
+        let point = LatLng::from(self).interpolate(LatLng::from(other), t);
+
+        Ok(point.to_cell(resolution))
+    }
+
     /// Computes the number of indexes in a line from the current index to the
     /// end one.
     ///
@@ -1215,8 +1850,8 @@ impl CellIndex {
     /// This function may fail to find the distance between two indexes, for
     /// example if they are very far apart. It may also fail when finding
     /// distances for indexes on opposite sides of a pentagon.
-    /// In such case, [`LocalIjError::Pentagon`] or [`LocalIjError::HexGrid`] is
-    /// returned.
+    /// In such case, [`LocalIjError::TooFar`], [`LocalIjError::Pentagon`] or
+    /// [`LocalIjError::HexGrid`] is returned.
     ///
     /// [`LocalIjError::ResolutionMismatch`] if the source and destination
     /// indexes don't have the same resolution.
@@ -1345,8 +1980,8 @@ impl CellIndex {
     ///
     /// Failure may occur if the index is too far away from the origin or if the
     /// index is on the other side of a pentagon.
-    /// In such case, [`LocalIjError::Pentagon`] or [`LocalIjError::HexGrid`] is
-    /// returned.
+    /// In such case, [`LocalIjError::TooFar`], [`LocalIjError::Pentagon`] or
+    /// [`LocalIjError::HexGrid`] is returned.
     ///
     /// # Example
     ///
@@ -1497,6 +2132,61 @@ impl CellIndex {
         Self::new_unchecked(bits::set_unused(bits, resolution))
     }
 
+    /// Returns the lower hex representation of the cell index (i.e. what's
+    /// printed by `Display`) as ASCII bytes, without allocating.
+    ///
+    /// Cell indexes always have 15 hexadecimal digits.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let index = h3o::CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// assert_eq!(&index.to_hex_array(), b"8a1fb46622dffff");
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    #[must_use]
+    pub const fn to_hex_array(self) -> [u8; 15] {
+        const DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+        let value = self.0.get();
+        let mut bytes = [0; 15];
+        let mut i = 0;
+        while i < bytes.len() {
+            let shift = 4 * (bytes.len() - 1 - i);
+            // Masked to a single digit, no truncation.
+            #[allow(clippy::cast_possible_truncation)]
+            let digit = ((value >> shift) & 0xf) as usize;
+            bytes[i] = DIGITS[digit];
+            i += 1;
+        }
+
+        bytes
+    }
+
+    /// Writes the lower hex representation of the cell index (i.e. what's
+    /// printed by `Display`) into `writer`, without allocating.
+    ///
+    /// # Errors
+    ///
+    /// Forwards the error of the underlying writer.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::fmt::Write;
+    ///
+    /// let index = h3o::CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let mut line = String::from("cell,");
+    /// index.write_to(&mut line).expect("infallible");
+    /// assert_eq!(line, "cell,8a1fb46622dffff");
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    pub fn write_to(self, writer: &mut impl fmt::Write) -> fmt::Result {
+        self.to_hex_array()
+            .into_iter()
+            .try_for_each(|byte| writer.write_char(char::from(byte)))
+    }
+
     pub(crate) fn new_unchecked(value: u64) -> Self {
         debug_assert!(Self::try_from(value).is_ok(), "invalid cell index");
         Self(NonZeroU64::new(value).expect("valid cell index"))
@@ -1563,9 +2253,9 @@ impl CellIndex {
     ///
     /// Failure may occur if the index is too far away from the origin or if the
     /// index is on the other side of a pentagon.
-    /// In such case, [`LocalIjError::Pentagon`] or [`LocalIjError::HexGrid`] is
-    /// returned.
-    pub(super) fn to_local_ijk(
+    /// In such case, [`LocalIjError::TooFar`], [`LocalIjError::Pentagon`] or
+    /// [`LocalIjError::HexGrid`] is returned.
+    pub(crate) fn to_local_ijk(
         mut self,
         origin: Self,
     ) -> Result<LocalIJK, LocalIjError> {
@@ -1584,9 +2274,7 @@ impl CellIndex {
             Direction::Center
         } else {
             dir = origin_base_cell.direction(base_cell).ok_or_else(|| {
-                HexGridError::new(
-                    "cannot unfold (base cells are not neighbors)",
-                )
+                LocalIjError::too_far(origin_base_cell, Some(base_cell))
             })?;
             base_cell
                 .direction(origin_base_cell)
@@ -1595,6 +2283,12 @@ impl CellIndex {
 
         let origin_on_pent = origin_base_cell.is_pentagon();
         let index_on_pent = base_cell.is_pentagon();
+        let pentagon = if origin_on_pent {
+            origin_base_cell
+        } else {
+            base_cell
+        };
+        let error = LocalIjError::pentagon(origin_base_cell, pentagon);
 
         if dir != Direction::Center {
             // Rotate index into the orientation of the origin base cell.
@@ -1632,7 +2326,7 @@ impl CellIndex {
             let (pentagon_rotations, direction_rotations) = if origin_on_pent {
                 let leading_dir = bits::first_axe(origin.into())
                     .map_or_else(|| 0, NonZeroU8::get);
-                validate_direction(leading_dir, dir.into())?;
+                validate_direction(leading_dir, dir.into(), error)?;
 
                 let rotations = PENTAGON_ROTATIONS[usize::from(leading_dir)]
                     [usize::from(dir)];
@@ -1640,7 +2334,7 @@ impl CellIndex {
             } else if index_on_pent {
                 let leading_dir = bits::first_axe(self.into())
                     .map_or_else(|| 0, NonZeroU8::get);
-                validate_direction(leading_dir, rev_dir.into())?;
+                validate_direction(leading_dir, rev_dir.into(), error)?;
 
                 (
                     PENTAGON_ROTATIONS[usize::from(rev_dir)]
@@ -1685,7 +2379,7 @@ impl CellIndex {
                 .map_or_else(|| 0, NonZeroU8::get);
             let index_leading_dir =
                 bits::first_axe(self.into()).map_or_else(|| 0, NonZeroU8::get);
-            validate_direction(origin_leading_dir, index_leading_dir)?;
+            validate_direction(origin_leading_dir, index_leading_dir, error)?;
 
             let rotations = PENTAGON_ROTATIONS[usize::from(origin_leading_dir)]
                 [usize::from(index_leading_dir)];
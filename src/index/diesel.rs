@@ -0,0 +1,96 @@
+//! `diesel` integration.
+//!
+//! Cells are stored as their 64-bit index in `BIGINT` columns: valid indexes
+//! always fit in a signed integer, since their most significant bit is
+//! reserved (and set to 0).
+//!
+//! `TEXT` columns hold the hexadecimal representation instead.
+//!
+//! Every loaded value is validated.
+//!
+//! Loading works with any backend, but writing is backend-specific and
+//! requires the matching `diesel-mysql`, `diesel-postgres` or `diesel-sqlite`
+//! feature.
+
+use crate::CellIndex;
+use diesel::{
+    backend::Backend,
+    deserialize::{self, FromSql},
+    serialize::{self, Output, ToSql},
+    sql_types::{BigInt, Text},
+};
+
+/// Implements `ToSql` for a backend serializing into raw bytes.
+macro_rules! impl_to_sql_raw_bytes {
+    ($feature: literal, $backend: ty) => {
+        #[cfg(feature = $feature)]
+        impl ToSql<BigInt, $backend> for CellIndex {
+            fn to_sql<'b>(
+                &'b self,
+                out: &mut Output<'b, '_, $backend>,
+            ) -> serialize::Result {
+                let v = i64::try_from(u64::from(*self))?;
+                <i64 as ToSql<BigInt, $backend>>::to_sql(
+                    &v,
+                    &mut out.reborrow(),
+                )
+            }
+        }
+
+        #[cfg(feature = $feature)]
+        impl ToSql<Text, $backend> for CellIndex {
+            fn to_sql<'b>(
+                &'b self,
+                out: &mut Output<'b, '_, $backend>,
+            ) -> serialize::Result {
+                let v = self.to_string();
+                <str as ToSql<Text, $backend>>::to_sql(&v, &mut out.reborrow())
+            }
+        }
+    };
+}
+
+impl_to_sql_raw_bytes!("diesel-mysql", diesel::mysql::Mysql);
+impl_to_sql_raw_bytes!("diesel-postgres", diesel::pg::Pg);
+
+#[cfg(feature = "diesel-sqlite")]
+impl ToSql<BigInt, diesel::sqlite::Sqlite> for CellIndex {
+    fn to_sql<'b>(
+        &'b self,
+        out: &mut Output<'b, '_, diesel::sqlite::Sqlite>,
+    ) -> serialize::Result {
+        out.set_value(i64::try_from(u64::from(*self))?);
+        Ok(serialize::IsNull::No)
+    }
+}
+
+#[cfg(feature = "diesel-sqlite")]
+impl ToSql<Text, diesel::sqlite::Sqlite> for CellIndex {
+    fn to_sql<'b>(
+        &'b self,
+        out: &mut Output<'b, '_, diesel::sqlite::Sqlite>,
+    ) -> serialize::Result {
+        out.set_value(self.to_string());
+        Ok(serialize::IsNull::No)
+    }
+}
+
+impl<DB: Backend> FromSql<BigInt, DB> for CellIndex
+where
+    i64: FromSql<BigInt, DB>,
+{
+    fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+        let index = <i64 as FromSql<BigInt, DB>>::from_sql(bytes)?;
+        Ok(Self::try_from(u64::try_from(index)?)?)
+    }
+}
+
+impl<DB: Backend> FromSql<Text, DB> for CellIndex
+where
+    String: FromSql<Text, DB>,
+{
+    fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+        let text = <String as FromSql<Text, DB>>::from_sql(bytes)?;
+        Ok(text.parse()?)
+    }
+}
@@ -1,9 +1,14 @@
 use super::{bits, IndexMode};
 use crate::{
-    coord::FaceIJK, error, grid, Boundary, CellIndex, Direction,
+    coord::FaceIJK, error, grid, Boundary, CellIndex, Direction, LatLng,
     EARTH_RADIUS_KM,
 };
-use std::{cmp::Ordering, fmt, num::NonZeroU64, str::FromStr};
+use std::{
+    cmp::{self, Ordering},
+    fmt,
+    num::NonZeroU64,
+    str::FromStr,
+};
 
 /// Minimum value for a cell edge.
 const MIN: u8 = 1;
@@ -166,6 +171,30 @@ impl DirectedEdgeIndex {
         (self.origin(), self.destination())
     }
 
+    /// Returns the directed edge going the opposite way (i.e. from the
+    /// destination to the origin).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let index = h3o::DirectedEdgeIndex::try_from(0x13a194e699ab7fff)?;
+    /// let reversed = index.reversed();
+    /// assert_eq!(reversed.origin(), index.destination());
+    /// assert_eq!(reversed.destination(), index.origin());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Never in practice: a valid directed edge always has a reverse one, since
+    /// neighborhood is symmetric.
+    #[must_use]
+    pub fn reversed(self) -> Self {
+        let (origin, destination) = self.cells();
+        // Neighborhood is symmetric.
+        destination.edge(origin).expect("reversed edge")
+    }
+
     /// Returns the coordinates defining the directed edge.
     ///
     /// # Example
@@ -197,6 +226,59 @@ impl DirectedEdgeIndex {
         }
     }
 
+    /// Returns `count` points evenly spaced along the directed edge.
+    ///
+    /// The points follow the great circle arc(s) of the edge, from the start
+    /// to the end vertex (both included), which is useful for an accurate
+    /// rendering of the edge at large scale.
+    ///
+    /// `count` is clamped to at least 2 (the two vertices of the edge).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let index = h3o::DirectedEdgeIndex::try_from(0x13a194e699ab7fff)?;
+    /// let points = index.boundary_densified(10);
+    /// assert_eq!(points.len(), 10);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn boundary_densified(self, count: usize) -> Vec<LatLng> {
+        let boundary = self.boundary();
+        let lengths = (0..boundary.len() - 1)
+            .map(|i| boundary[i].distance_rads(boundary[i + 1]))
+            .collect::<Vec<_>>();
+        let total = lengths.iter().sum::<f64>();
+        let count = count.max(2);
+
+        let mut points = Vec::with_capacity(count);
+        let (mut segment, mut offset) = (0, 0.);
+        #[allow(clippy::cast_precision_loss)] // Point count fits in 52 bits.
+        let step = total / (count - 1) as f64;
+        for i in 0..count - 1 {
+            #[allow(clippy::cast_precision_loss)] // Ditto.
+            let distance = i as f64 * step;
+            // Move to the segment containing the point.
+            while segment < lengths.len() - 1
+                && distance > offset + lengths[segment]
+            {
+                offset += lengths[segment];
+                segment += 1;
+            }
+            let fraction = if lengths[segment] > 0. {
+                (distance - offset) / lengths[segment]
+            } else {
+                0.
+            };
+            points.push(
+                boundary[segment].interpolate(boundary[segment + 1], fraction),
+            );
+        }
+        points.push(boundary[boundary.len() - 1]);
+
+        points
+    }
+
     /// Computes the length of this directed edge, in radians.
     ///
     /// # Example
@@ -359,6 +441,183 @@ impl fmt::UpperHex for DirectedEdgeIndex {
     }
 }
 
+// -----------------------------------------------------------------------------
+
+/// Represents a physical edge shared by two cells, regardless of its
+/// direction.
+///
+/// It's stored as the canonical directed edge (i.e. the smallest of the two
+/// directions) so that each physical edge has a single representation,
+/// which makes it suitable as a key to store per-edge data only once.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(from = "DirectedEdgeIndex"))]
+pub struct UndirectedEdgeIndex(DirectedEdgeIndex);
+
+impl UndirectedEdgeIndex {
+    /// Returns the two directed edges making up this edge.
+    ///
+    /// The first one is the canonical one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{DirectedEdgeIndex, UndirectedEdgeIndex};
+    ///
+    /// let index = DirectedEdgeIndex::try_from(0x13a194e699ab7fff)?;
+    /// let edge = UndirectedEdgeIndex::from(index);
+    /// assert_eq!(edge.directed_edges(), (index.reversed(), index));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn directed_edges(self) -> (DirectedEdgeIndex, DirectedEdgeIndex) {
+        (self.0, self.0.reversed())
+    }
+
+    /// Returns the pair of cells sharing this edge.
+    ///
+    /// The cells are returned in the order of the canonical directed edge.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{CellIndex, DirectedEdgeIndex, UndirectedEdgeIndex};
+    ///
+    /// let index = DirectedEdgeIndex::try_from(0x13a194e699ab7fff)?;
+    /// let edge = UndirectedEdgeIndex::from(index);
+    /// assert_eq!(edge.cells(), (
+    ///     CellIndex::try_from(0x8a194e699a97fff)?,
+    ///     CellIndex::try_from(0x8a194e699ab7fff)?,
+    /// ));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn cells(self) -> (CellIndex, CellIndex) {
+        self.0.cells()
+    }
+
+    /// Returns the coordinates defining the edge.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{DirectedEdgeIndex, UndirectedEdgeIndex};
+    ///
+    /// let index = DirectedEdgeIndex::try_from(0x13a194e699ab7fff)?;
+    /// let boundary = UndirectedEdgeIndex::from(index).boundary();
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn boundary(self) -> Boundary {
+        self.0.boundary()
+    }
+
+    /// Computes the length of this edge, in radians.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{DirectedEdgeIndex, UndirectedEdgeIndex};
+    ///
+    /// let index = DirectedEdgeIndex::try_from(0x13a194e699ab7fff)?;
+    /// let edge = UndirectedEdgeIndex::from(index);
+    /// assert_eq!(edge.length_rads(), 1.1795418098325597e-5);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn length_rads(self) -> f64 {
+        self.0.length_rads()
+    }
+
+    /// Computes the length of this edge, in kilometers.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{DirectedEdgeIndex, UndirectedEdgeIndex};
+    ///
+    /// let index = DirectedEdgeIndex::try_from(0x13a194e699ab7fff)?;
+    /// let edge = UndirectedEdgeIndex::from(index);
+    /// assert_eq!(edge.length_km(), 0.07514869340636812);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn length_km(self) -> f64 {
+        self.0.length_km()
+    }
+
+    /// Computes the length of this edge, in meters.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{DirectedEdgeIndex, UndirectedEdgeIndex};
+    ///
+    /// let index = DirectedEdgeIndex::try_from(0x13a194e699ab7fff)?;
+    /// let edge = UndirectedEdgeIndex::from(index);
+    /// assert_eq!(edge.length_m(), 75.14869340636812);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn length_m(self) -> f64 {
+        self.0.length_m()
+    }
+}
+
+impl From<DirectedEdgeIndex> for UndirectedEdgeIndex {
+    fn from(value: DirectedEdgeIndex) -> Self {
+        Self(cmp::min(value, value.reversed()))
+    }
+}
+
+impl From<UndirectedEdgeIndex> for DirectedEdgeIndex {
+    /// Returns the canonical directed edge.
+    fn from(value: UndirectedEdgeIndex) -> Self {
+        value.0
+    }
+}
+
+impl From<UndirectedEdgeIndex> for u64 {
+    fn from(value: UndirectedEdgeIndex) -> Self {
+        value.0.into()
+    }
+}
+
+impl TryFrom<u64> for UndirectedEdgeIndex {
+    type Error = error::InvalidUndirectedEdgeIndex;
+
+    /// Only accepts the canonical directed edge, as returned by
+    /// `u64::from(UndirectedEdgeIndex)`.
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        let edge = DirectedEdgeIndex::try_from(value)
+            .map_err(|err| Self::Error::new(err.value, err.reason))?;
+        if edge.reversed() < edge {
+            return Err(Self::Error::new(Some(value), "non-canonical edge"));
+        }
+
+        Ok(Self(edge))
+    }
+}
+
+impl FromStr for UndirectedEdgeIndex {
+    type Err = error::InvalidUndirectedEdgeIndex;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        u64::from_str_radix(s, 16)
+            .map_err(|_| Self::Err {
+                value: None,
+                reason: "invalid 64-bit hex number",
+            })
+            .and_then(Self::try_from)
+    }
+}
+
+impl fmt::Display for UndirectedEdgeIndex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
 #[cfg(feature = "arbitrary")]
 impl<'a> arbitrary::Arbitrary<'a> for DirectedEdgeIndex {
     fn arbitrary(
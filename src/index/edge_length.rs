@@ -0,0 +1,73 @@
+use crate::EARTH_RADIUS_KM;
+
+/// Statistics on the edge lengths of a cell, in radians.
+///
+/// # Example
+///
+/// ```
+/// let index = h3o::CellIndex::try_from(0x8a1fb46622dffff)?;
+/// let stats = index.edge_length_stats().to_km();
+/// assert!(stats.min <= stats.mean && stats.mean <= stats.max);
+/// # Ok::<(), h3o::error::InvalidCellIndex>(())
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EdgeLengthStats {
+    /// Length of the shortest edge.
+    pub min: f64,
+    /// Length of the longest edge.
+    pub max: f64,
+    /// Average edge length.
+    pub mean: f64,
+}
+
+impl EdgeLengthStats {
+    /// Computes the statistics of the given edge lengths.
+    pub(crate) fn new(lengths: impl IntoIterator<Item = f64>) -> Self {
+        let (count, stats) = lengths.into_iter().fold(
+            (
+                0_u8,
+                Self {
+                    min: f64::INFINITY,
+                    max: 0.,
+                    mean: 0.,
+                },
+            ),
+            |(count, stats), length| {
+                (
+                    count + 1,
+                    Self {
+                        min: stats.min.min(length),
+                        max: stats.max.max(length),
+                        mean: stats.mean + length,
+                    },
+                )
+            },
+        );
+
+        Self {
+            mean: stats.mean / f64::from(count),
+            ..stats
+        }
+    }
+
+    /// Converts the statistics into kilometers.
+    #[must_use]
+    pub fn to_km(self) -> Self {
+        self.scale(EARTH_RADIUS_KM)
+    }
+
+    /// Converts the statistics into meters.
+    #[must_use]
+    pub fn to_m(self) -> Self {
+        self.scale(EARTH_RADIUS_KM * 1000.)
+    }
+
+    /// Scales every statistic by the given factor.
+    fn scale(self, factor: f64) -> Self {
+        Self {
+            min: self.min * factor,
+            max: self.max * factor,
+            mean: self.mean * factor,
+        }
+    }
+}
@@ -0,0 +1,267 @@
+use super::bits;
+use crate::{CellIndex, CellSet, LatLng, Resolution};
+
+/// A frozen set of cells, optimized for containment queries.
+///
+/// Cells are stored as a sorted list of disjoint intervals (the range of
+/// their descendants at the finest resolution), normalized at construction:
+/// cells covered by a coarser cell are discarded and complete groups of
+/// siblings are replaced by their parent (i.e. the set is compacted).
+///
+/// Lookups are done by binary search, in `O(log n)`, which makes it a good
+/// fit for read-heavy workloads (e.g. geofencing) where the set is built once
+/// and queried many times.
+///
+/// # Example
+///
+/// ```
+/// use h3o::{CellIndex, FrozenCellSet, LatLng, Resolution};
+///
+/// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+/// let set = index.grid_disk::<FrozenCellSet>(2);
+///
+/// let child = index.center_child(Resolution::Twelve).expect("child");
+/// assert!(set.contains_cell(child));
+/// assert!(set.contains_latlng(LatLng::from(index)));
+/// # Ok::<(), h3o::error::InvalidCellIndex>(())
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct FrozenCellSet {
+    /// Compacted cells, sorted by the start of their interval.
+    cells: Vec<CellIndex>,
+}
+
+impl FrozenCellSet {
+    /// Returns the number of cells in the (normalized) set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{CellIndex, FrozenCellSet, Resolution};
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let set = index
+    ///     .children(Resolution::Eleven)
+    ///     .collect::<FrozenCellSet>();
+    /// assert_eq!(set.len(), 1);
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// Returns whether the set is empty or not.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let set = h3o::FrozenCellSet::default();
+    /// assert!(set.is_empty());
+    /// ```
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Returns the (normalized) cells of the set, sorted.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{CellIndex, FrozenCellSet};
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let set = index.grid_disk::<FrozenCellSet>(1);
+    /// let cells = set.iter().collect::<Vec<_>>();
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    #[must_use]
+    pub fn iter(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = CellIndex> + ExactSizeIterator + '_
+    {
+        self.cells.iter().copied()
+    }
+
+    /// Returns `true` if the specified cell is entirely covered by the set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{CellIndex, FrozenCellSet, Resolution};
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let set = std::iter::once(index).collect::<FrozenCellSet>();
+    /// let child = index.center_child(Resolution::Fifteen).expect("child");
+    /// assert!(set.contains_cell(child));
+    /// let parent = index.parent(Resolution::Nine).expect("parent");
+    /// assert!(!set.contains_cell(parent));
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    #[must_use]
+    pub fn contains_cell(&self, cell: CellIndex) -> bool {
+        let (start, end) = interval(cell);
+        // Intervals are either nested or disjoint: only the last interval
+        // starting before the cell may contain it.
+        let position = self.cells.partition_point(|&c| interval(c).0 <= start);
+        position
+            .checked_sub(1)
+            .is_some_and(|index| interval(self.cells[index]).1 >= end)
+    }
+
+    /// Returns `true` if the specified coordinate is covered by the set.
+    ///
+    /// A coordinate is covered if its cell at the finest resolution is covered,
+    /// thus giving the same result as [`CellSet::contains_latlng`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{CellIndex, FrozenCellSet, LatLng};
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let set = std::iter::once(index).collect::<FrozenCellSet>();
+    /// assert!(set.contains_latlng(LatLng::from(index)));
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    #[must_use]
+    pub fn contains_latlng(&self, ll: LatLng) -> bool {
+        self.contains_cell(ll.to_cell(Resolution::Fifteen))
+    }
+
+    /// Intersects the set with a stream of query cells.
+    ///
+    /// For each query cell, yields the cell itself if it is entirely covered
+    /// by the set, or else the cells of the set it contains (if any). Each
+    /// query costs `O(log n)`, plus the size of its output.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{CellIndex, FrozenCellSet, Resolution};
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let child = index.center_child(Resolution::Twelve).expect("child");
+    /// let set = [child].into_iter().collect::<FrozenCellSet>();
+    /// let cells = set.intersection([index]).collect::<Vec<_>>();
+    /// assert_eq!(cells, vec![child]);
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    pub fn intersection<'a>(
+        &'a self,
+        cells: impl IntoIterator<Item = CellIndex> + 'a,
+    ) -> impl Iterator<Item = CellIndex> + 'a {
+        cells.into_iter().flat_map(move |cell| {
+            let (covered, inner) = if self.contains_cell(cell) {
+                (Some(cell), &[][..])
+            } else {
+                (None, self.descendants(cell))
+            };
+            covered.into_iter().chain(inner.iter().copied())
+        })
+    }
+
+    /// Returns the cells covered by the set but not by `other`, compacted and
+    /// sorted.
+    pub(super) fn difference(&self, other: &Self) -> Vec<CellIndex> {
+        let mut cells = Vec::new();
+        let mut candidates = self.cells.clone();
+        while let Some(cell) = candidates.pop() {
+            if other.contains_cell(cell) {
+                continue;
+            }
+            match cell.resolution().succ() {
+                // Partially covered, refine.
+                Some(resolution) if !other.descendants(cell).is_empty() => {
+                    candidates.extend(cell.children(resolution));
+                }
+                _ => cells.push(cell),
+            }
+        }
+        cells.sort_unstable();
+
+        cells
+    }
+
+    /// Returns the cells of the set that are descendants of `cell`.
+    fn descendants(&self, cell: CellIndex) -> &[CellIndex] {
+        let (start, end) = interval(cell);
+        let from = self.cells.partition_point(|&c| interval(c).0 < start);
+        let to = self.cells.partition_point(|&c| interval(c).0 <= end);
+        &self.cells[from..to.max(from)]
+    }
+}
+
+impl FromIterator<CellIndex> for FrozenCellSet {
+    fn from_iter<T: IntoIterator<Item = CellIndex>>(iter: T) -> Self {
+        let mut cells = iter.into_iter().collect::<Vec<_>>();
+        // Coarser cells first, to discard their descendants.
+        cells.sort_unstable_by_key(|&cell| {
+            (interval(cell).0, cell.resolution())
+        });
+
+        let mut normalized: Vec<CellIndex> = Vec::with_capacity(cells.len());
+        for cell in cells {
+            let is_covered = normalized
+                .last()
+                .is_some_and(|&last| interval(last).1 >= interval(cell).1);
+            if !is_covered {
+                push_compacted(&mut normalized, cell);
+            }
+        }
+
+        Self { cells: normalized }
+    }
+}
+
+impl From<&CellSet> for FrozenCellSet {
+    fn from(value: &CellSet) -> Self {
+        value.iter().collect()
+    }
+}
+
+impl IntoIterator for FrozenCellSet {
+    type Item = CellIndex;
+    type IntoIter = std::vec::IntoIter<CellIndex>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.cells.into_iter()
+    }
+}
+
+/// Pushes a cell, replacing complete groups of siblings by their parent.
+fn push_compacted(cells: &mut Vec<CellIndex>, mut cell: CellIndex) {
+    loop {
+        cells.push(cell);
+        let resolution = cell.resolution();
+        let Some(parent) = resolution.pred().and_then(|res| cell.parent(res))
+        else {
+            return;
+        };
+        // Siblings are contiguous, and distinct once the nested cells are
+        // discarded.
+        let count = if parent.is_pentagon() { 6 } else { 7 };
+        let Some(start) = cells.len().checked_sub(count) else {
+            return;
+        };
+        let is_complete = cells[start..].iter().all(|sibling| {
+            sibling.resolution() == resolution
+                && sibling.parent(parent.resolution()) == Some(parent)
+        });
+        if !is_complete {
+            return;
+        }
+        cells.truncate(start);
+        cell = parent;
+    }
+}
+
+/// Returns the range of the descendants, at the finest resolution, of a cell.
+pub(super) fn interval(cell: CellIndex) -> (u64, u64) {
+    // Unused directions are set to 7, thus the end is already the maximum.
+    let end = bits::set_resolution(u64::from(cell), Resolution::Fifteen);
+    let unused = (1 << cell.resolution().direction_offset()) - 1;
+
+    (end & !unused, end)
+}
@@ -1,11 +1,14 @@
-use super::CellIndex;
+use super::{frozen_set::interval, CellIndex};
 use crate::{
     coord::{CoordCube, CoordIJK, LocalIJK},
     error::{CompactionError, LocalIjError},
     index::bits,
     Direction, Resolution, DIRECTION_BITSIZE,
 };
-use std::cmp::max;
+use std::{
+    cmp::{max, Reverse},
+    collections::{BinaryHeap, VecDeque},
+};
 
 /// Iterator over a children cell index at a given resolution.
 pub struct Children {
@@ -342,3 +345,137 @@ impl Iterator for Compact {
         Some(cell)
     }
 }
+
+// -----------------------------------------------------------------------------
+
+/// Iterator over the compacted union of sorted streams of compacted cells.
+pub struct UnionCompacted<I> {
+    /// Input streams.
+    streams: Vec<I>,
+    /// Next cell of each non-exhausted stream, ordered by interval start.
+    heads: BinaryHeap<Reverse<(u64, Resolution, usize, CellIndex)>>,
+    /// Cells waiting for their siblings, to be compacted with them.
+    pending: VecDeque<CellIndex>,
+    /// Number of pending cells that can't be compacted anymore.
+    ready: usize,
+    /// End of the area covered so far.
+    covered_until: Option<u64>,
+}
+
+impl<I> UnionCompacted<I>
+where
+    I: Iterator<Item = CellIndex>,
+{
+    /// Returns an iterator over the union of the input streams.
+    pub fn new(streams: impl IntoIterator<Item = I>) -> Self {
+        let mut streams = streams.into_iter().collect::<Vec<_>>();
+        let heads = streams
+            .iter_mut()
+            .enumerate()
+            .filter_map(|(index, stream)| {
+                stream.next().map(|cell| Self::head(cell, index))
+            })
+            .collect();
+
+        Self {
+            streams,
+            heads,
+            pending: VecDeque::new(),
+            ready: 0,
+            covered_until: None,
+        }
+    }
+
+    /// Returns the heap entry of the next cell of a stream.
+    fn head(
+        cell: CellIndex,
+        index: usize,
+    ) -> Reverse<(u64, Resolution, usize, CellIndex)> {
+        // Start of interval first, then coarser first on ties.
+        Reverse((interval(cell).0, cell.resolution(), index, cell))
+    }
+
+    /// Returns the next cell across every streams, in order.
+    fn next_input(&mut self) -> Option<CellIndex> {
+        let Reverse((_, _, index, cell)) = self.heads.pop()?;
+        if let Some(next) = self.streams[index].next() {
+            self.heads.push(Self::head(next, index));
+        }
+
+        Some(cell)
+    }
+
+    /// Adds a cell, replacing complete groups of siblings by their parent.
+    fn push(&mut self, mut cell: CellIndex) {
+        loop {
+            self.pending.push_back(cell);
+            let resolution = cell.resolution();
+            let Some(parent) =
+                resolution.pred().and_then(|res| cell.parent(res))
+            else {
+                return;
+            };
+            let count = if parent.is_pentagon() { 6 } else { 7 };
+            let Some(start) = self.pending.len().checked_sub(count) else {
+                return;
+            };
+            if start < self.ready {
+                return;
+            }
+            let is_complete = self.pending.range(start..).all(|sibling| {
+                sibling.resolution() == resolution
+                    && sibling.parent(parent.resolution()) == Some(parent)
+            });
+            if !is_complete {
+                return;
+            }
+            self.pending.truncate(start);
+            cell = parent;
+        }
+    }
+}
+
+impl<I> Iterator for UnionCompacted<I>
+where
+    I: Iterator<Item = CellIndex>,
+{
+    type Item = CellIndex;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.ready > 0 {
+                self.ready -= 1;
+                return self.pending.pop_front();
+            }
+
+            let Some(cell) = self.next_input() else {
+                // No more input, nothing left to compact.
+                self.ready = self.pending.len();
+                if self.ready == 0 {
+                    return None;
+                }
+                continue;
+            };
+            let (start, end) = interval(cell);
+            if self.covered_until.is_some_and(|until| end <= until) {
+                continue;
+            }
+            self.covered_until = Some(end);
+
+            // Pending cells whose parent ends before this cell (and thus any
+            // upcoming one) can't be compacted anymore.
+            self.ready = self
+                .pending
+                .iter()
+                .position(|pending| {
+                    let resolution = pending.resolution();
+                    resolution
+                        .pred()
+                        .and_then(|res| pending.parent(res))
+                        .is_some_and(|parent| interval(parent).1 >= start)
+                })
+                .unwrap_or(self.pending.len());
+            self.push(cell);
+        }
+    }
+}
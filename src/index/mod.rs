@@ -2,16 +2,26 @@
 
 pub mod bits;
 mod cell;
+#[cfg(feature = "diesel")]
+mod diesel;
 mod edge;
+mod edge_length;
+mod frozen_set;
 mod iterator;
 mod mode;
+mod set;
+#[cfg(feature = "sqlx")]
+mod sqlx;
 mod triangle;
 mod vertex;
 
 pub use cell::CellIndex;
-pub use edge::{DirectedEdgeIndex, Edge};
+pub use edge::{DirectedEdgeIndex, Edge, UndirectedEdgeIndex};
+pub use edge_length::EdgeLengthStats;
+pub use frozen_set::FrozenCellSet;
 pub use mode::IndexMode;
+pub use set::{CellSet, CellSetDiff, EdgeKind, LocalIjBatch};
 pub use vertex::{Vertex, VertexIndex};
 
-use iterator::{Children, Compact, GridPathCells};
+use iterator::{Children, Compact, GridPathCells, UnionCompacted};
 use triangle::Triangle;
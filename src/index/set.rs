@@ -0,0 +1,438 @@
+use crate::{
+    coord::Vec3d, error::LocalIjError, CellIndex, DirectedEdgeIndex,
+    FrozenCellSet, LatLng, LocalIJ, Resolution,
+};
+use ahash::{HashSet, HashSetExt};
+
+/// Maximum number of anchors tried by [`CellSet::to_local_ij`].
+const MAX_ANCHOR_CANDIDATES: usize = 16;
+
+/// A set of cell indexes.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct CellSet(HashSet<CellIndex>);
+
+impl CellSet {
+    /// Initializes a new empty set of cells.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let set = h3o::CellSet::new();
+    /// assert!(set.is_empty());
+    /// ```
+    #[must_use]
+    pub fn new() -> Self {
+        Self(HashSet::new())
+    }
+
+    /// Initializes a new empty set of cells, with space for at least
+    /// `capacity` cells.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let set = h3o::CellSet::with_capacity(42);
+    /// assert!(set.is_empty());
+    /// ```
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self(HashSet::with_capacity(capacity))
+    }
+
+    /// Returns the number of cells in the set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let index = h3o::CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let set = index.grid_disk::<h3o::CellSet>(1);
+    /// assert_eq!(set.len(), 7);
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns whether the set is empty or not.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let index = h3o::CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let set = index.grid_disk::<h3o::CellSet>(1);
+    /// assert!(!set.is_empty());
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns `true` if the specified cell is present in the set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let index = h3o::CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let set = index.grid_disk::<h3o::CellSet>(1);
+    /// assert!(set.contains(index));
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    #[must_use]
+    pub fn contains(&self, cell: CellIndex) -> bool {
+        self.0.contains(&cell)
+    }
+
+    /// Returns `true` if the specified cell is covered by the set, i.e. if the
+    /// cell itself or one of its ancestors is present in the set.
+    ///
+    /// This works directly on compacted (mixed-resolution) sets, without
+    /// uncompacting them, and costs at most one lookup per resolution.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{CellIndex, CellSet, Resolution};
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let set = CellIndex::compact(index.children(Resolution::Eleven))?
+    ///     .collect::<CellSet>();
+    /// assert!(set.contains_cell(index));
+    /// let child = index.center_child(Resolution::Fifteen).expect("child");
+    /// assert!(set.contains_cell(child));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[must_use]
+    pub fn contains_cell(&self, cell: CellIndex) -> bool {
+        Resolution::range(Resolution::Zero, cell.resolution())
+            .rev()
+            .filter_map(|resolution| cell.parent(resolution))
+            .any(|ancestor| self.contains(ancestor))
+    }
+
+    /// Returns `true` if the specified coordinate is covered by the set.
+    ///
+    /// A coordinate is covered if its cell at the finest resolution is covered
+    /// (see [`Self::contains_cell`]): for a compacted set, this gives the same
+    /// result as a lookup in the uncompacted set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{CellIndex, CellSet, LatLng};
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let set = std::iter::once(index).collect::<CellSet>();
+    /// assert!(set.contains_latlng(LatLng::from(index)));
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    #[must_use]
+    pub fn contains_latlng(&self, ll: LatLng) -> bool {
+        self.contains_cell(ll.to_cell(Resolution::Fifteen))
+    }
+
+    /// Adds a cell to the set.
+    ///
+    /// Returns whether the cell was newly inserted.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let index = h3o::CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let mut set = h3o::CellSet::new();
+    /// assert!(set.insert(index));
+    /// assert!(!set.insert(index));
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    pub fn insert(&mut self, cell: CellIndex) -> bool {
+        self.0.insert(cell)
+    }
+
+    /// Removes a cell from the set.
+    ///
+    /// Returns whether the cell was present in the set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let index = h3o::CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let mut set = index.grid_disk::<h3o::CellSet>(1);
+    /// assert!(set.remove(index));
+    /// assert!(!set.remove(index));
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    pub fn remove(&mut self, cell: CellIndex) -> bool {
+        self.0.remove(&cell)
+    }
+
+    /// Returns the cells of the set, in arbitrary order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let index = h3o::CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let set = index.grid_disk::<h3o::CellSet>(1);
+    /// let cells = set.iter().collect::<Vec<_>>();
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = CellIndex> + '_ {
+        self.0.iter().copied()
+    }
+
+    /// Returns the directed edges going out of the cells of the set, labeled
+    /// according to their destination.
+    ///
+    /// An edge is internal if its destination is part of the set, external
+    /// otherwise (i.e. it crosses the boundary of the set). Every edge between
+    /// two cells of the set is returned twice, once in each direction.
+    ///
+    /// Note that cells are matched exactly, thus all the cells of the set are
+    /// expected to share the same resolution.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{CellIndex, CellSet, EdgeKind};
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let set = index.grid_disk::<CellSet>(1);
+    /// let outline = set
+    ///     .edges()
+    ///     .filter_map(|(edge, kind)| (kind == EdgeKind::External).then_some(edge))
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(outline.len(), 18);
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    pub fn edges(
+        &self,
+    ) -> impl Iterator<Item = (DirectedEdgeIndex, EdgeKind)> + '_ {
+        self.iter()
+            .flat_map(CellIndex::edges)
+            .map(|edge| (edge, self.edge_kind(edge)))
+    }
+
+    /// Computes the changes from this set to `other`.
+    ///
+    /// Both sets are treated as coverages (i.e. a cell covers all of its
+    /// descendants), thus they may be compacted differently, or not at all:
+    /// the returned cells are normalized (compacted and sorted), which gives
+    /// minimal deltas.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::{CellIndex, CellSet, Resolution};
+    ///
+    /// let index = CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let old = std::iter::once(index).collect::<CellSet>();
+    /// let new = index
+    ///     .children(Resolution::Eleven)
+    ///     .skip(1)
+    ///     .collect::<CellSet>();
+    ///
+    /// let diff = old.diff(&new);
+    /// assert!(diff.added().is_empty());
+    /// assert_eq!(diff.removed().len(), 1);
+    /// assert_eq!(diff.unchanged().len(), 6);
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> CellSetDiff {
+        let (old, new) =
+            (FrozenCellSet::from(self), FrozenCellSet::from(other));
+        let unchanged = old.intersection(new.iter()).collect::<FrozenCellSet>();
+
+        CellSetDiff {
+            added: new.difference(&old),
+            removed: old.difference(&new),
+            unchanged: unchanged.into_iter().collect(),
+        }
+    }
+
+    /// Computes the local `IJ` coordinates of every cell of the set, using an
+    /// automatically selected anchor.
+    ///
+    /// The cells closest to the center of the set are tried as anchor, and the
+    /// first one able to convert every cell is used. If there is no such cell
+    /// (e.g. the set is too large, or wraps around a pentagon), the anchor with
+    /// the fewest conversion failures is used and the failures are reported
+    /// alongside the coordinates.
+    ///
+    /// Returns `None` if the set is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let index = h3o::CellIndex::try_from(0x8a1fb46622dffff)?;
+    /// let set = index.grid_disk::<h3o::CellSet>(2);
+    /// let batch = set.to_local_ij().expect("non-empty set");
+    /// assert_eq!(batch.anchor(), index);
+    /// assert_eq!(batch.coords().len(), 19);
+    /// assert!(batch.failures().is_empty());
+    /// # Ok::<(), h3o::error::InvalidCellIndex>(())
+    /// ```
+    #[must_use]
+    pub fn to_local_ij(&self) -> Option<LocalIjBatch> {
+        // Rank the cells by their angular distance to the mean center.
+        let centers = self
+            .iter()
+            .map(|cell| (cell, Vec3d::from(LatLng::from(cell))))
+            .collect::<Vec<_>>();
+        let mean =
+            centers.iter().fold(Vec3d::new(0., 0., 0.), |acc, &(_, v)| {
+                Vec3d::new(acc.x + v.x, acc.y + v.y, acc.z + v.z)
+            });
+        let mut candidates = centers
+            .iter()
+            .map(|&(cell, center)| (center.dot(&mean), cell))
+            .collect::<Vec<_>>();
+        candidates.sort_unstable_by(|a, b| b.0.total_cmp(&a.0));
+
+        let mut best: Option<LocalIjBatch> = None;
+        for &(_, anchor) in candidates.iter().take(MAX_ANCHOR_CANDIDATES) {
+            let batch = self.to_local_ij_from(anchor);
+            if batch.failures.is_empty() {
+                return Some(batch);
+            }
+            if best
+                .as_ref()
+                .is_none_or(|best| batch.failures.len() < best.failures.len())
+            {
+                best = Some(batch);
+            }
+        }
+        best
+    }
+
+    /// Computes the local `IJ` coordinates of every cell, from `anchor`.
+    fn to_local_ij_from(&self, anchor: CellIndex) -> LocalIjBatch {
+        let mut coords = Vec::with_capacity(self.len());
+        let mut failures = Vec::new();
+        for cell in self.iter() {
+            match cell.to_local_ij(anchor) {
+                Ok(coord) => coords.push((cell, coord)),
+                Err(err) => failures.push((cell, err)),
+            }
+        }
+        LocalIjBatch {
+            anchor,
+            coords,
+            failures,
+        }
+    }
+
+    /// Returns the kind of a directed edge going out of the set.
+    fn edge_kind(&self, edge: DirectedEdgeIndex) -> EdgeKind {
+        if self.contains(edge.destination()) {
+            EdgeKind::Internal
+        } else {
+            EdgeKind::External
+        }
+    }
+}
+
+impl FromIterator<CellIndex> for CellSet {
+    fn from_iter<T: IntoIterator<Item = CellIndex>>(iter: T) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl Extend<CellIndex> for CellSet {
+    fn extend<T: IntoIterator<Item = CellIndex>>(&mut self, iter: T) {
+        self.0.extend(iter);
+    }
+}
+
+impl IntoIterator for CellSet {
+    type Item = CellIndex;
+    type IntoIter = std::collections::hash_set::IntoIter<CellIndex>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+// -----------------------------------------------------------------------------
+
+/// Local `IJ` coordinates of a set of cells, sharing the same anchor.
+#[derive(Clone, Debug)]
+pub struct LocalIjBatch {
+    /// Anchor cell.
+    anchor: CellIndex,
+    /// Successfully converted cells.
+    coords: Vec<(CellIndex, LocalIJ)>,
+    /// Cells that couldn't be converted.
+    failures: Vec<(CellIndex, LocalIjError)>,
+}
+
+impl LocalIjBatch {
+    /// Returns the anchor cell shared by every coordinate.
+    #[must_use]
+    pub const fn anchor(&self) -> CellIndex {
+        self.anchor
+    }
+
+    /// Returns the converted cells, along with their local `IJ` coordinates.
+    #[must_use]
+    pub fn coords(&self) -> &[(CellIndex, LocalIJ)] {
+        &self.coords
+    }
+
+    /// Returns the cells that couldn't be converted, along with the reason.
+    #[must_use]
+    pub fn failures(&self) -> &[(CellIndex, LocalIjError)] {
+        &self.failures
+    }
+}
+
+// -----------------------------------------------------------------------------
+
+/// Changes between two sets of cells, see [`CellSet::diff`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct CellSetDiff {
+    /// Cells only covered by the new set.
+    added: Vec<CellIndex>,
+    /// Cells only covered by the old set.
+    removed: Vec<CellIndex>,
+    /// Cells covered by both sets.
+    unchanged: Vec<CellIndex>,
+}
+
+impl CellSetDiff {
+    /// Returns the cells only covered by the new set.
+    #[must_use]
+    pub fn added(&self) -> &[CellIndex] {
+        &self.added
+    }
+
+    /// Returns the cells only covered by the old set.
+    #[must_use]
+    pub fn removed(&self) -> &[CellIndex] {
+        &self.removed
+    }
+
+    /// Returns the cells covered by both sets.
+    #[must_use]
+    pub fn unchanged(&self) -> &[CellIndex] {
+        &self.unchanged
+    }
+
+    /// Returns `true` if both sets cover the same area.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
+    }
+}
+
+// -----------------------------------------------------------------------------
+
+/// Position of a directed edge relative to a set of cells.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+#[allow(clippy::exhaustive_enums)] // An edge is either inside or outside.
+pub enum EdgeKind {
+    /// Both the origin and the destination are in the set.
+    Internal,
+    /// Only the origin is in the set, the edge crosses the set boundary.
+    External,
+}
@@ -0,0 +1,67 @@
+//! `sqlx` integration.
+//!
+//! Cells are stored as their 64-bit index in `BIGINT` columns: valid indexes
+//! always fit in a signed integer, since their most significant bit is
+//! reserved (and set to 0).
+//!
+//! `TEXT` columns (holding the hexadecimal representation) can be decoded
+//! too, and written by wrapping the cell in `sqlx::types::Text`.
+//!
+//! Every decoded value is validated.
+
+use crate::CellIndex;
+use sqlx::{
+    encode::IsNull, error::BoxDynError, Database, Decode, Encode, Type,
+    ValueRef,
+};
+
+impl<DB: Database> Type<DB> for CellIndex
+where
+    i64: Type<DB>,
+    str: Type<DB>,
+{
+    fn type_info() -> DB::TypeInfo {
+        <i64 as Type<DB>>::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <i64 as Type<DB>>::compatible(ty) || <str as Type<DB>>::compatible(ty)
+    }
+}
+
+impl<'q, DB: Database> Encode<'q, DB> for CellIndex
+where
+    i64: Encode<'q, DB>,
+{
+    fn encode_by_ref(
+        &self,
+        buf: &mut <DB as Database>::ArgumentBuffer<'q>,
+    ) -> Result<IsNull, BoxDynError> {
+        i64::try_from(u64::from(*self))?.encode_by_ref(buf)
+    }
+
+    fn size_hint(&self) -> usize {
+        size_of::<i64>()
+    }
+}
+
+impl<'r, DB: Database> Decode<'r, DB> for CellIndex
+where
+    i64: Decode<'r, DB>,
+    &'r str: Decode<'r, DB>,
+    str: Type<DB>,
+{
+    fn decode(
+        value: <DB as Database>::ValueRef<'r>,
+    ) -> Result<Self, BoxDynError> {
+        let is_text = !value.is_null()
+            && <str as Type<DB>>::compatible(&value.type_info());
+        if is_text {
+            let text = <&str as Decode<DB>>::decode(value)?;
+            return Ok(text.parse()?);
+        }
+
+        let index = <i64 as Decode<DB>>::decode(value)?;
+        Ok(Self::try_from(u64::try_from(index)?)?)
+    }
+}
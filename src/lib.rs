@@ -228,6 +228,9 @@
     // Usually yes, but not really applicable for most literals in this crate.
     clippy::unreadable_literal,
 )]
+// The dependencies of `sqlx` come with duplicates, which are out of our hands
+// (the other features are linted without it on the CI).
+#![cfg_attr(feature = "sqlx", allow(clippy::multiple_crate_versions))]
 
 // }}}
 
@@ -239,19 +242,29 @@ mod coord;
 mod direction;
 pub mod error;
 mod face;
+#[cfg(feature = "geoarrow")]
+pub mod geoarrow;
 #[cfg(feature = "geo")]
 pub mod geom;
+pub mod graph;
+mod graticule;
 mod grid;
 mod index;
+mod math;
 mod resolution;
+mod trace;
 
 pub use base_cell::BaseCell;
-pub use boundary::Boundary;
-pub use coord::{LatLng, LocalIJ};
+pub use boundary::{Boundary, BoundaryBuffer};
+pub use coord::{FixedLatLng, LatLng, LocalIJ};
 pub use direction::Direction;
 pub use face::{Face, FaceSet};
+pub use graticule::Graticule;
+pub use grid::{simplify_path, trace_to_cells, GridDistance};
 pub use index::{
-    CellIndex, DirectedEdgeIndex, Edge, IndexMode, Vertex, VertexIndex,
+    CellIndex, CellSet, CellSetDiff, DirectedEdgeIndex, Edge, EdgeKind,
+    EdgeLengthStats, FrozenCellSet, IndexMode, LocalIjBatch,
+    UndirectedEdgeIndex, Vertex, VertexIndex,
 };
 pub use resolution::Resolution;
 
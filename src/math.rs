@@ -0,0 +1,68 @@
+//! Floating-point math functions.
+//!
+//! By default, they are forwarded to the standard library, whose implementation
+//! depends on the platform: results may differ in the last bits from one
+//! platform to another (which can be enough to assign a point close to a cell
+//! boundary to different cells).
+//!
+//! With the `libm` feature, a portable implementation is used instead, which
+//! gives bit-identical results on every platform.
+
+// Macro to forward unary functions, sharing optional attributes.
+macro_rules! unary {
+    (@[$($attr:tt)*]) => {};
+    (@[$($attr:tt)*] $name:ident $(, $rest:ident)*) => {
+        #[doc = concat!("Computes `", stringify!($name), "(x)`.")]
+        $($attr)*
+        #[cfg(not(feature = "libm"))]
+        pub fn $name(x: f64) -> f64 {
+            x.$name()
+        }
+
+        #[doc = concat!("Computes `", stringify!($name), "(x)`.")]
+        $($attr)*
+        #[cfg(feature = "libm")]
+        pub fn $name(x: f64) -> f64 {
+            libm::$name(x)
+        }
+
+        unary!(@[$($attr)*] $($rest),*);
+    };
+    ($(#[$attr:meta])* $($name:ident),+) => {
+        unary!(@[$(#[$attr])*] $($name),+);
+    };
+}
+
+unary!(sin, cos, tan, asin, acos, atan, log2);
+unary!(
+    #[cfg(feature = "geo")]
+    sinh,
+    asinh,
+    exp2
+);
+
+/// Computes the four quadrant arctangent of `y` and `x`.
+pub fn atan2(y: f64, x: f64) -> f64 {
+    #[cfg(not(feature = "libm"))]
+    return y.atan2(x);
+    #[cfg(feature = "libm")]
+    return libm::atan2(y, x);
+}
+
+/// Computes `sqrt(x² + y²)`, without undue overflow or underflow.
+pub fn hypot(x: f64, y: f64) -> f64 {
+    #[cfg(not(feature = "libm"))]
+    return x.hypot(y);
+    #[cfg(feature = "libm")]
+    return libm::hypot(x, y);
+}
+
+/// Computes `(a * b) + c`, with a single rounding.
+// Can only be `const` with some backends.
+#[allow(clippy::missing_const_for_fn)]
+pub fn mul_add(a: f64, b: f64, c: f64) -> f64 {
+    #[cfg(not(feature = "libm"))]
+    return a.mul_add(b, c);
+    #[cfg(feature = "libm")]
+    return libm::fma(a, b, c);
+}
@@ -1,11 +1,24 @@
 use crate::{
-    error, index::bits, BaseCell, CellIndex, DIRECTION_BITSIZE, NUM_PENTAGONS,
+    error, index::bits, math::log2, BaseCell, CellIndex, VertexIndex,
+    DIRECTION_BITSIZE, EARTH_RADIUS_KM, NUM_PENTAGONS,
+};
+use std::{
+    f64::consts::PI,
+    ffi::c_int,
+    fmt,
+    iter::{DoubleEndedIterator, FusedIterator},
+    str::FromStr,
 };
-use std::{ffi::c_int, fmt, iter::DoubleEndedIterator, str::FromStr};
 
 /// Maximum supported H3 resolution.
 pub const MAX: u8 = 15;
 
+/// Size, in pixels, of a web map tile.
+pub const TILE_SIZE: f64 = 256.;
+
+/// Radius, in meters, of the sphere used by the web-mercator projection.
+const MERCATOR_RADIUS_M: f64 = 6_378_137.;
+
 /// Cell resolution, from 0 to 15.
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
 #[repr(u8)]
@@ -80,9 +93,13 @@ impl Resolution {
     /// assert!(Resolution::Fifteen.succ().is_none());
     /// ```
     #[must_use]
-    pub fn succ(self) -> Option<Self> {
+    pub const fn succ(self) -> Option<Self> {
         // SAFETY: Every resolution but 15 have a finer one.
-        (self != Self::Fifteen).then(|| Self::new_unchecked(u8::from(self) + 1))
+        if matches!(self, Self::Fifteen) {
+            None
+        } else {
+            Some(Self::new_unchecked(self as u8 + 1))
+        }
     }
 
     /// Return the previous resolution, if any.
@@ -96,9 +113,13 @@ impl Resolution {
     /// assert!(Resolution::Zero.pred().is_none());
     /// ```
     #[must_use]
-    pub fn pred(self) -> Option<Self> {
+    pub const fn pred(self) -> Option<Self> {
         // SAFETY: Every resolution but 0 have a coarser one.
-        (self != Self::Zero).then(|| Self::new_unchecked(u8::from(self) - 1))
+        if matches!(self, Self::Zero) {
+            None
+        } else {
+            Some(Self::new_unchecked(self as u8 - 1))
+        }
     }
 
     /// Iterates over the resolution in `[start, end]` (inclusive bounds).
@@ -130,16 +151,35 @@ impl Resolution {
     ///               .collect::<Vec<_>>();
     /// assert_eq!(res, vec![Resolution::Two, Resolution::One, Resolution::Zero]);
     /// ```
+    #[must_use]
     #[allow(unsafe_code)]
     pub fn range(
         start: Self,
         end: Self,
-    ) -> impl Iterator<Item = Self> + DoubleEndedIterator {
+    ) -> impl DoubleEndedIterator<Item = Self> + ExactSizeIterator + FusedIterator
+    {
         (u8::from(start)..=u8::from(end))
             // SAFETY: values between two resolutions are valid resolutions.
             .map(|value| unsafe { std::mem::transmute::<u8, Self>(value) })
     }
 
+    /// Iterates over every resolution, from the coarsest to the finest.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::Resolution;
+    ///
+    /// assert_eq!(Resolution::iter().len(), 16);
+    /// assert_eq!(Resolution::iter().next_back(), Some(Resolution::Fifteen));
+    /// ```
+    #[must_use]
+    pub fn iter(
+    ) -> impl DoubleEndedIterator<Item = Self> + ExactSizeIterator + FusedIterator
+    {
+        Self::range(Self::Zero, Self::Fifteen)
+    }
+
     /// Returns the average hexagon area, in square radians, at this
     /// resolution (excludes pentagons).
     ///
@@ -322,6 +362,66 @@ impl Resolution {
         }
     }
 
+    /// Returns the shortest edge length, in kilometers, at this resolution
+    /// (includes pentagons).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let min_edge_len = h3o::Resolution::Three.edge_length_min_km();
+    /// ```
+    #[must_use]
+    pub const fn edge_length_min_km(self) -> f64 {
+        match self {
+            Self::Zero => 1195.574513092082,
+            Self::One => 380.09950618356646,
+            Self::Two => 141.0464561827082,
+            Self::Three => 49.22497144002955,
+            Self::Four => 19.491707259861414,
+            Self::Five => 6.931265339966516,
+            Self::Six => 2.7711342351512376,
+            Self::Seven => 0.9881322427433472,
+            Self::Eight => 0.3956030848681321,
+            Self::Nine => 0.14111997271777196,
+            Self::Ten => 0.056509150624102986,
+            Self::Eleven => 0.020159143612352697,
+            Self::Twelve => 0.008072622009790502,
+            Self::Thirteen => 0.0028798602593012815,
+            Self::Fourteen => 0.0011532293883865796,
+            Self::Fifteen => 0.00041140825069915023,
+        }
+    }
+
+    /// Returns the longest edge length, in kilometers, at this resolution
+    /// (includes pentagons).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let max_edge_len = h3o::Resolution::Three.edge_length_max_km();
+    /// ```
+    #[must_use]
+    pub const fn edge_length_max_km(self) -> f64 {
+        match self {
+            Self::Zero => 1374.68536059302,
+            Self::One => 529.3521465796296,
+            Self::Two => 200.6211326305114,
+            Self::Three => 75.85721703989935,
+            Self::Four => 28.67293003010828,
+            Self::Five => 10.837435124899613,
+            Self::Six => 4.096170112148981,
+            Self::Seven => 1.548207029217752,
+            Self::Eight => 0.585167267484024,
+            Self::Nine => 0.22117243861085062,
+            Self::Ten => 0.08359532424424172,
+            Self::Eleven => 0.0315960626774947,
+            Self::Twelve => 0.011942189181279808,
+            Self::Thirteen => 0.004513723244134167,
+            Self::Fourteen => 0.0017060270304042422,
+            Self::Fifteen => 0.00064481761065041,
+        }
+    }
+
     /// Returns the number of unique H3 indexes at the given resolution.
     ///
     /// # Example
@@ -352,6 +452,20 @@ impl Resolution {
         }
     }
 
+    /// Returns the number of unique vertexes at the given resolution.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let nb_vertexes = h3o::Resolution::Three.vertex_count();
+    /// ```
+    #[must_use]
+    pub const fn vertex_count(self) -> u64 {
+        // Every vertex is shared by three cells, and the 12 pentagons have one
+        // vertex less than the hexagons: 3V = 6F - 12.
+        2 * self.cell_count() - 4
+    }
+
     /// Returns the number of pentagons (same at any resolution).
     ///
     /// # Example
@@ -364,6 +478,65 @@ impl Resolution {
         NUM_PENTAGONS
     }
 
+    /// Returns the total area of the cells, in square kilometers (same at any
+    /// resolution, i.e. the area of the Earth's surface).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let area = h3o::Resolution::total_area_km2();
+    /// ```
+    #[must_use]
+    pub const fn total_area_km2() -> f64 {
+        4. * PI * EARTH_RADIUS_KM * EARTH_RADIUS_KM
+    }
+
+    /// Returns the finest resolution whose cells are at least
+    /// `pixels_per_cell` pixels wide on a web map at the given zoom level.
+    ///
+    /// This is the inverse of [`Self::zoom`]: the cell width is measured at
+    /// the equator, where the web-mercator projection doesn't stretch the
+    /// features (at higher latitudes, cells are displayed bigger).
+    ///
+    /// Defaults to [`Resolution::Zero`] when even the coarsest cells are
+    /// smaller than requested.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::Resolution;
+    ///
+    /// assert_eq!(Resolution::for_zoom(10., 32.), Resolution::Six);
+    /// ```
+    #[must_use]
+    pub fn for_zoom(zoom: f64, pixels_per_cell: f64) -> Self {
+        Self::iter()
+            .rev()
+            .find(|resolution| resolution.zoom(pixels_per_cell) <= zoom)
+            .unwrap_or(Self::Zero)
+    }
+
+    /// Returns the web map zoom level at which the cells of this resolution
+    /// are `pixels_per_cell` pixels wide (measured at the equator).
+    ///
+    /// Zoom levels are fractional, and may be outside of the range supported
+    /// by web maps.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::Resolution;
+    ///
+    /// let zoom = Resolution::Six.zoom(32.);
+    /// assert_eq!(Resolution::for_zoom(zoom, 32.), Resolution::Six);
+    /// ```
+    #[must_use]
+    pub fn zoom(self, pixels_per_cell: f64) -> f64 {
+        // A hexagon is about twice as wide as its edges are long.
+        let meters_per_pixel = 2. * self.edge_length_m() / pixels_per_cell;
+        log2(2. * PI * MERCATOR_RADIUS_M / TILE_SIZE / meters_per_pixel)
+    }
+
     /// Generates all pentagons at this resolution.
     ///
     /// # Example
@@ -386,6 +559,28 @@ impl Resolution {
         })
     }
 
+    /// Generates all the vertexes at this resolution, without duplicates.
+    ///
+    /// Each vertex is yielded once, by its owner cell (cells are visited in
+    /// increasing index order), thus no deduplication is needed when building
+    /// a global mesh.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use h3o::Resolution;
+    ///
+    /// let vertexes = Resolution::One.vertexes().collect::<Vec<_>>();
+    /// assert_eq!(vertexes.len() as u64, Resolution::One.vertex_count());
+    /// ```
+    pub fn vertexes(self) -> impl Iterator<Item = VertexIndex> {
+        CellIndex::base_cells()
+            .flat_map(move |cell| cell.children(self))
+            .flat_map(|cell| {
+                cell.vertexes().filter(move |vertex| vertex.owner() == cell)
+            })
+    }
+
     /// Initializes a new `Resolution` using a value that may be out of range.
     ///
     /// # Safety
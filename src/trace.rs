@@ -0,0 +1,152 @@
+//! Instrumentation of the expensive code paths.
+//!
+//! When the `tracing` feature is enabled, those paths are wrapped into spans
+//! recording what has been processed (e.g. number of cells emitted), otherwise
+//! every helper here is a no-op.
+
+use crate::CellIndex;
+#[cfg(feature = "geo")]
+use crate::Resolution;
+#[cfg(feature = "geo")]
+use geo::MultiPolygon;
+
+/// An iterator over cells, counting the emitted cells.
+///
+/// The count is recorded in the `cells` field of its span once the iterator is
+/// dropped.
+pub struct Instrumented<I> {
+    inner: I,
+    #[cfg(feature = "tracing")]
+    span: tracing::Span,
+    #[cfg(feature = "tracing")]
+    count: u64,
+}
+
+impl<I> Instrumented<I> {
+    #[cfg(feature = "tracing")]
+    const fn new(inner: I, span: tracing::Span) -> Self {
+        Self {
+            inner,
+            span,
+            count: 0,
+        }
+    }
+
+    #[cfg(not(feature = "tracing"))]
+    const fn new(inner: I) -> Self {
+        Self { inner }
+    }
+}
+
+impl<I> Iterator for Instrumented<I>
+where
+    I: Iterator<Item = CellIndex>,
+{
+    type Item = CellIndex;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        #[cfg(feature = "tracing")]
+        let _guard = self.span.enter();
+        let item = self.inner.next();
+        #[cfg(feature = "tracing")]
+        if item.is_some() {
+            self.count += 1;
+        }
+        item
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+#[cfg(feature = "tracing")]
+impl<I> Drop for Instrumented<I> {
+    fn drop(&mut self) {
+        self.span.record("cells", self.count);
+    }
+}
+
+// -----------------------------------------------------------------------------
+
+/// Instruments the computation of the cells covering a polygon, made of the
+/// given number of rings.
+///
+/// `f` performs the eager part of the computation, and returns an iterator
+/// over the covering cells.
+#[cfg(feature = "geo")]
+#[cfg_attr(not(feature = "tracing"), allow(unused_variables))] // Span fields.
+pub fn polyfill<I>(
+    rings: usize,
+    resolution: Resolution,
+    f: impl FnOnce() -> I,
+) -> Instrumented<I> {
+    #[cfg(feature = "tracing")]
+    {
+        let span = tracing::debug_span!(
+            "polyfill",
+            resolution = u8::from(resolution),
+            rings,
+            cells = tracing::field::Empty,
+        );
+        let inner = span.in_scope(f);
+        Instrumented::new(inner, span)
+    }
+    #[cfg(not(feature = "tracing"))]
+    {
+        Instrumented::new(f())
+    }
+}
+
+/// Instruments the compaction of a set of cells.
+///
+/// `f` performs the eager part of the computation, and returns an iterator
+/// over the compacted cells.
+pub fn compaction<I, E>(
+    f: impl FnOnce() -> Result<I, E>,
+) -> Result<Instrumented<I>, E> {
+    #[cfg(feature = "tracing")]
+    {
+        let span =
+            tracing::debug_span!("compaction", cells = tracing::field::Empty);
+        let inner = span.in_scope(f)?;
+        Ok(Instrumented::new(inner, span))
+    }
+    #[cfg(not(feature = "tracing"))]
+    {
+        f().map(Instrumented::new)
+    }
+}
+
+/// Instruments the assembly of the outline of a set of cells.
+///
+/// `f` is given the (counted) input cells, and returns their outline.
+#[cfg(feature = "geo")]
+pub fn outline<C, E>(
+    cells: C,
+    f: impl FnOnce(Instrumented<C::IntoIter>) -> Result<MultiPolygon<f64>, E>,
+) -> Result<MultiPolygon<f64>, E>
+where
+    C: IntoIterator<Item = CellIndex>,
+{
+    #[cfg(feature = "tracing")]
+    {
+        let span = tracing::debug_span!(
+            "outline",
+            cells = tracing::field::Empty,
+            rings = tracing::field::Empty,
+        );
+        let cells = Instrumented::new(cells.into_iter(), span.clone());
+        let outline = span.in_scope(|| f(cells))?;
+        let rings = outline
+            .iter()
+            .map(|polygon| 1 + polygon.interiors().len())
+            .sum::<usize>();
+        span.record("rings", rings);
+        Ok(outline)
+    }
+    #[cfg(not(feature = "tracing"))]
+    {
+        f(Instrumented::new(cells.into_iter()))
+    }
+}
@@ -0,0 +1,66 @@
+use h3o::{CellIndex, Resolution};
+
+#[test]
+fn children_array() {
+    let hexagon = CellIndex::try_from(0x8a1fb46622dffff).expect("hexagon");
+    let pentagon = CellIndex::try_from(0x851c0003fffffff).expect("pentagon");
+    let finest = CellIndex::try_from(0x8f734e64992d6d8).expect("finest");
+
+    for index in [hexagon, pentagon, finest] {
+        let expected = index
+            .resolution()
+            .succ()
+            .map(|resolution| index.children(resolution).collect())
+            .unwrap_or_else(Vec::new);
+
+        assert_eq!(index.children_array().as_slice(), expected, "{index}");
+    }
+}
+
+#[test]
+fn edges_array() {
+    let hexagon = CellIndex::try_from(0x8a1fb46622dffff).expect("hexagon");
+    let pentagon = CellIndex::try_from(0x851c0003fffffff).expect("pentagon");
+
+    for index in [hexagon, pentagon] {
+        let expected = index.edges().collect::<Vec<_>>();
+
+        assert_eq!(index.edges_array().as_slice(), expected, "{index}");
+    }
+}
+
+#[test]
+fn vertexes_array() {
+    let hexagon = CellIndex::try_from(0x8a1fb46622dffff).expect("hexagon");
+    let pentagon = CellIndex::try_from(0x851c0003fffffff).expect("pentagon");
+
+    for index in [hexagon, pentagon] {
+        let expected = index.vertexes().collect::<Vec<_>>();
+
+        assert_eq!(index.vertexes_array().as_slice(), expected, "{index}");
+    }
+}
+
+#[test]
+fn grid_disk_array() {
+    let hexagon = CellIndex::try_from(0x8a1fb46622dffff).expect("hexagon");
+    let pentagon = CellIndex::try_from(0x851c0003fffffff).expect("pentagon");
+
+    for index in [hexagon, pentagon] {
+        for k in 0..=4 {
+            let expected = index.grid_disk::<Vec<_>>(k);
+            let result = index.grid_disk_array::<61>(k).expect("grid disk");
+
+            assert_eq!(result.as_slice(), expected, "{index}, k={k}");
+        }
+    }
+}
+
+#[test]
+fn grid_disk_array_too_small() {
+    let index = CellIndex::first(Resolution::Five);
+
+    assert!(index.grid_disk_array::<7>(1).is_some());
+    assert!(index.grid_disk_array::<6>(1).is_none());
+    assert!(index.grid_disk_array::<1>(0).is_some());
+}
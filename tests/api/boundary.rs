@@ -1,4 +1,4 @@
-use h3o::DirectedEdgeIndex;
+use h3o::{BoundaryBuffer, CellIndex, DirectedEdgeIndex};
 
 #[test]
 fn display() {
@@ -10,3 +10,41 @@ fn display() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn buffer() {
+    let pentagon = CellIndex::try_from(0x851c0003fffffff).expect("pentagon");
+    let cells = pentagon.grid_disk::<Vec<_>>(1);
+    let result = cells.iter().copied().collect::<BoundaryBuffer>();
+
+    assert_eq!(result.len(), cells.len());
+    assert_eq!(result.offsets().len(), cells.len() + 1);
+    assert_eq!(result.offsets().last(), Some(&result.points().len()));
+    for (boundary, cell) in result.iter().zip(&cells) {
+        assert_eq!(boundary, &*cell.boundary(), "{cell}");
+    }
+    assert_eq!(result.get(cells.len()), None);
+}
+
+#[test]
+fn buffer_clear() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let mut buffer = BoundaryBuffer::with_capacity(1);
+    buffer.push(index);
+    buffer.clear();
+
+    assert!(buffer.is_empty());
+    assert_eq!(buffer, BoundaryBuffer::new());
+}
+
+#[test]
+fn buffer_edges() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let edges = index.edges().collect::<Vec<_>>();
+    let result = edges.iter().copied().collect::<BoundaryBuffer>();
+
+    assert_eq!(result.len(), edges.len());
+    for (boundary, edge) in result.iter().zip(&edges) {
+        assert_eq!(boundary, &*edge.boundary(), "{edge}");
+    }
+}
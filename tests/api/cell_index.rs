@@ -1,4 +1,8 @@
-use h3o::{error, CellIndex, Resolution};
+use float_eq::assert_float_eq;
+use h3o::{
+    error, CellIndex, DirectedEdgeIndex, GridDistance, LatLng, Resolution,
+};
+use std::{f64::consts::TAU, num::NonZeroU32};
 
 #[test]
 fn is_neighbor_with() {
@@ -6,10 +10,10 @@ fn is_neighbor_with() {
     let dst = CellIndex::try_from(0x8a1fb46622d7fff).expect("dst");
     assert_eq!(src.is_neighbor_with(dst), Ok(true));
 
-    assert_eq!(
+    assert!(matches!(
         src.is_neighbor_with(dst.parent(Resolution::Six).expect("parent")),
-        Err(error::ResolutionMismatch)
-    );
+        Err(error::ResolutionMismatch { .. })
+    ));
 
     let dst = CellIndex::try_from(0x8a1fb4644937fff).expect("dst2");
     assert_eq!(src.is_neighbor_with(dst), Ok(false));
@@ -69,6 +73,25 @@ fn display() {
     assert_eq!(result, expected, "binary");
 }
 
+#[test]
+fn to_hex_array() {
+    for value in [0x8a1fb46622dffff, 0x8001fffffffffff, 0x8ff29380e0d0cc4] {
+        let index = CellIndex::try_from(value).expect("index");
+        let result = index.to_hex_array();
+
+        assert_eq!(result.as_slice(), index.to_string().as_bytes(), "{index}");
+    }
+}
+
+#[test]
+fn write_to() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let mut result = String::from("id=");
+    index.write_to(&mut result).expect("write to string");
+
+    assert_eq!(result, "id=8a1fb46622dffff");
+}
+
 #[test]
 fn child_position() {
     let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
@@ -181,3 +204,530 @@ fn last() {
         );
     }
 }
+
+#[test]
+fn boundary_distance() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let boundary = index.boundary();
+    let center = LatLng::from(index);
+
+    // From the center, the closest edge is less than an edge length away.
+    let distance = index.boundary_distance_m(center);
+    assert!(distance > 0.);
+    assert!(distance < index.resolution().edge_length_m());
+
+    // Vertices are on the boundary.
+    for vertex in boundary.iter() {
+        assert_float_eq!(
+            index.boundary_distance_rads(*vertex),
+            0.,
+            abs <= 1e-12
+        );
+    }
+
+    // Distance is consistent across units.
+    assert_float_eq!(
+        index.boundary_distance_km(center) * 1000.,
+        distance,
+        r2nd <= 1e-12
+    );
+
+    // Works for coordinates outside the cell too.
+    let outside =
+        LatLng::from(CellIndex::try_from(0x8a1fb4644937fff).expect("far away"));
+    assert!(index.boundary_distance_m(outside) > distance);
+}
+
+#[test]
+fn contains_latlng() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let neighbor = CellIndex::try_from(0x8a1fb46622d7fff).expect("neighbor");
+
+    assert!(index.contains_latlng(LatLng::from(index)));
+    assert!(!index.contains_latlng(LatLng::from(neighbor)));
+
+    // The center child's center is within the parent.
+    let parent = index.parent(Resolution::Nine).expect("parent");
+    let child = parent.center_child(Resolution::Twelve).expect("child");
+    assert!(parent.contains_latlng(LatLng::from(child)));
+}
+
+#[test]
+fn overlaps() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let neighbor = CellIndex::try_from(0x8a1fb46622d7fff).expect("neighbor");
+    let parent = index.parent(Resolution::Five).expect("parent");
+    let child = index.center_child(Resolution::Twelve).expect("child");
+
+    assert!(index.overlaps(index));
+    assert!(index.overlaps(parent));
+    assert!(parent.overlaps(index));
+    assert!(index.overlaps(child));
+    assert!(parent.overlaps(child));
+    assert!(!index.overlaps(neighbor));
+    assert!(!neighbor.overlaps(child));
+}
+
+#[cfg(feature = "geo")]
+#[test]
+fn intersects_boundary() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let neighbor = CellIndex::try_from(0x8a1fb46622d7fff).expect("neighbor");
+    let far = CellIndex::try_from(0x8a1fb4644937fff).expect("far away");
+
+    assert!(index.intersects_boundary(neighbor));
+    assert!(index.intersects_boundary(
+        index.center_child(Resolution::Eleven).expect("child")
+    ));
+    assert!(!index.intersects_boundary(far));
+
+    // Some children of the neighbor overhang into the cell.
+    assert!(neighbor.children(Resolution::Eleven).any(|child| !index
+        .overlaps(child)
+        && index.intersects_boundary(child)));
+    // But not the center one.
+    let center = neighbor.center_child(Resolution::Eleven).expect("center");
+    assert!(!index.intersects_boundary(center));
+}
+
+#[cfg(feature = "geo")]
+#[test]
+fn intersects_boundary_polar() {
+    let north = LatLng::new(90., 0.).expect("north pole");
+    let south = LatLng::new(-90., 0.).expect("south pole");
+    let polar = north.to_cell(Resolution::Three);
+
+    for neighbor in polar.grid_disk::<Vec<_>>(1) {
+        assert!(polar.intersects_boundary(neighbor));
+        assert!(neighbor.intersects_boundary(polar));
+    }
+    let polar = south.to_cell(Resolution::Three);
+    for neighbor in polar.grid_disk::<Vec<_>>(1) {
+        assert!(polar.intersects_boundary(neighbor));
+    }
+    let polar = north.to_cell(Resolution::Three);
+
+    let far = LatLng::new(80., 0.)
+        .expect("far")
+        .to_cell(Resolution::Three);
+    assert!(!polar.intersects_boundary(far));
+    assert!(!far.intersects_boundary(polar));
+
+    assert!(!polar.intersects_boundary(south.to_cell(Resolution::Three)));
+}
+
+#[test]
+fn grid_distance_approx() {
+    let src = CellIndex::try_from(0x8a1fb46622dffff).expect("src");
+    let dst = CellIndex::try_from(0x8a1fb4644937fff).expect("dst");
+    let exact = src.grid_distance(dst).expect("exact distance");
+    assert_eq!(
+        src.grid_distance_approx(dst),
+        Ok(GridDistance::Exact(exact))
+    );
+
+    // Too far away for an exact distance.
+    let far = LatLng::new(-33.8688, 151.2093)
+        .expect("coord")
+        .to_cell(Resolution::Ten);
+    assert!(src.grid_distance(far).is_err());
+    let result = src.grid_distance_approx(far).expect("estimate");
+    assert!(!result.is_exact());
+    // ~17000km away with at most ~145m between cell centers.
+    let value = result.value();
+    assert!((110_000..125_000).contains(&value), "{value}");
+
+    // Resolution must match.
+    let parent = src.parent(Resolution::Nine).expect("parent");
+    assert!(src.grid_distance_approx(parent).is_err());
+}
+
+#[test]
+fn grid_distance_approx_lower_bound() {
+    // Cells around a pentagon are smaller than average.
+    let pentagon = CellIndex::try_from(0x8009fffffffffff)
+        .expect("pentagon")
+        .center_child(Resolution::One)
+        .expect("center child");
+
+    let mut estimated = 0;
+    for (src, _) in pentagon.grid_disk_distances_safe(3) {
+        for (dst, exact) in src.grid_disk_distances_safe(10) {
+            if let Ok(GridDistance::Approximate(estimate)) =
+                src.grid_distance_approx(dst)
+            {
+                assert!(
+                    u32::try_from(estimate).expect("positive") <= exact,
+                    "{src} -> {dst}: {estimate} > {exact}"
+                );
+                estimated += 1;
+            }
+        }
+    }
+    assert!(estimated > 0, "no estimate checked");
+}
+
+#[test]
+fn grid_disk_checked() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let (cells, distortion) = index.grid_disk_checked::<Vec<_>>(2);
+    assert_eq!(distortion, None);
+    assert_eq!(cells, index.grid_disk::<Vec<_>>(2));
+
+    // Pentagon origin.
+    let pentagon = CellIndex::try_from(0x8009fffffffffff).expect("pentagon");
+    let (cells, distortion) = pentagon.grid_disk_checked::<Vec<_>>(1);
+    assert_eq!(distortion, Some(0));
+    assert_eq!(cells.len(), 6);
+
+    // Pentagon in the first ring.
+    let neighbor = pentagon.grid_disk_safe(1).find(|&cell| cell != pentagon);
+    let neighbor = neighbor.expect("neighbor");
+    let (mut cells, distortion) = neighbor.grid_disk_checked::<Vec<_>>(2);
+    let mut expected = neighbor.grid_disk_safe(2).collect::<Vec<_>>();
+    cells.sort_unstable();
+    expected.sort_unstable();
+    assert_eq!(distortion, Some(1));
+    assert_eq!(cells, expected);
+}
+
+/// Filters the whole disk by bearing, as a reference for `grid_sector`.
+fn grid_sector_reference(
+    origin: CellIndex,
+    k: u32,
+    min: f64,
+    max: f64,
+) -> Vec<CellIndex> {
+    let from = LatLng::from(origin);
+    let (min, max) = (min.to_radians(), max.to_radians());
+    let width = if max - min >= TAU {
+        TAU
+    } else {
+        (max - min).rem_euclid(TAU)
+    };
+    let mut cells = origin
+        .grid_disk_safe(k)
+        .filter(|&cell| {
+            let to = LatLng::from(cell);
+            let dlng = to.lng_radians() - from.lng_radians();
+            let azimuth = f64::atan2(
+                to.lat_radians().cos() * dlng.sin(),
+                from.lat_radians().cos() * to.lat_radians().sin()
+                    - from.lat_radians().sin()
+                        * to.lat_radians().cos()
+                        * dlng.cos(),
+            );
+            cell == origin || (azimuth - min).rem_euclid(TAU) <= width
+        })
+        .collect::<Vec<_>>();
+    cells.sort_unstable();
+    cells
+}
+
+#[test]
+fn grid_sector() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let pentagon = CellIndex::try_from(0x8009fffffffffff).expect("pentagon");
+    let coarse = CellIndex::try_from(0x81083ffffffffff).expect("coarse");
+
+    for (origin, k) in [(index, 10), (index, 30), (pentagon, 3), (coarse, 4)] {
+        for (min, max) in [
+            (60., 120.),
+            (350., 10.),
+            (-10., 10.),
+            (45., 46.),
+            (180., 359.),
+            (0., 360.),
+        ] {
+            let mut cells = origin.grid_sector::<Vec<_>>(k, min, max);
+            cells.sort_unstable();
+            assert_eq!(
+                cells,
+                grid_sector_reference(origin, k, min, max),
+                "{origin} k={k} [{min}; {max}]"
+            );
+        }
+    }
+}
+
+#[test]
+fn grid_sector_random() {
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    let mut next = move || {
+        // xorshift64
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    };
+
+    // Cells within distance k may only be reachable through cells outside of
+    // the sector.
+    let origin = CellIndex::try_from(0x86080545fffffff).expect("origin");
+    let cells =
+        origin.grid_sector::<Vec<_>>(7, 71.39157675806354, 250.86823069140792);
+    assert!(
+        cells.contains(&CellIndex::try_from(0x86080425fffffff).expect("cell"))
+    );
+
+    for _ in 0..1000 {
+        let lat = (next() % 180_001) as f64 / 1000. - 90.;
+        let lng = (next() % 360_000) as f64 / 1000. - 180.;
+        let resolution =
+            Resolution::try_from((next() % 11) as u8).expect("resolution");
+        let origin = LatLng::new(lat, lng).expect("ll").to_cell(resolution);
+        let k = (next() % 9) as u32;
+        let min = (next() % 360_000) as f64 / 1000.;
+        let max = min + (next() % 360_000) as f64 / 1000.;
+
+        let mut cells = origin.grid_sector::<Vec<_>>(k, min, max);
+        cells.sort_unstable();
+        assert_eq!(
+            cells,
+            grid_sector_reference(origin, k, min, max),
+            "{origin} k={k} [{min}; {max}]"
+        );
+    }
+}
+
+#[test]
+fn grid_sector_origin() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+
+    assert_eq!(index.grid_sector::<Vec<_>>(0, 0., 90.), vec![index]);
+    assert_eq!(index.grid_sector::<Vec<_>>(5, 0., 0.)[0], index);
+}
+
+#[test]
+fn interpolate() {
+    let src = LatLng::new(48.864716, 2.349014)
+        .expect("src")
+        .to_cell(Resolution::Seven);
+    let dst = LatLng::new(41.902782, 12.496366)
+        .expect("dst")
+        .to_cell(Resolution::Seven);
+
+    assert_eq!(src.interpolate(dst, 0.), Ok(src));
+    assert_eq!(src.interpolate(dst, 1.), Ok(dst));
+    assert_eq!(src.interpolate(dst, -1.), Ok(src), "clamped low");
+    assert_eq!(src.interpolate(dst, 2.), Ok(dst), "clamped high");
+    assert_eq!(src.interpolate(dst, f64::NAN), Ok(src), "NaN");
+    assert_eq!(src.interpolate(src, 0.5), Ok(src), "same cell");
+
+    // Steps move steadily away from the source.
+    let distances = (0..=20)
+        .map(|i| {
+            let cell = src.interpolate(dst, f64::from(i) / 20.).expect("cell");
+            LatLng::from(src).distance_km(LatLng::from(cell))
+        })
+        .collect::<Vec<_>>();
+    assert!(distances.windows(2).all(|w| w[0] < w[1]));
+}
+
+#[test]
+fn interpolate_resolution_mismatch() {
+    let src = CellIndex::try_from(0x8a1fb46622dffff).expect("src");
+    let dst = src.parent(Resolution::Nine).expect("parent");
+
+    assert!(src.interpolate(dst, 0.5).is_err());
+    assert!(src.midpoint(dst).is_err());
+}
+
+#[test]
+fn midpoint() {
+    let src = LatLng::new(48.864716, 2.349014)
+        .expect("src")
+        .to_cell(Resolution::Seven);
+    let dst = LatLng::new(41.902782, 12.496366)
+        .expect("dst")
+        .to_cell(Resolution::Seven);
+
+    let midpoint = LatLng::from(src.midpoint(dst).expect("midpoint"));
+    let from_src = LatLng::from(src).distance_km(midpoint);
+    let from_dst = LatLng::from(dst).distance_km(midpoint);
+    // Equidistant, up to the size of a cell.
+    assert!(
+        (from_src - from_dst).abs() < 2. * Resolution::Seven.edge_length_km()
+    );
+    assert_eq!(src.midpoint(dst), src.interpolate(dst, 0.5));
+}
+
+#[test]
+fn edge_length_stats() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let stats = index.edge_length_stats();
+    let lengths = index
+        .edges()
+        .map(DirectedEdgeIndex::length_rads)
+        .collect::<Vec<_>>();
+
+    assert_eq!(stats.min, lengths.iter().copied().fold(f64::MAX, f64::min));
+    assert_eq!(stats.max, lengths.iter().copied().fold(0., f64::max));
+    assert_float_eq!(
+        stats.mean,
+        lengths.iter().sum::<f64>() / 6.,
+        r2nd <= f64::EPSILON
+    );
+    assert_float_eq!(
+        stats.to_km().mean,
+        index.edges().map(DirectedEdgeIndex::length_km).sum::<f64>() / 6.,
+        r2nd <= 1e-15
+    );
+
+    // Pentagons only have 5 edges.
+    let pentagon = CellIndex::try_from(0x851c0003fffffff).expect("pentagon");
+    let stats = pentagon.edge_length_stats().to_m();
+    assert_float_eq!(
+        stats.mean,
+        pentagon
+            .edges()
+            .map(DirectedEdgeIndex::length_m)
+            .sum::<f64>()
+            / 5.,
+        r2nd <= 1e-15
+    );
+    assert!(stats.min <= stats.mean && stats.mean <= stats.max);
+}
+
+#[test]
+fn distortion() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let distortion = index.distortion();
+    assert!((1. ..1.1).contains(&distortion), "{distortion}");
+
+    // Cells around pentagons are the most distorted.
+    let pentagon = CellIndex::try_from(0x851c0003fffffff).expect("pentagon");
+    let neighbors = pentagon.grid_disk::<Vec<_>>(1);
+    let max = neighbors
+        .iter()
+        .copied()
+        .map(CellIndex::distortion)
+        .fold(0., f64::max);
+    let center = CellIndex::try_from(0x85ee0003fffffff).expect("center");
+    assert!(max > center.distortion());
+}
+
+#[test]
+fn crosses_face_edge() {
+    let pentagon = CellIndex::try_from(0x851c0003fffffff).expect("pentagon");
+    assert!(pentagon.crosses_face_edge());
+
+    let index = CellIndex::try_from(0x851c000bfffffff).expect("crossing");
+    assert!(index.crosses_face_edge());
+
+    let index = CellIndex::try_from(0x851c0073fffffff).expect("inside");
+    assert!(!index.crosses_face_edge());
+
+    // Consistent with the faces of the cell vertexes, on both classes.
+    for resolution in [Resolution::Two, Resolution::Three] {
+        for index in
+            CellIndex::base_cells().flat_map(|cell| cell.children(resolution))
+        {
+            assert_eq!(
+                index.crosses_face_edge(),
+                index.icosahedron_faces().len() > 1,
+                "{index}"
+            );
+        }
+    }
+}
+
+#[test]
+fn union_compacted() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let cells = index
+        .grid_disk::<Vec<_>>(3)
+        .into_iter()
+        .flat_map(|cell| cell.children(Resolution::Twelve))
+        .collect::<Vec<_>>();
+    // Spread the cells over several differently compacted shards.
+    let shards = (0..4)
+        .map(|shard| {
+            let cells = cells
+                .iter()
+                .copied()
+                .enumerate()
+                .filter_map(|(i, cell)| (i % 4 == shard).then_some(cell));
+            CellIndex::compact(cells)
+                .expect("compact")
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let expected = cells.iter().copied().collect::<h3o::FrozenCellSet>();
+
+    let result = CellIndex::union_compacted(shards).collect::<Vec<_>>();
+
+    assert_eq!(result, expected.iter().collect::<Vec<_>>());
+}
+
+#[test]
+fn union_compacted_overlap() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let child = index.center_child(Resolution::Twelve).expect("child");
+    let parent = index.parent(Resolution::Nine).expect("parent");
+    let siblings = parent.children(Resolution::Ten).collect::<Vec<_>>();
+
+    let result = CellIndex::union_compacted([
+        vec![child],
+        vec![index],
+        siblings[..3].to_vec(),
+        siblings[3..].to_vec(),
+        Vec::new(),
+    ])
+    .collect::<Vec<_>>();
+
+    assert_eq!(result, vec![parent]);
+    assert_eq!(
+        CellIndex::union_compacted(Vec::<Vec<CellIndex>>::new()).count(),
+        0
+    );
+}
+
+#[test]
+fn union_compacted_pentagon() {
+    let pentagon = CellIndex::try_from(0x8009fffffffffff).expect("pentagon");
+    let children = pentagon.children(Resolution::One).collect::<Vec<_>>();
+    let streams = children.iter().map(|&cell| [cell]);
+
+    let result = CellIndex::union_compacted(streams).collect::<Vec<_>>();
+
+    assert_eq!(result, vec![pentagon]);
+}
+
+#[test]
+fn shard() {
+    let count = NonZeroU32::new(10).expect("non-zero");
+    let mut loads = [0; 10];
+    for cell in CellIndex::base_cells()
+        .flat_map(|cell| cell.children(Resolution::Three).collect::<Vec<_>>())
+    {
+        let shard = cell.shard(count);
+        loads[usize::try_from(shard).expect("shard index")] += 1;
+
+        let child = cell.center_child(Resolution::Ten).expect("child");
+        assert_eq!(child.shard(count), shard, "{cell} and {child}");
+    }
+
+    // 41,162 cells: about 4,116 cells per shard.
+    for load in loads {
+        assert!((3900..4350).contains(&load), "unbalanced: {loads:?}");
+    }
+}
+
+#[test]
+fn shard_stability() {
+    let count = NonZeroU32::new(1000).expect("non-zero");
+    let cell = CellIndex::try_from(0x8a1fb46622dffff).expect("cell");
+
+    assert_eq!(cell.shard(count), 585);
+    assert_eq!(cell.shard_at(Resolution::Zero, count), 217);
+    assert_eq!(cell.shard_at(Resolution::Fifteen, count), 908);
+}
+
+#[test]
+fn shard_one() {
+    let count = NonZeroU32::new(1).expect("non-zero");
+    let cell = CellIndex::try_from(0x8a1fb46622dffff).expect("cell");
+
+    assert_eq!(cell.shard(count), 0);
+}
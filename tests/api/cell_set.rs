@@ -0,0 +1,218 @@
+use h3o::{CellIndex, CellSet, EdgeKind, LatLng, Resolution};
+
+#[test]
+fn insert_remove() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let mut set = CellSet::new();
+
+    assert!(set.is_empty());
+    assert!(set.insert(index));
+    assert!(!set.insert(index));
+    assert!(set.contains(index));
+    assert_eq!(set.len(), 1);
+    assert!(set.remove(index));
+    assert!(!set.contains(index));
+    assert!(set.is_empty());
+}
+
+#[test]
+fn collect() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let mut set = index.grid_disk::<CellSet>(1);
+    set.extend(index.grid_disk::<Vec<_>>(2));
+
+    assert_eq!(set.len(), 19);
+    let mut cells = set.into_iter().collect::<Vec<_>>();
+    let mut expected = index.grid_disk::<Vec<_>>(2);
+    cells.sort_unstable();
+    expected.sort_unstable();
+    assert_eq!(cells, expected);
+}
+
+#[test]
+fn edges() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let set = index.grid_disk::<CellSet>(1);
+    let (internal, external): (Vec<_>, Vec<_>) = set
+        .edges()
+        .partition(|(_, kind)| *kind == EdgeKind::Internal);
+
+    // 12 internal edges, in both directions.
+    assert_eq!(internal.len(), 24);
+    assert_eq!(external.len(), 18);
+    assert!(internal
+        .iter()
+        .all(|(edge, _)| set.contains(edge.destination())));
+    assert!(external
+        .iter()
+        .all(|(edge, _)| !set.contains(edge.destination())));
+}
+
+#[test]
+fn edges_pentagon() {
+    let pentagon = CellIndex::try_from(0x8009fffffffffff).expect("pentagon");
+    let set = std::iter::once(pentagon).collect::<CellSet>();
+
+    assert!(set.edges().all(|(_, kind)| kind == EdgeKind::External));
+    assert_eq!(set.edges().count(), 5);
+}
+
+#[test]
+fn to_local_ij() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let set = index.grid_disk::<CellSet>(2);
+    let batch = set.to_local_ij().expect("batch");
+
+    assert_eq!(batch.anchor(), index);
+    assert_eq!(batch.coords().len(), set.len());
+    assert!(batch.failures().is_empty());
+    for &(cell, coord) in batch.coords() {
+        assert_eq!(coord.anchor(), index);
+        assert_eq!(CellIndex::try_from(coord).expect("cell"), cell);
+    }
+}
+
+#[test]
+fn to_local_ij_failures() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let antipode = LatLng::new(-37.8, -57.6)
+        .expect("coord")
+        .to_cell(Resolution::Ten);
+    let mut set = index.grid_disk::<CellSet>(1);
+    set.insert(antipode);
+    let batch = set.to_local_ij().expect("batch");
+
+    assert_eq!(batch.coords().len(), 7);
+    assert_eq!(batch.failures().len(), 1);
+    assert_eq!(batch.failures()[0].0, antipode);
+}
+
+#[test]
+fn to_local_ij_empty() {
+    assert!(CellSet::new().to_local_ij().is_none());
+}
+
+#[test]
+fn contains_cell() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let compacted = CellIndex::compact(
+        index
+            .grid_disk::<Vec<_>>(1)
+            .into_iter()
+            .flat_map(|cell| cell.children(Resolution::Twelve)),
+    )
+    .expect("compacted")
+    .collect::<Vec<_>>();
+    let set = compacted.iter().copied().collect::<CellSet>();
+    let uncompacted =
+        CellIndex::uncompact(compacted.iter().copied(), Resolution::Twelve)
+            .collect::<CellSet>();
+
+    assert!(set.contains_cell(index));
+    let parent = index.parent(Resolution::Nine).expect("parent");
+    assert!(!set.contains_cell(parent));
+    for cell in index.grid_disk::<Vec<_>>(2) {
+        for child in cell.children(Resolution::Twelve) {
+            assert_eq!(
+                set.contains_cell(child),
+                uncompacted.contains(child),
+                "{child}"
+            );
+        }
+    }
+}
+
+#[test]
+fn contains_latlng() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let set = CellIndex::compact(index.children(Resolution::Thirteen))
+        .expect("compacted")
+        .collect::<CellSet>();
+
+    assert!(set.contains_latlng(LatLng::from(index)));
+    for child in index.children(Resolution::Fifteen).step_by(7) {
+        assert!(set.contains_latlng(LatLng::from(child)), "{child}");
+    }
+    for neighbor in index.grid_ring_fast(2).flatten() {
+        assert!(!set.contains_latlng(LatLng::from(neighbor)), "{neighbor}");
+    }
+}
+
+fn uncompacted(cells: &[CellIndex], resolution: Resolution) -> Vec<CellIndex> {
+    let mut cells = CellIndex::uncompact(cells.iter().copied(), resolution)
+        .collect::<Vec<_>>();
+    cells.sort_unstable();
+    cells
+}
+
+#[test]
+fn diff_same_coverage() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let old = index.grid_disk::<CellSet>(1);
+    let new = index
+        .grid_disk::<Vec<_>>(1)
+        .into_iter()
+        .flat_map(|cell| cell.children(Resolution::Twelve))
+        .collect::<CellSet>();
+
+    let diff = old.diff(&new);
+
+    assert!(diff.is_empty());
+    assert!(diff.added().is_empty());
+    assert!(diff.removed().is_empty());
+    let mut expected = index.grid_disk::<Vec<_>>(1);
+    expected.sort_unstable();
+    assert_eq!(diff.unchanged(), expected);
+}
+
+#[test]
+fn diff() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let old = index.grid_disk::<CellSet>(1);
+    // Shifted disk, at a finer resolution, with a hole.
+    let neighbor = index.grid_ring_fast(1).next().flatten().expect("neighbor");
+    let hole = neighbor.center_child(Resolution::Twelve).expect("hole");
+    let new = neighbor
+        .grid_disk::<Vec<_>>(1)
+        .into_iter()
+        .flat_map(|cell| cell.children(Resolution::Twelve))
+        .filter(|cell| *cell != hole)
+        .collect::<CellSet>();
+
+    let diff = old.diff(&new);
+    assert!(!diff.is_empty());
+
+    let resolution = Resolution::Twelve;
+    let old_cells = uncompacted(&old.iter().collect::<Vec<_>>(), resolution);
+    let new_cells = uncompacted(&new.iter().collect::<Vec<_>>(), resolution);
+    let added = uncompacted(diff.added(), resolution);
+    let removed = uncompacted(diff.removed(), resolution);
+    let unchanged = uncompacted(diff.unchanged(), resolution);
+    assert_eq!(
+        added,
+        new_cells
+            .iter()
+            .copied()
+            .filter(|cell| old_cells.binary_search(cell).is_err())
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(
+        removed,
+        old_cells
+            .iter()
+            .copied()
+            .filter(|cell| new_cells.binary_search(cell).is_err())
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(
+        unchanged,
+        old_cells
+            .iter()
+            .copied()
+            .filter(|cell| new_cells.binary_search(cell).is_ok())
+            .collect::<Vec<_>>()
+    );
+    // Deltas are compacted.
+    assert!(diff.removed().contains(&hole));
+    assert!(diff.added().len() < added.len());
+}
@@ -1,4 +1,5 @@
-use h3o::DirectedEdgeIndex;
+use float_eq::assert_float_eq;
+use h3o::{CellIndex, DirectedEdgeIndex, Resolution};
 
 #[test]
 fn try_from_str() {
@@ -37,3 +38,56 @@ fn display() {
             .to_owned();
     assert_eq!(result, expected, "binary");
 }
+
+#[test]
+fn reversed() {
+    let index = DirectedEdgeIndex::try_from(0x13a194e699ab7fff).expect("index");
+    let reversed = index.reversed();
+
+    assert_eq!(reversed.cells(), (index.destination(), index.origin()));
+    assert_eq!(reversed.reversed(), index);
+
+    // Works for pentagons as well.
+    let pentagon = CellIndex::try_from(0x8009fffffffffff).expect("pentagon");
+    for edge in pentagon.edges() {
+        assert_eq!(edge.reversed().destination(), pentagon);
+        assert_eq!(edge.reversed().reversed(), edge);
+    }
+}
+
+#[test]
+fn boundary_densified() {
+    let index = DirectedEdgeIndex::try_from(0x13a194e699ab7fff).expect("index");
+    let boundary = index.boundary();
+    let points = index.boundary_densified(5);
+
+    assert_eq!(points.len(), 5);
+    assert_eq!(points[0], boundary[0]);
+    assert_eq!(points[4], boundary[boundary.len() - 1]);
+    // Points are evenly spaced.
+    let step = index.length_rads() / 4.;
+    for pair in points.windows(2) {
+        assert_float_eq!(pair[0].distance_rads(pair[1]), step, abs <= 1e-12);
+    }
+
+    // At least the vertices are returned.
+    assert_eq!(index.boundary_densified(0).len(), 2);
+}
+
+#[test]
+fn boundary_densified_distorted() {
+    // Edge crossing an icosahedron edge, with a distortion vertex.
+    let edge = CellIndex::base_cells()
+        .flat_map(|cell| cell.children(Resolution::One))
+        .flat_map(CellIndex::edges)
+        .find(|edge| edge.boundary().len() > 2)
+        .expect("distorted edge");
+    let points = edge.boundary_densified(100);
+    let length = points
+        .windows(2)
+        .map(|pair| pair[0].distance_rads(pair[1]))
+        .sum::<f64>();
+
+    // Slightly shorter, as the distortion vertex corner is cut.
+    assert_float_eq!(length, edge.length_rads(), r2nd <= 1e-5);
+}
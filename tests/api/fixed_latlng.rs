@@ -0,0 +1,128 @@
+use h3o::{CellIndex, FixedLatLng, LatLng, Resolution};
+
+/// Deterministic pseudo-random coordinates, in nanodegrees.
+fn coordinates(count: usize) -> impl Iterator<Item = (i64, i64)> {
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    let mut next = move || {
+        // xorshift64
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    };
+
+    (0..count).map(move |_| {
+        let lat = (next() % 180_000_000_001) as i64 - 90_000_000_000;
+        let lng = (next() % 360_000_000_000) as i64 - 180_000_000_000;
+        (lat, lng)
+    })
+}
+
+#[test]
+fn new() {
+    assert!(FixedLatLng::new(90_000_000_000, 0).is_ok());
+    assert!(FixedLatLng::new(-90_000_000_000, 0).is_ok());
+    assert!(FixedLatLng::new(90_000_000_001, 0).is_err());
+    assert!(FixedLatLng::new(i64::MIN, 0).is_err());
+}
+
+#[test]
+fn longitude_normalization() {
+    let ll = FixedLatLng::new(0, 180_000_000_000).expect("ll");
+    assert_eq!(ll.lng(), -180_000_000_000);
+
+    let ll = FixedLatLng::new(0, -190_000_000_000).expect("ll");
+    assert_eq!(ll.lng(), 170_000_000_000);
+
+    let ll = FixedLatLng::new(0, i64::MAX).expect("ll");
+    assert!((-180_000_000_000..180_000_000_000).contains(&ll.lng()));
+}
+
+#[test]
+fn to_cell_matches_float() {
+    for (lat, lng) in coordinates(5_000) {
+        let fixed = FixedLatLng::new(lat, lng).expect("fixed");
+        let float =
+            LatLng::new(lat as f64 / 1e9, lng as f64 / 1e9).expect("ll");
+        for resolution in
+            Resolution::range(Resolution::Zero, Resolution::Fifteen)
+        {
+            assert_eq!(
+                fixed.to_cell(resolution),
+                float.to_cell(resolution),
+                "{lat} {lng} at {resolution:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn from_cell_matches_float() {
+    for (lat, lng) in coordinates(2_000) {
+        let ll = LatLng::new(lat as f64 / 1e9, lng as f64 / 1e9).expect("ll");
+        for resolution in
+            Resolution::range(Resolution::Zero, Resolution::Fifteen)
+        {
+            let cell = ll.to_cell(resolution);
+            let center = FixedLatLng::from(cell);
+            let expected = LatLng::from(cell);
+
+            let lat_delta = center.lat() as f64 / 1e9 - expected.lat();
+            let lng_delta = center.lng() as f64 / 1e9 - expected.lng();
+            let lng_delta = (lng_delta + 180.).rem_euclid(360.) - 180.;
+            assert!(lat_delta.abs() < 1e-9, "{cell}: {lat_delta}");
+            assert!(
+                lng_delta.abs() < 1e-9 || expected.lat().abs() > 89.9,
+                "{cell}: {lng_delta}"
+            );
+
+            assert_eq!(center.to_cell(resolution), cell, "round-trip {cell}");
+        }
+    }
+}
+// Those values must be identical on every platform.
+#[test]
+fn reference_values() {
+    let cases = [
+        (
+            (48_864_716_000, 2_349_014_000),
+            Resolution::Nine,
+            0x891f_b466_2d7f_fff,
+            (48_864_942_070, 2_349_328_471),
+        ),
+        (
+            (-18_102_722_814, -158_886_722_592),
+            Resolution::Twelve,
+            0x8cb4_dd88_36e3_7ff,
+            (-18_102_810_393, -158_886_700_717),
+        ),
+        (
+            (-77_024_903_243, -76_848_532_426),
+            Resolution::Twelve,
+            0x8cef_604a_22de_5ff,
+            (-77_024_898_510, -76_848_475_493),
+        ),
+        (
+            (62_585_076_424, -10_849_550_143),
+            Resolution::Twelve,
+            0x8c19_3440_d6b2_1ff,
+            (62_585_040_725, -10_849_606_643),
+        ),
+    ];
+
+    for ((lat, lng), resolution, cell, center) in cases {
+        let cell = CellIndex::try_from(cell).expect("cell");
+        let ll = FixedLatLng::new(lat, lng).expect("ll");
+        assert_eq!(ll.to_cell(resolution), cell);
+
+        let result = FixedLatLng::from(cell);
+        assert_eq!((result.lat(), result.lng()), center, "{cell}");
+    }
+
+    let cell = CellIndex::try_from(0x8009_ffff_ffff_fff).expect("cell");
+    let result = FixedLatLng::from(cell);
+    assert_eq!(
+        (result.lat(), result.lng()),
+        (64_700_000_128, 10_536_199_075)
+    );
+}
@@ -0,0 +1,121 @@
+use h3o::{CellIndex, CellSet, FrozenCellSet, LatLng, Resolution};
+
+fn coverage() -> Vec<CellIndex> {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    index
+        .grid_disk::<Vec<_>>(3)
+        .into_iter()
+        .flat_map(|cell| cell.children(Resolution::Twelve))
+        .collect()
+}
+
+#[test]
+fn normalized() {
+    let cells = coverage();
+    let set = cells.iter().copied().collect::<FrozenCellSet>();
+    let mut compacted = CellIndex::compact(cells)
+        .expect("compacted")
+        .collect::<Vec<_>>();
+    compacted.sort_unstable();
+
+    assert_eq!(set.len(), compacted.len());
+    assert_eq!(set.iter().collect::<Vec<_>>(), compacted);
+}
+
+#[test]
+fn nested_and_duplicates() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let child = index.center_child(Resolution::Twelve).expect("child");
+    let set = [child, index, child, index]
+        .into_iter()
+        .collect::<FrozenCellSet>();
+
+    assert_eq!(set.iter().collect::<Vec<_>>(), vec![index]);
+}
+
+#[test]
+fn pentagon() {
+    let pentagon = CellIndex::try_from(0x8009fffffffffff).expect("pentagon");
+    let set = pentagon
+        .children(Resolution::Two)
+        .collect::<FrozenCellSet>();
+
+    assert_eq!(set.iter().collect::<Vec<_>>(), vec![pentagon]);
+}
+
+#[test]
+fn contains_cell() {
+    let cells = coverage();
+    let compacted = CellIndex::compact(cells.iter().copied())
+        .expect("compacted")
+        .collect::<Vec<_>>();
+    let set = compacted.iter().copied().collect::<FrozenCellSet>();
+    let reference = compacted.into_iter().collect::<CellSet>();
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+
+    for cell in index.grid_disk::<Vec<_>>(4) {
+        for child in cell.children(Resolution::Thirteen) {
+            assert_eq!(
+                set.contains_cell(child),
+                reference.contains_cell(child),
+                "{child}"
+            );
+        }
+        // Coarser cells are only contained if entirely covered.
+        assert_eq!(
+            set.contains_cell(cell),
+            reference.contains_cell(cell),
+            "{cell}"
+        );
+    }
+    assert!(
+        !set.contains_cell(index.parent(Resolution::Eight).expect("parent"))
+    );
+}
+
+#[test]
+fn contains_latlng() {
+    let set = coverage().into_iter().collect::<FrozenCellSet>();
+    let reference = coverage().into_iter().collect::<CellSet>();
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+
+    for cell in index.grid_disk::<Vec<_>>(5) {
+        for child in cell.children(Resolution::Twelve).step_by(5) {
+            let ll = LatLng::from(child);
+            assert_eq!(
+                set.contains_latlng(ll),
+                reference.contains_latlng(ll),
+                "{child}"
+            );
+        }
+    }
+}
+
+#[test]
+fn intersection() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let set = index.grid_disk::<FrozenCellSet>(1);
+    let parent = index.parent(Resolution::Seven).expect("parent");
+    let inside = index.center_child(Resolution::Eleven).expect("child");
+    let far = CellIndex::try_from(0x8a0800000007fff).expect("far");
+
+    let mut cells = set.intersection([parent, inside, far]).collect::<Vec<_>>();
+    cells.sort_unstable();
+
+    let mut expected = parent
+        .children(Resolution::Ten)
+        .filter(|cell| set.contains_cell(*cell))
+        .collect::<Vec<_>>();
+    expected.push(inside);
+    expected.sort_unstable();
+    assert_eq!(cells, expected);
+}
+
+#[test]
+fn from_cell_set() {
+    let reference = coverage().into_iter().collect::<CellSet>();
+    let set = FrozenCellSet::from(&reference);
+
+    assert_eq!(set, coverage().into_iter().collect::<FrozenCellSet>());
+    assert!(FrozenCellSet::default().is_empty());
+}
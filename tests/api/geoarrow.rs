@@ -0,0 +1,71 @@
+use h3o::{
+    geoarrow::{LineStringArray, PolygonArray},
+    BoundaryBuffer, CellIndex, DirectedEdgeIndex, LatLng, Resolution,
+};
+
+fn coords(points: &[LatLng]) -> Vec<f64> {
+    points.iter().flat_map(|ll| [ll.lng(), ll.lat()]).collect()
+}
+
+#[test]
+fn polygons() {
+    let hexagon = CellIndex::try_from(0x8a1fb46622dffff).expect("hexagon");
+    let pentagon = CellIndex::try_from(0x8009fffffffffff).expect("pentagon");
+    let polygons = [hexagon, pentagon].into_iter().collect::<PolygonArray>();
+
+    assert_eq!(polygons.len(), 2);
+    assert_eq!(polygons.geom_offsets(), &[0, 1, 2]);
+    // Rings are closed.
+    assert_eq!(polygons.ring_offsets(), &[0, 7, 13]);
+
+    let mut ring = hexagon.boundary().to_vec();
+    ring.push(ring[0]);
+    assert_eq!(&polygons.coords()[..14], &coords(&ring)[..]);
+}
+
+#[test]
+fn polygons_distorted() {
+    // Crosses an icosahedron edge: its boundary has distortion vertices.
+    let cell = CellIndex::base_cells()
+        .flat_map(|cell| cell.children(Resolution::One))
+        .find(|cell| !cell.is_pentagon() && cell.boundary().len() > 6)
+        .expect("distorted cell");
+    let polygons = std::iter::once(cell).collect::<PolygonArray>();
+    let count = i32::try_from(cell.boundary().len() + 1).expect("count");
+
+    assert_eq!(polygons.ring_offsets(), &[0, count]);
+}
+
+#[test]
+fn linestrings() {
+    let edge = DirectedEdgeIndex::try_from(0x13a194e699ab7fff).expect("edge");
+    let lines = [edge, edge.reversed()]
+        .into_iter()
+        .collect::<LineStringArray>();
+
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines.geom_offsets(), &[0, 2, 4]);
+    assert_eq!(&lines.coords()[..4], &coords(&edge.boundary())[..]);
+}
+
+#[test]
+fn from_boundaries() {
+    let cell = CellIndex::try_from(0x8a1fb46622dffff).expect("cell");
+    let boundaries = cell.grid_disk::<BoundaryBuffer>(1);
+    let polygons = PolygonArray::from_boundaries(&boundaries);
+
+    assert_eq!(polygons, cell.grid_disk::<PolygonArray>(1));
+    assert_eq!(polygons.len(), boundaries.len());
+}
+
+#[test]
+fn empty() {
+    let polygons = PolygonArray::from_boundaries(&BoundaryBuffer::new());
+    let lines = LineStringArray::from_boundaries(&BoundaryBuffer::new());
+
+    assert!(polygons.is_empty());
+    assert_eq!(polygons.geom_offsets(), &[0]);
+    assert_eq!(polygons.ring_offsets(), &[0]);
+    assert!(lines.is_empty());
+    assert_eq!(lines.geom_offsets(), &[0]);
+}
@@ -1,6 +1,7 @@
+use float_eq::assert_float_eq;
 use h3o::{
     geom::{LineString, ToCells},
-    Resolution,
+    LatLng, Resolution,
 };
 
 fn linestring_rads() -> geo::LineString {
@@ -60,3 +61,30 @@ fn to_cells() {
 
     assert!(result <= bound);
 }
+
+#[test]
+fn cells_length_m() {
+    let geom = LineString::from_degrees(linestring_degs()).expect("geom");
+    let lengths = geom.cells_length_m(Resolution::Six).collect::<Vec<_>>();
+    let start =
+        LatLng::new(47.47088771408784, -0.5458558636632915).expect("start");
+    let end = LatLng::new(48.84548389122412, 2.373611818843102).expect("end");
+
+    let total = lengths.iter().map(|(_, length)| length).sum::<f64>();
+    assert_float_eq!(total, start.distance_m(end), r2nd <= 1e-9);
+
+    assert_eq!(
+        lengths.first().map(|x| x.0),
+        Some(start.to_cell(Resolution::Six))
+    );
+    assert_eq!(
+        lengths.last().map(|x| x.0),
+        Some(end.to_cell(Resolution::Six))
+    );
+    assert!(lengths.iter().all(|&(_, length)| length > 0.));
+
+    // Consecutive cells are neighbors.
+    for pair in lengths.windows(2) {
+        assert_eq!(pair[0].0.is_neighbor_with(pair[1].0), Ok(true));
+    }
+}
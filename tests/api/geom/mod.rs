@@ -5,9 +5,15 @@ mod linestring;
 mod multilinestring;
 mod multipoint;
 mod multipolygon;
+mod partition;
 mod point;
 mod polygon;
+#[cfg(feature = "polyline")]
+mod polyline;
 mod rect;
 mod to_cells;
 mod to_geo;
+#[cfg(feature = "topojson")]
+mod topojson;
 mod triangle;
+mod viewport;
@@ -0,0 +1,277 @@
+use geo::{polygon, BoundingRect};
+use h3o::{
+    geom::{Geometry, ToCells},
+    Resolution,
+};
+use std::f64::consts::PI;
+
+/// Checks that the partitions together give the coverage of the geometry,
+/// without duplicates across partitions.
+fn check(geometry: &Geometry<'_>, coarse: Resolution, resolution: Resolution) {
+    let partitions = geometry.partition(coarse);
+    assert!(partitions.windows(2).all(|w| w[0].cell() < w[1].cell()));
+
+    let mut result = partitions
+        .iter()
+        .flat_map(|partition| {
+            let mut cells = partition.to_cells(resolution).collect::<Vec<_>>();
+            assert!(cells.len() <= partition.max_cells_count(resolution));
+            cells.sort_unstable();
+            cells.dedup();
+            assert!(cells
+                .iter()
+                .all(|cell| { cell.parent(coarse) == Some(partition.cell()) }));
+            cells
+        })
+        .collect::<Vec<_>>();
+    let count = result.len();
+    result.sort_unstable();
+    result.dedup();
+    assert_eq!(result.len(), count, "duplicates");
+
+    let mut expected = geometry.to_cells(resolution).collect::<Vec<_>>();
+    expected.sort_unstable();
+    expected.dedup();
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn polygon() {
+    let polygon = polygon![
+        (x: -4.7, y: 48.4),
+        (x: 2.5, y: 51.1),
+        (x: 8.2, y: 48.9),
+        (x: 7.5, y: 43.7),
+        (x: 3.1, y: 42.4),
+        (x: -1.8, y: 43.4),
+        (x: -4.7, y: 48.4),
+    ];
+    let geometry =
+        Geometry::from_degrees(polygon.into()).expect("valid geometry");
+
+    check(&geometry, Resolution::Zero, Resolution::Five);
+    check(&geometry, Resolution::Two, Resolution::Five);
+    check(&geometry, Resolution::Five, Resolution::Five);
+}
+
+#[test]
+fn polygon_with_hole() {
+    let polygon = geo::Polygon::new(
+        geo::LineString::from(vec![
+            (-10., 35.),
+            (20., 35.),
+            (20., 60.),
+            (-10., 60.),
+            (-10., 35.),
+        ]),
+        vec![geo::LineString::from(vec![
+            (0., 40.),
+            (10., 40.),
+            (10., 50.),
+            (0., 50.),
+            (0., 40.),
+        ])],
+    );
+    let geometry =
+        Geometry::from_degrees(polygon.into()).expect("valid geometry");
+
+    check(&geometry, Resolution::One, Resolution::Four);
+}
+
+#[test]
+fn transmeridian() {
+    let polygon = polygon![
+        (x: 175., y: -20.),
+        (x: -175., y: -20.),
+        (x: -175., y: -10.),
+        (x: 175., y: -10.),
+        (x: 175., y: -20.),
+    ];
+    let geometry =
+        Geometry::from_degrees(polygon.into()).expect("valid geometry");
+
+    check(&geometry, Resolution::One, Resolution::Four);
+}
+
+#[test]
+fn high_latitude() {
+    // Descendants of near-polar base cells spread over more than half a turn.
+    for (lng, lat, coarse, resolution) in [
+        (90.1, -72.8, Resolution::Zero, Resolution::Five),
+        (25.9, 73.8, Resolution::Two, Resolution::Four),
+        (24.9, 79.5, Resolution::Two, Resolution::Five),
+        (14., 84.9, Resolution::Four, Resolution::Four),
+        (-150., -86., Resolution::One, Resolution::Four),
+    ] {
+        let geometry = Geometry::from_degrees(octagon(lng, lat, 3.).into())
+            .expect("valid geometry");
+
+        check(&geometry, coarse, resolution);
+        assert_ne!(geometry.to_cells(resolution).count(), 0);
+    }
+}
+
+#[test]
+fn random() {
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    let mut next = || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        // Keep 53 bits, to get a float uniformly distributed in [0, 1).
+        (state >> 11) as f64 / (1_u64 << 53) as f64
+    };
+
+    for _ in 0..50 {
+        let lng = next().mul_add(360., -180.);
+        let lat = next().mul_add(170., -85.);
+        let radius = next().mul_add(15., 1.).min(89. - lat.abs());
+        let coarse = Resolution::try_from((next() * 3.) as u8).expect("res");
+        let geometry = Geometry::from_degrees(octagon(lng, lat, radius).into())
+            .expect("valid geometry");
+
+        check(&geometry, coarse, Resolution::Four);
+    }
+}
+
+#[test]
+fn clipped() {
+    let shape = octagon(2.3, 48.8, 5.);
+    let geometry =
+        Geometry::from_degrees(shape.clone().into()).expect("valid geometry");
+    let partitions = geometry.partition(Resolution::Two);
+    let bbox = shape.bounding_rect().expect("bbox");
+
+    assert!(partitions.len() > 1);
+    for partition in partitions {
+        let cell = partition.cell();
+        let pieces = geo::GeometryCollection::from(partition);
+        assert_eq!(pieces.len(), 1, "{cell}");
+        let piece = pieces.bounding_rect().expect("piece bbox");
+        assert!(
+            piece.width().to_degrees() < bbox.width()
+                && piece.height().to_degrees() < bbox.height(),
+            "{cell} isn't clipped"
+        );
+    }
+}
+
+#[test]
+fn clipped_transmeridian() {
+    let geometry = Geometry::from_degrees(octagon(180., -15., 5.).into())
+        .expect("valid geometry");
+
+    for partition in geometry.partition(Resolution::Two) {
+        for piece in geo::GeometryCollection::from(partition) {
+            let geo::Geometry::Polygon(piece) = piece else {
+                panic!("not a polygon");
+            };
+            assert!(piece
+                .exterior()
+                .coords()
+                .all(|coord| { (-PI..=PI).contains(&coord.x) }));
+        }
+    }
+}
+
+#[test]
+fn collection() {
+    let polygon = polygon![
+        (x: 37.58601939796671, y: 55.72992682544245),
+        (x: 37.66530173673016, y: 55.72992682544245),
+        (x: 37.66530173673016, y: 55.777641325418415),
+        (x: 37.58601939796671, y: 55.777641325418415),
+        (x: 37.58601939796671, y: 55.72992682544245),
+    ];
+    let line = geo::LineString::from(vec![
+        (3.1343287, 45.8684781),
+        (2.4067407, 47.0887399),
+        (2.349014, 48.864716),
+    ]);
+    let point = geo::point!(x: -73.985130, y: 40.758896);
+    let collection = geo::GeometryCollection::new_from(vec![
+        polygon.into(),
+        line.into(),
+        point.into(),
+    ]);
+    let geometry =
+        Geometry::from_degrees(geo::Geometry::GeometryCollection(collection))
+            .expect("valid geometry");
+
+    check(&geometry, Resolution::Four, Resolution::Nine);
+}
+
+#[test]
+fn coarser_resolution() {
+    let polygon = polygon![
+        (x: 37.58601939796671, y: 55.72992682544245),
+        (x: 37.66530173673016, y: 55.72992682544245),
+        (x: 37.66530173673016, y: 55.777641325418415),
+        (x: 37.58601939796671, y: 55.777641325418415),
+        (x: 37.58601939796671, y: 55.72992682544245),
+    ];
+    let geometry =
+        Geometry::from_degrees(polygon.into()).expect("valid geometry");
+    let partitions = geometry.partition(Resolution::Five);
+
+    assert!(!partitions.is_empty());
+    for partition in partitions {
+        assert_eq!(partition.to_cells(Resolution::Four).count(), 0);
+    }
+}
+
+#[test]
+fn parallel() {
+    let geometry = Geometry::from_degrees(octagon(2.3, 48.8, 5.).into())
+        .expect("valid geometry");
+    let partitions = geometry.partition(Resolution::One);
+
+    let mut result = std::thread::scope(|scope| {
+        partitions
+            .iter()
+            .map(|partition| {
+                scope.spawn(|| {
+                    partition.to_cells(Resolution::Five).collect::<Vec<_>>()
+                })
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
+            .flat_map(|handle| handle.join().expect("worker"))
+            .collect::<Vec<_>>()
+    });
+    result.sort_unstable();
+    result.dedup();
+
+    let mut expected = geometry.to_cells(Resolution::Five).collect::<Vec<_>>();
+    expected.sort_unstable();
+    expected.dedup();
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn empty() {
+    let geometry = Geometry::from_degrees(geo::Geometry::GeometryCollection(
+        geo::GeometryCollection::default(),
+    ))
+    .expect("valid geometry");
+
+    assert!(geometry.partition(Resolution::Zero).is_empty());
+}
+
+// -----------------------------------------------------------------------------
+
+/// Returns an octagon centered on the given point, in degrees.
+fn octagon(lng: f64, lat: f64, radius: f64) -> geo::Polygon {
+    let mut ring = (0..8)
+        .map(|i| {
+            let angle = f64::from(i) * std::f64::consts::FRAC_PI_4;
+            (
+                (lng + radius * angle.cos() + 180.).rem_euclid(360.) - 180.,
+                lat + radius * angle.sin(),
+            )
+        })
+        .collect::<geo::LineString>();
+    ring.close();
+
+    geo::Polygon::new(ring, Vec::new())
+}
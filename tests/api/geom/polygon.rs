@@ -1,7 +1,8 @@
 use geo::polygon;
 use h3o::{
-    geom::{Polygon, ToCells},
-    Resolution,
+    error::InvalidGeometryKind,
+    geom::{CellRelation, ContainmentMode, Polygon, ToCells},
+    LatLng, Resolution,
 };
 
 fn polygon_rads() -> geo::Polygon {
@@ -70,7 +71,10 @@ fn invalid_nan() {
         (x: -2., y: 3.)
     ]);
 
-    assert!(result.is_err());
+    assert_eq!(
+        result.map_err(|err| err.kind()),
+        Err(InvalidGeometryKind::Coordinate)
+    );
 }
 
 #[test]
@@ -79,7 +83,10 @@ fn invalid_point() {
         (x: -1., y: 3.),
     ]);
 
-    assert!(result.is_err());
+    assert_eq!(
+        result.map_err(|err| err.kind()),
+        Err(InvalidGeometryKind::TooFewCoordinates)
+    );
 }
 
 #[test]
@@ -89,7 +96,10 @@ fn invalid_line() {
         (x: -1., y: 1.),
     ]);
 
-    assert!(result.is_err());
+    assert_eq!(
+        result.map_err(|err| err.kind()),
+        Err(InvalidGeometryKind::TooFewCoordinates)
+    );
 }
 
 #[test]
@@ -110,3 +120,256 @@ fn to_cells() {
 
     assert!(result <= bound);
 }
+
+#[test]
+fn relation() {
+    let shape = polygon![
+        (x: 37.58601939796671, y: 55.72992682544245),
+        (x: 37.66530173673016, y: 55.72992682544245),
+        (x: 37.66530173673016, y: 55.777641325418415),
+        (x: 37.58601939796671, y: 55.777641325418415),
+        (x: 37.58601939796671, y: 55.72992682544245),
+    ];
+    let polygon = Polygon::from_degrees(shape).expect("polygon");
+    let cell_at = |lat, lng, resolution| {
+        LatLng::new(lat, lng).expect("coord").to_cell(resolution)
+    };
+
+    let inside = cell_at(55.75, 37.62, Resolution::Nine);
+    assert_eq!(polygon.relation(inside), CellRelation::Contains);
+
+    let corner =
+        cell_at(55.72992682544245, 37.58601939796671, Resolution::Nine);
+    assert_eq!(polygon.relation(corner), CellRelation::Intersects);
+
+    // The polygon is entirely inside the cell.
+    let around = cell_at(55.75, 37.62, Resolution::Zero);
+    assert_eq!(polygon.relation(around), CellRelation::Intersects);
+
+    let outside = cell_at(48.85, 2.35, Resolution::Nine);
+    assert_eq!(polygon.relation(outside), CellRelation::Disjoint);
+
+    // Check consistency with the polygon to cells algorithm.
+    let cells = polygon.to_cells(Resolution::Nine).collect::<Vec<_>>();
+    assert!(cells
+        .iter()
+        .all(|cell| polygon.relation(*cell) != CellRelation::Disjoint));
+}
+
+#[test]
+fn to_cells_with_mode() {
+    let shape = polygon![
+        (x: 37.58601939796671, y: 55.72992682544245),
+        (x: 37.66530173673016, y: 55.72992682544245),
+        (x: 37.66530173673016, y: 55.777641325418415),
+        (x: 37.58601939796671, y: 55.777641325418415),
+        (x: 37.58601939796671, y: 55.72992682544245),
+    ];
+    let polygon = Polygon::from_degrees(shape).expect("polygon");
+    let cells = |mode| {
+        let mut cells = polygon
+            .to_cells_with_mode(Resolution::Nine, mode)
+            .collect::<Vec<_>>();
+        cells.sort_unstable();
+        cells
+    };
+    let center = cells(ContainmentMode::Center);
+    let full = cells(ContainmentMode::Full);
+    let overlapping = cells(ContainmentMode::Overlapping);
+    let overlapping_bbox = cells(ContainmentMode::OverlappingBbox);
+
+    // Same result as the classic algorithm.
+    let mut expected = polygon.to_cells(Resolution::Nine).collect::<Vec<_>>();
+    expected.sort_unstable();
+    assert_eq!(center, expected);
+
+    // Each mode is more inclusive than the previous one.
+    assert!(!full.is_empty());
+    assert!(full.iter().all(|cell| center.binary_search(cell).is_ok()));
+    assert!(center
+        .iter()
+        .all(|cell| overlapping.binary_search(cell).is_ok()));
+    assert!(overlapping
+        .iter()
+        .all(|cell| overlapping_bbox.binary_search(cell).is_ok()));
+    assert!(full.len() < center.len());
+    assert!(center.len() < overlapping.len());
+    assert!(overlapping.len() <= overlapping_bbox.len());
+
+    assert!(full
+        .iter()
+        .all(|cell| polygon.relation(*cell) == CellRelation::Contains));
+    assert!(overlapping
+        .iter()
+        .all(|cell| polygon.relation(*cell) != CellRelation::Disjoint));
+}
+
+#[test]
+fn to_cells_with_mode_transmeridian() {
+    let shape = polygon![
+        (x: 179.9, y: 10.),
+        (x: -179.9, y: 10.),
+        (x: -179.9, y: 10.2),
+        (x: 179.9, y: 10.2),
+        (x: 179.9, y: 10.),
+    ];
+    let polygon = Polygon::from_degrees(shape).expect("polygon");
+    let cells = polygon
+        .to_cells_with_mode(Resolution::Six, ContainmentMode::Overlapping)
+        .collect::<Vec<_>>();
+
+    let cell_at = |lat, lng| {
+        LatLng::new(lat, lng)
+            .expect("coord")
+            .to_cell(Resolution::Six)
+    };
+    assert!(cells.contains(&cell_at(10.1, 179.95)));
+    assert!(cells.contains(&cell_at(10.1, -179.95)));
+    assert!(!cells.contains(&cell_at(10.1, 0.)));
+    assert!(cells.iter().all(|cell| {
+        let lng = LatLng::from(*cell).lng();
+        lng.abs() > 179.
+    }));
+}
+
+#[test]
+fn to_cells_with_mode_high_latitude() {
+    // Close to the pole, but not containing it.
+    let shape = polygon![
+        (x: 21.9, y: 79.5),
+        (x: 24.9, y: 76.5),
+        (x: 27.9, y: 79.5),
+        (x: 24.9, y: 82.5),
+        (x: 21.9, y: 79.5),
+    ];
+    let polygon = Polygon::from_degrees(shape).expect("polygon");
+    let mut cells = polygon
+        .to_cells_with_mode(Resolution::Five, ContainmentMode::Center)
+        .collect::<Vec<_>>();
+    cells.sort_unstable();
+
+    let mut expected = polygon.to_cells(Resolution::Five).collect::<Vec<_>>();
+    expected.sort_unstable();
+    assert!(!expected.is_empty());
+    assert_eq!(cells, expected);
+}
+
+#[test]
+fn overlay() {
+    let polygon = Polygon::from_degrees(polygon_degs()).expect("polygon");
+    let resolution = Resolution::Nine;
+    let cells = polygon.overlay(resolution).collect::<Vec<_>>();
+    let with = |relations: &[CellRelation]| {
+        cells
+            .iter()
+            .filter_map(|(cell, relation)| {
+                relations.contains(relation).then_some(*cell)
+            })
+            .collect::<Vec<_>>()
+    };
+    let with_mode = |mode| {
+        polygon
+            .to_cells_with_mode(resolution, mode)
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        with(&[CellRelation::Contains]),
+        with_mode(ContainmentMode::Full)
+    );
+    assert_eq!(
+        with(&[CellRelation::Contains, CellRelation::Intersects]),
+        with_mode(ContainmentMode::Overlapping)
+    );
+    let exterior = with(&[CellRelation::Disjoint]);
+    assert!(!exterior.is_empty());
+    assert!(exterior
+        .iter()
+        .all(|cell| polygon.relation(*cell) == CellRelation::Disjoint));
+}
+
+#[test]
+fn to_cells_with_mode_unique() {
+    let shape = polygon![
+        (x: 37.58601939796671, y: 55.72992682544245),
+        (x: 37.66530173673016, y: 55.72992682544245),
+        (x: 37.66530173673016, y: 55.777641325418415),
+        (x: 37.58601939796671, y: 55.777641325418415),
+        (x: 37.58601939796671, y: 55.72992682544245),
+    ];
+    let polygon = Polygon::from_degrees(shape).expect("polygon");
+    let cells = polygon
+        .to_cells_with_mode(Resolution::Ten, ContainmentMode::Full)
+        .collect::<Vec<_>>();
+    let mut unique = cells.clone();
+    unique.sort_unstable();
+    unique.dedup();
+
+    // The order is unspecified, but each cell is returned once.
+    assert!(!cells.is_empty());
+    assert_eq!(unique.len(), cells.len());
+}
+
+#[test]
+fn relation_polar() {
+    let north_pole = LatLng::new(90., 0.).expect("pole");
+    let polar = north_pole.to_cell(Resolution::Two);
+    let cell_at = |lat, lng| {
+        LatLng::new(lat, lng)
+            .expect("coord")
+            .to_cell(Resolution::Two)
+    };
+
+    // Entirely within the polar cell.
+    let shape = polygon![
+        (x: 10., y: 89.),
+        (x: 20., y: 89.),
+        (x: 20., y: 89.5),
+        (x: 10., y: 89.5),
+        (x: 10., y: 89.),
+    ];
+    let polygon = Polygon::from_degrees(shape).expect("polygon");
+    assert_eq!(polygon.relation(polar), CellRelation::Intersects);
+    assert_eq!(polygon.relation(cell_at(60., 15.)), CellRelation::Disjoint);
+
+    // Across the antimeridian, and the boundary of the polar cell.
+    let shape = polygon![
+        (x: 170., y: 80.),
+        (x: -170., y: 80.),
+        (x: -170., y: 89.5),
+        (x: 170., y: 89.5),
+        (x: 170., y: 80.),
+    ];
+    let polygon = Polygon::from_degrees(shape).expect("polygon");
+    assert_eq!(polygon.relation(polar), CellRelation::Intersects);
+
+    // Far from the pole.
+    let shape = polygon![
+        (x: 10., y: 60.),
+        (x: 20., y: 60.),
+        (x: 20., y: 61.),
+        (x: 10., y: 61.),
+        (x: 10., y: 60.),
+    ];
+    let polygon = Polygon::from_degrees(shape).expect("polygon");
+    assert_eq!(polygon.relation(polar), CellRelation::Disjoint);
+    let south_pole = LatLng::new(-90., 0.).expect("pole");
+    assert_eq!(
+        polygon.relation(south_pole.to_cell(Resolution::Two)),
+        CellRelation::Disjoint
+    );
+
+    // Consistency with the overlay.
+    let shape = polygon![
+        (x: 10., y: 88.),
+        (x: 20., y: 88.),
+        (x: 20., y: 89.5),
+        (x: 10., y: 89.5),
+        (x: 10., y: 88.),
+    ];
+    let polygon = Polygon::from_degrees(shape).expect("polygon");
+    let cells = polygon
+        .to_cells_with_mode(Resolution::Two, ContainmentMode::Overlapping)
+        .collect::<Vec<_>>();
+    assert!(cells.contains(&polar));
+}
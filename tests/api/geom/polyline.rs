@@ -0,0 +1,68 @@
+use h3o::{
+    error::InvalidGeometryKind,
+    geom::{LineString, Polyline, Precision, ToCells},
+    Resolution,
+};
+
+const ENCODED5: &str = "_p~iF~ps|U_ulLnnqC_mqNvxq`@";
+const ENCODED6: &str = "_izlhA~rlgdF_{geC~ywl@_kwzCn`{nI";
+
+fn line_string() -> geo::LineString {
+    geo::LineString::from(vec![
+        (-120.2, 38.5),
+        (-120.95, 40.7),
+        (-126.453, 43.252),
+    ])
+}
+
+#[test]
+fn decode() {
+    for (encoded, precision) in
+        [(ENCODED5, Precision::Five), (ENCODED6, Precision::Six)]
+    {
+        let polyline = Polyline::new(encoded, precision).expect("polyline");
+        let result = geo::LineString::from(polyline);
+
+        assert_eq!(result, line_string(), "{precision:?}");
+    }
+}
+
+#[test]
+fn invalid() {
+    let kind = |encoded| {
+        Polyline::new(encoded, Precision::Five)
+            .expect_err("invalid polyline")
+            .kind()
+    };
+
+    // Truncated value.
+    assert_eq!(kind("_p~iF~ps|"), InvalidGeometryKind::Encoding);
+    // Missing longitude.
+    assert_eq!(kind("_p~iF~ps|U_ulL"), InvalidGeometryKind::Encoding);
+    // Out of range character.
+    assert_eq!(
+        kind("_p~iF~ps|U_ulLnnqC_mqNvxq` "),
+        InvalidGeometryKind::Encoding
+    );
+    // Single point.
+    assert_eq!(kind("_p~iF~ps|U"), InvalidGeometryKind::TooFewCoordinates);
+    // Out of range latitude (the precision-6 one read with precision 5).
+    assert_eq!(kind(ENCODED6), InvalidGeometryKind::Coordinate);
+}
+
+#[test]
+fn to_cells() {
+    let polyline = Polyline::new(ENCODED5, Precision::Five).expect("polyline");
+    let line = LineString::from_degrees(line_string()).expect("line");
+
+    for resolution in [Resolution::Two, Resolution::Five] {
+        assert_eq!(
+            polyline.max_cells_count(resolution),
+            line.max_cells_count(resolution)
+        );
+        assert_eq!(
+            polyline.to_cells(resolution).collect::<Vec<_>>(),
+            line.to_cells(resolution).collect::<Vec<_>>()
+        );
+    }
+}
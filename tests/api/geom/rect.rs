@@ -1,6 +1,6 @@
 use h3o::{
-    geom::{Rect, ToCells},
-    Resolution,
+    geom::{Polygon, Rect, ToCells},
+    CellIndex, Resolution,
 };
 
 fn rect_rads() -> geo::Rect {
@@ -58,3 +58,43 @@ fn to_cells() {
 
     assert!(result <= bound);
 }
+
+#[test]
+fn to_cells_same_as_polygon() {
+    let rects = [
+        rect_degs(),
+        // Paris.
+        geo::Rect::new(
+            geo::coord! { x: 2.2, y: 48.8 },
+            geo::coord! { x: 2.5, y: 48.95 },
+        ),
+        // Crossing the antimeridian (Fiji).
+        geo::Rect::new(
+            geo::coord! { x: 179.5, y: -17.5 },
+            geo::coord! { x: 180.5, y: -16.5 },
+        ),
+        // Close to the pole.
+        geo::Rect::new(
+            geo::coord! { x: -20., y: 85. },
+            geo::coord! { x: 40., y: 89.5 },
+        ),
+    ];
+    let cells = |iter: Box<dyn Iterator<Item = CellIndex> + '_>| {
+        let mut cells = iter.collect::<Vec<_>>();
+        cells.sort_unstable();
+        cells
+    };
+
+    for rect in rects {
+        let geom = Rect::from_degrees(rect).expect("rect");
+        let polygon = Polygon::from_degrees(rect.to_polygon()).expect("poly");
+        for resolution in [Resolution::Two, Resolution::Five, Resolution::Seven]
+        {
+            assert_eq!(
+                cells(geom.to_cells(resolution)),
+                cells(polygon.to_cells(resolution)),
+                "{rect:?} at {resolution}"
+            );
+        }
+    }
+}
@@ -364,3 +364,48 @@ fn pentagon_shape() -> geo::Polygon<f64> {
         (x: coord.x - edge_length_2, y: coord.y - edge_length_2)
     ]
 }
+
+// -----------------------------------------------------------------------------
+
+macro_rules! multi_resolution_test {
+    ($name:ident, $shape:expr, $resolutions:expr) => {
+        #[test]
+        fn $name() {
+            let shape = $shape;
+            let polygon = Polygon::from_radians(&shape).expect("polygon");
+            let resolutions = $resolutions
+                .into_iter()
+                .map(|res: u8| Resolution::try_from(res).expect("resolution"))
+                .collect::<Vec<_>>();
+
+            let pyramid = polygon.to_cells_at(&resolutions);
+
+            assert_eq!(pyramid.len(), resolutions.len());
+            for (&resolution, cells) in resolutions.iter().zip(pyramid) {
+                let result = cells.into_iter().collect::<HashSet<_>>();
+                let expected =
+                    polygon.to_cells(resolution).collect::<HashSet<_>>();
+                assert_eq!(result, expected, "resolution {resolution}");
+            }
+        }
+    };
+}
+
+multi_resolution_test!(multi_resolution_h3js_67, h3js_67_shape(), [5, 6, 7]);
+multi_resolution_test!(
+    multi_resolution_h3js_67_2nd,
+    h3js_67_2nd_shape(),
+    [7, 5]
+);
+multi_resolution_test!(multi_resolution_h3_595, h3_595_shape(), [4, 5, 6]);
+multi_resolution_test!(
+    multi_resolution_hexagon,
+    hexagon_shape(),
+    [9, 10, 11, 10]
+);
+multi_resolution_test!(
+    multi_resolution_pentagon,
+    pentagon_shape(),
+    [9, 10, 11]
+);
+multi_resolution_test!(multi_resolution_empty, hexagon_shape(), []);
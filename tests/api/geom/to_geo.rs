@@ -134,6 +134,77 @@ fn from_vertex() {
     assert_relative_eq!(result, expected, epsilon = 1e-6);
 }
 
+#[test]
+fn to_geom_radians() {
+    let index = CellIndex::try_from(0x89283470803ffff).expect("index");
+
+    // No round-trip through degrees: coordinates are exact.
+    let polygon = index.to_geom_radians().expect("polygon");
+    let expected = index
+        .boundary()
+        .iter()
+        .map(|ll| (ll.lng_radians(), ll.lat_radians()))
+        .collect::<Vec<_>>();
+    let result = polygon
+        .exterior()
+        .coords()
+        .map(|coord| (coord.x, coord.y))
+        .take(expected.len())
+        .collect::<Vec<_>>();
+    assert_eq!(result, expected);
+
+    let cells = index.children(Resolution::Ten).collect::<Vec<_>>();
+    let mut expected = cells.clone().to_geom(true).expect("degrees");
+    geo::MapCoordsInPlace::map_coords_in_place(&mut expected, |coord| {
+        geo::Coord {
+            x: coord.x.to_radians(),
+            y: coord.y.to_radians(),
+        }
+    });
+    let result = cells.to_geom_radians().expect("radians");
+    assert_relative_eq!(result, expected, epsilon = 1e-12);
+}
+
+#[test]
+fn from_directed_edges() {
+    let index = CellIndex::try_from(0x89283470803ffff).expect("index");
+    let edges = index.edges().collect::<Vec<_>>();
+
+    let result = edges.clone().to_geom(true).expect("lines");
+    let expected = edges
+        .iter()
+        .map(|edge| geo::LineString::from(edge.to_geom(true).expect("line")))
+        .collect::<Vec<_>>();
+
+    assert_eq!(result, geo::MultiLineString::new(expected));
+
+    let geojson = edges.to_geojson().expect("geojson");
+    assert!(matches!(geojson.value, geojson::Value::MultiLineString(_)));
+}
+
+#[test]
+fn from_vertexes() {
+    let index = CellIndex::try_from(0x89283470803ffff).expect("index");
+    let vertexes = index.vertexes().collect::<Vec<_>>();
+
+    let result = vertexes.clone().to_geom(false).expect("points");
+    let expected = vertexes
+        .iter()
+        .map(|vertex| vertex.to_geom(false).expect("point"))
+        .collect::<Vec<_>>();
+
+    assert_eq!(result, geo::MultiPoint::new(expected));
+    assert_eq!(result.0.len(), 6);
+}
+
+#[test]
+fn from_empty_vertexes() {
+    let vertexes: Vec<VertexIndex> = Vec::new();
+    let result = vertexes.to_geom(true).expect("points");
+
+    assert!(result.0.is_empty());
+}
+
 // -----------------------------------------------------------------------------
 
 #[test]
@@ -177,7 +248,9 @@ fn resolution_mismatch() {
 
 #[test]
 fn empty() {
-    let result = std::iter::empty().to_geom(false).expect("geometry");
+    let result = std::iter::empty::<CellIndex>()
+        .to_geom(false)
+        .expect("geometry");
 
     // No shape.
     assert!(result.0.is_empty())
@@ -0,0 +1,201 @@
+use h3o::{geom::Topology, CellIndex, Resolution};
+
+/// Decodes the quantized arcs of a topology.
+fn decode_arcs(json: &serde_json::Value) -> Vec<Vec<(i64, i64)>> {
+    json["arcs"]
+        .as_array()
+        .expect("arcs")
+        .iter()
+        .map(|arc| {
+            let mut position = (0, 0);
+            arc.as_array()
+                .expect("arc")
+                .iter()
+                .map(|delta| {
+                    position.0 += delta[0].as_i64().expect("x");
+                    position.1 += delta[1].as_i64().expect("y");
+                    position
+                })
+                .collect()
+        })
+        .collect()
+}
+
+#[test]
+fn arc_count() {
+    let cell = CellIndex::try_from(0x8a1fb46622dffff).expect("cell");
+    let pentagon = CellIndex::try_from(0x8009fffffffffff).expect("pentagon");
+
+    // Without junction, the whole ring is a single arc.
+    assert_eq!(std::iter::once(cell).collect::<Topology>().arc_count(), 1);
+    assert_eq!(
+        std::iter::once(pentagon).collect::<Topology>().arc_count(),
+        1
+    );
+    // 12 inner edges, shared, and 6 outer runs of 3 edges.
+    assert_eq!(cell.grid_disk::<Topology>(1).arc_count(), 18);
+    // Duplicates are ignored.
+    assert_eq!(
+        vec![cell, cell]
+            .into_iter()
+            .collect::<Topology>()
+            .arc_count(),
+        1
+    );
+}
+
+#[test]
+fn rings() {
+    let pentagon = CellIndex::try_from(0x8009fffffffffff).expect("pentagon");
+    let cells = pentagon
+        .grid_disk::<Vec<_>>(2)
+        .into_iter()
+        .flat_map(|cell| cell.children(Resolution::Two))
+        .collect::<Vec<_>>();
+    let json = cells
+        .iter()
+        .copied()
+        .collect::<Topology>()
+        .to_json(Some(1 << 20));
+    let arcs = decode_arcs(&json);
+
+    let geometries = json["objects"]["cells"]["geometries"]
+        .as_array()
+        .expect("geometries");
+    assert_eq!(geometries.len(), cells.len());
+    for geometry in geometries {
+        assert_eq!(geometry["type"], "Polygon");
+        let ring = geometry["arcs"][0]
+            .as_array()
+            .expect("ring")
+            .iter()
+            .map(|id| {
+                let id = id.as_i64().expect("arc id");
+                let index = usize::try_from(if id < 0 { !id } else { id })
+                    .expect("arc index");
+                let mut arc = arcs[index].clone();
+                if id < 0 {
+                    arc.reverse();
+                }
+                arc
+            })
+            .collect::<Vec<_>>();
+
+        // Arcs are chained, and the ring is closed.
+        for (i, arc) in ring.iter().enumerate() {
+            let next = &ring[(i + 1) % ring.len()];
+            let (end, start) = (arc[arc.len() - 1], next[0]);
+            assert!(
+                (end.0 - start.0).abs() <= 1 && (end.1 - start.1).abs() <= 1,
+                "{} not chained: {end:?} vs {start:?}",
+                geometry["id"]
+            );
+        }
+    }
+}
+
+#[test]
+fn unquantized() {
+    let cell = CellIndex::try_from(0x8a1fb46622dffff).expect("cell");
+    let json = std::iter::once(cell).collect::<Topology>().to_json(None);
+
+    assert!(json.get("transform").is_none());
+    let boundary = cell.boundary();
+    let first = &json["arcs"][0][0];
+    assert!(boundary.iter().any(|ll| {
+        first[0].as_f64() == Some(ll.lng())
+            && first[1].as_f64() == Some(ll.lat())
+    }));
+    assert_eq!(
+        json["objects"]["cells"]["geometries"][0]["id"],
+        cell.to_string()
+    );
+}
+
+#[test]
+fn empty() {
+    let json = Topology::from_iter(std::iter::empty()).to_json(Some(10_000));
+
+    assert_eq!(json["arcs"].as_array().map(Vec::len), Some(0));
+}
+
+#[test]
+fn smaller_than_geojson() {
+    let cell = CellIndex::try_from(0x8a1fb46622dffff).expect("cell");
+    // 10,981 cells.
+    let cells = cell.grid_disk::<Vec<_>>(60);
+    let features = cells
+        .iter()
+        .map(|cell| {
+            let polygon = geo::Polygon::new(cell.boundary().into(), Vec::new());
+            geojson::Feature {
+                id: Some(geojson::feature::Id::String(cell.to_string())),
+                geometry: Some(geojson::Geometry::new(geojson::Value::from(
+                    &polygon,
+                ))),
+                ..geojson::Feature::default()
+            }
+        })
+        .collect::<Vec<_>>();
+    let geojson = geojson::FeatureCollection {
+        bbox: None,
+        features,
+        foreign_members: None,
+    }
+    .to_string();
+
+    let topology = cells.iter().copied().collect::<Topology>();
+    let topojson = topology.to_json(Some(10_000)).to_string();
+    let compact = topology.to_compact_json(Some(10_000));
+    let arcs = compact["arcs"].to_string();
+    let compact = compact.to_string();
+
+    // Inner edges are shared: about 3 arcs per cell.
+    assert!(topology.arc_count() < cells.len() * 31 / 10);
+
+    // Cells are simple polygons, the gain comes from the shared edges and the
+    // quantization: about 2.4x, far from the ~6x usually expected, as
+    // arcs can't span more than one edge.
+    assert!(
+        topojson.len() * 23 < geojson.len() * 10,
+        "{} vs {}",
+        topojson.len(),
+        geojson.len()
+    );
+    // Without the cell indexes: about 2.8x, the arcs alone are already above
+    // a sixth of the GeoJSON size.
+    assert!(
+        compact.len() * 27 < geojson.len() * 10,
+        "{} vs {}",
+        compact.len(),
+        geojson.len()
+    );
+    assert!(arcs.len() * 6 > geojson.len());
+}
+
+#[test]
+fn compact() {
+    let cell = CellIndex::try_from(0x8a1fb46622dffff).expect("cell");
+    let topology = cell.grid_disk::<Topology>(2);
+    let json = topology.to_json(Some(10_000));
+    let compact = topology.to_compact_json(Some(10_000));
+
+    let geometries = json["objects"]["cells"]["geometries"]
+        .as_array()
+        .expect("geometries");
+    let compact_geometries = compact["objects"]["cells"]["geometries"]
+        .as_array()
+        .expect("geometries");
+    assert_eq!(json["arcs"], compact["arcs"]);
+    assert_eq!(geometries.len(), compact_geometries.len());
+    // Geometries follow the order of the cells.
+    for ((geometry, compact), cell) in geometries
+        .iter()
+        .zip(compact_geometries)
+        .zip(topology.cells())
+    {
+        assert_eq!(geometry["id"], cell.to_string());
+        assert!(compact.get("id").is_none());
+        assert_eq!(geometry["arcs"], compact["arcs"]);
+    }
+}
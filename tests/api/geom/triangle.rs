@@ -1,6 +1,6 @@
 use h3o::{
-    geom::{ToCells, Triangle},
-    Resolution,
+    geom::{Polygon, ToCells, Triangle},
+    CellIndex, Resolution,
 };
 
 fn triangle_rads() -> geo::Triangle {
@@ -61,3 +61,131 @@ fn to_cells() {
 
     assert!(result <= bound);
 }
+
+#[test]
+fn to_cells_same_as_polygon() {
+    // Finest resolution tested for each triangle, to go through the hierarchy.
+    let triangles = [
+        (triangle_degs(), Resolution::Seven),
+        // Clockwise.
+        (
+            geo::Triangle::new(
+                geo::coord! { x: 2.2, y: 48.8 },
+                geo::coord! { x: 2.35, y: 48.95 },
+                geo::coord! { x: 2.5, y: 48.8 },
+            ),
+            Resolution::Nine,
+        ),
+        // Crossing the antimeridian (Fiji).
+        (
+            geo::Triangle::new(
+                geo::coord! { x: 179.5, y: -17.5 },
+                geo::coord! { x: -179.5, y: -17.5 },
+                geo::coord! { x: 180., y: -16.5 },
+            ),
+            Resolution::Seven,
+        ),
+        // Close to the pole.
+        (
+            geo::Triangle::new(
+                geo::coord! { x: -10., y: 89. },
+                geo::coord! { x: 10., y: 89. },
+                geo::coord! { x: 0., y: 89.9 },
+            ),
+            Resolution::Eight,
+        ),
+    ];
+    let cells = |iter: Box<dyn Iterator<Item = CellIndex> + '_>| {
+        let mut cells = iter.collect::<Vec<_>>();
+        cells.sort_unstable();
+        cells
+    };
+
+    for (triangle, finest) in triangles {
+        let geom = Triangle::from_degrees(triangle).expect("triangle");
+        let polygon =
+            Polygon::from_degrees(triangle.to_polygon()).expect("polygon");
+        for resolution in [Resolution::Two, finest] {
+            assert_eq!(
+                cells(geom.to_cells(resolution)),
+                cells(polygon.to_cells(resolution)),
+                "{triangle:?} at {resolution}"
+            );
+        }
+    }
+}
+
+#[test]
+fn to_cells_random() {
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    let mut next = || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        // Keep 53 bits, to get a float uniformly distributed in [0, 1).
+        (state >> 11) as f64 / (1_u64 << 53) as f64
+    };
+
+    for _ in 0..200 {
+        let lng = next().mul_add(340., -170.);
+        let lat = next().mul_add(160., -80.);
+        let size = next().mul_add(0.2, 0.01);
+        let mut vertex = || {
+            geo::coord! {
+                x: next().mul_add(size, lng),
+                y: next().mul_add(size, lat),
+            }
+        };
+        let triangle = geo::Triangle::new(vertex(), vertex(), vertex());
+        let geom = Triangle::from_degrees(triangle).expect("triangle");
+        let polygon =
+            Polygon::from_degrees(triangle.to_polygon()).expect("polygon");
+
+        // From flooding the edges to going through the hierarchy.
+        for resolution in
+            [Resolution::Five, Resolution::Seven, Resolution::Nine]
+        {
+            let mut result = geom.to_cells(resolution).collect::<Vec<_>>();
+            assert!(result.len() <= geom.max_cells_count(resolution));
+            result.sort_unstable();
+            let mut expected = polygon.to_cells(resolution).collect::<Vec<_>>();
+            expected.sort_unstable();
+            assert_eq!(result, expected, "{triangle:?} at {resolution}");
+        }
+    }
+}
+
+#[test]
+fn to_cells_tessellation() {
+    // Two triangles sharing an edge, like a tessellated polygon.
+    let (a, b, c, d) = (
+        geo::coord! { x: 2.2, y: 48.8 },
+        geo::coord! { x: 2.5, y: 48.8 },
+        geo::coord! { x: 2.5, y: 48.9 },
+        geo::coord! { x: 2.2, y: 48.9 },
+    );
+    let first = Triangle::from_degrees(geo::Triangle::new(a, b, c))
+        .expect("first triangle");
+    let second = Triangle::from_degrees(geo::Triangle::new(a, c, d))
+        .expect("second triangle");
+    let quad = Polygon::from_degrees(geo::Polygon::new(
+        geo::LineString::from(vec![a, b, c, d, a]),
+        Vec::new(),
+    ))
+    .expect("quad");
+
+    for resolution in [Resolution::Six, Resolution::Nine, Resolution::Eleven] {
+        let mut result = first
+            .to_cells(resolution)
+            .chain(second.to_cells(resolution))
+            .collect::<Vec<_>>();
+        result.sort_unstable();
+        let count = result.len();
+        result.dedup();
+        assert_eq!(result.len(), count, "duplicates at {resolution}");
+
+        let mut expected = quad.to_cells(resolution).collect::<Vec<_>>();
+        expected.sort_unstable();
+        assert_eq!(result, expected, "at {resolution}");
+    }
+}
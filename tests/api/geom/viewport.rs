@@ -0,0 +1,141 @@
+use geo::Intersects;
+use h3o::{error::InvalidGeometryKind, geom::Viewport, LatLng, Resolution};
+
+#[test]
+fn invalid() {
+    let center = LatLng::new(48.864716, 2.349014).expect("center");
+
+    for (zoom, width, height) in [
+        (-1., 1024, 768),
+        (31., 1024, 768),
+        (f64::NAN, 1024, 768),
+        (12., 0, 768),
+        (12., 1024, 0),
+    ] {
+        let error = Viewport::new(center, zoom, width, height)
+            .expect_err("invalid viewport");
+        assert_eq!(error.kind(), InvalidGeometryKind::Parameter);
+    }
+}
+
+#[test]
+fn bounds() {
+    let center = LatLng::new(0., 0.).expect("center");
+    let viewport = Viewport::new(center, 1., 256, 256).expect("viewport");
+    let bounds = viewport.bounds();
+
+    assert_eq!(bounds.len(), 1);
+    assert!((bounds[0].min().x + 90.).abs() < 1e-9);
+    assert!((bounds[0].max().x - 90.).abs() < 1e-9);
+    assert!(
+        (bounds[0].min().y + bounds[0].max().y).abs() < 1e-9,
+        "symmetric latitudes"
+    );
+}
+
+#[test]
+fn bounds_whole_world() {
+    let center = LatLng::new(0., 0.).expect("center");
+    let viewport = Viewport::new(center, 0., 1024, 1024).expect("viewport");
+    let bounds = viewport.bounds();
+
+    assert_eq!(bounds.len(), 1);
+    assert_eq!(bounds[0].min().x, -180.);
+    assert_eq!(bounds[0].max().x, 180.);
+
+    assert_eq!(viewport.to_cells(Resolution::Zero).len(), 122);
+    assert_eq!(viewport.to_cells(Resolution::One).len(), 842);
+}
+
+#[test]
+fn wider_than_half_world() {
+    // 281° wide, yet not the whole world.
+    let center = LatLng::new(0., 0.).expect("center");
+    let viewport = Viewport::new(center, 1., 400, 300).expect("viewport");
+    let wide = viewport.to_cells(Resolution::Zero);
+    // 180° wide, same center.
+    let narrow = Viewport::new(center, 1., 256, 300)
+        .expect("viewport")
+        .to_cells(Resolution::Zero);
+
+    assert!(wide.len() > narrow.len());
+    for cell in narrow {
+        assert!(wide.contains(&cell), "{cell} is visible");
+    }
+}
+
+#[test]
+fn antimeridian() {
+    let center = LatLng::new(-17.7, 179.9).expect("center");
+    let viewport = Viewport::new(center, 10., 1024, 768).expect("viewport");
+    let bounds = viewport.bounds();
+
+    assert_eq!(bounds.len(), 2);
+    assert_eq!(bounds[0].max().x, 180.);
+    assert_eq!(bounds[1].min().x, -180.);
+
+    let cells = viewport.to_cells(Resolution::Six);
+    let (mut east, mut west) = (false, false);
+    for cell in &cells {
+        let lng = LatLng::from(*cell).lng();
+        east |= lng > 0.;
+        west |= lng < 0.;
+    }
+    assert!(east && west, "cells on both sides of the antimeridian");
+}
+
+#[test]
+fn resolution() {
+    let center = LatLng::new(48.864716, 2.349014).expect("center");
+    let resolutions = (0..=20)
+        .map(|zoom| {
+            Viewport::new(center, f64::from(zoom), 1024, 768)
+                .expect("viewport")
+                .resolution()
+        })
+        .collect::<Vec<_>>();
+
+    assert!(resolutions.windows(2).all(|pair| pair[0] <= pair[1]));
+    assert!(resolutions[0] < resolutions[20]);
+}
+
+#[test]
+fn resolution_polar() {
+    let edge = LatLng::new(85.051_128_779_806_59, 0.).expect("edge");
+    for lat in [90., -90., 89.999_999] {
+        let center = LatLng::new(lat, 0.).expect("center");
+        let viewport = Viewport::new(center, 2., 1024, 768).expect("viewport");
+        let expected = Viewport::new(edge, 2., 1024, 768)
+            .expect("viewport")
+            .resolution();
+        let resolution = viewport.resolution();
+
+        assert_eq!(resolution, expected, "{lat}");
+        assert!(resolution < Resolution::Five, "{lat}");
+        assert!(!viewport.to_cells(resolution).is_empty(), "{lat}");
+    }
+}
+
+#[test]
+fn to_cells() {
+    let center = LatLng::new(48.864716, 2.349014).expect("center");
+    let viewport = Viewport::new(center, 12., 1024, 768).expect("viewport");
+    let resolution = viewport.resolution();
+    let cells = viewport.to_cells(resolution);
+
+    assert!(cells.contains(&center.to_cell(resolution)));
+    assert!(cells.windows(2).all(|pair| pair[0] < pair[1]));
+    for cell in cells {
+        let boundary = geo::Polygon::new(
+            cell.boundary()
+                .iter()
+                .map(|ll| geo::coord! { x: ll.lng(), y: ll.lat() })
+                .collect(),
+            Vec::new(),
+        );
+        assert!(
+            viewport.bounds()[0].intersects(&boundary),
+            "{cell} is visible"
+        );
+    }
+}
@@ -0,0 +1,3 @@
+#[cfg(feature = "petgraph")]
+mod petgraph;
+mod search;
@@ -0,0 +1,51 @@
+use h3o::{graph, CellIndex, CellSet};
+use petgraph::visit::{EdgeRef, IntoNodeReferences};
+
+#[test]
+fn to_graph() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let cells = index.grid_disk::<CellSet>(2);
+    let graph = graph::to_graph(cells.iter(), |edge| edge.length_m());
+
+    assert_eq!(graph.node_count(), 19);
+    // 19 cells, 6 edges each, minus the 30 outside edges, counted once.
+    assert_eq!(graph.edge_count(), (19 * 6 - 30) / 2);
+    for edge in graph.edge_references() {
+        let (src, dst) = (graph[edge.source()], graph[edge.target()]);
+        let directed = src.edge(dst).expect("neighbors");
+        assert_eq!(*edge.weight(), directed.length_m());
+    }
+}
+
+#[test]
+fn to_graph_duplicates() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let mut cells = index.grid_disk::<Vec<_>>(1);
+    cells.extend(index.grid_disk::<Vec<_>>(1));
+    let graph = graph::to_graph(cells, |_| ());
+
+    assert_eq!(graph.node_count(), 7);
+    assert_eq!(graph.edge_count(), 12);
+}
+
+#[test]
+fn to_csr() {
+    let index = CellIndex::try_from(0x8a1fb46622dffff).expect("index");
+    let far = CellIndex::try_from(0x8a1fb4644937fff).expect("isolated");
+    let cells = index.grid_disk::<Vec<_>>(1).into_iter().chain([far]);
+    let (graph, cells) = graph::to_csr(cells, |edge| edge);
+
+    assert_eq!(graph.node_count(), 8);
+    assert_eq!(graph.edge_count(), 24);
+    for (node, ()) in graph.node_references() {
+        for edge in graph.edges(node) {
+            let directed = *edge.weight();
+            assert_eq!(directed.origin(), cells[edge.source() as usize]);
+            assert_eq!(directed.destination(), cells[edge.target() as usize]);
+        }
+    }
+    // The isolated cell has no neighbor.
+    let position = cells.iter().position(|&cell| cell == far).expect("far");
+    let node = u32::try_from(position).expect("node");
+    assert_eq!(graph.edges(node).count(), 0);
+}
@@ -0,0 +1,120 @@
+use float_eq::assert_float_eq;
+use h3o::{
+    graph::{self, GridGraph},
+    CellIndex, CellSet, LatLng, Resolution,
+};
+
+/// Infinite grid with a wall, where each move costs 1.
+struct Walled {
+    wall: CellSet,
+}
+
+impl GridGraph for Walled {
+    fn is_blocked(&self, cell: CellIndex) -> bool {
+        self.wall.contains(cell)
+    }
+
+    fn weight(&self, _from: CellIndex, _to: CellIndex) -> f64 {
+        1.
+    }
+}
+
+fn path_cost(path: &[CellIndex]) -> f64 {
+    path.windows(2)
+        .map(|pair| LatLng::from(pair[0]).distance_rads(pair[1].into()))
+        .sum()
+}
+
+#[test]
+fn dijkstra_grid() {
+    let src = CellIndex::try_from(0x8a1fb46622dffff).expect("src");
+    let dst = CellIndex::try_from(0x8a1fb4644937fff).expect("dst");
+    let (path, cost) =
+        graph::dijkstra(&Resolution::Ten, src, dst).expect("path");
+
+    let distance = src.grid_distance(dst).expect("distance");
+    assert_eq!(path.len(), usize::try_from(distance).expect("len") + 1);
+    assert_eq!(path.first(), Some(&src));
+    assert_eq!(path.last(), Some(&dst));
+    assert!(path.windows(2).all(|pair| pair[0]
+        .is_neighbor_with(pair[1])
+        .expect("same resolution")));
+    assert_float_eq!(cost, path_cost(&path), abs <= 1e-12);
+}
+
+#[test]
+fn astar_matches_dijkstra() {
+    let src = CellIndex::try_from(0x8a1fb46622dffff).expect("src");
+    let dst = CellIndex::try_from(0x8a1fb4644937fff).expect("dst");
+    let goal = LatLng::from(dst);
+
+    let (_, expected) =
+        graph::dijkstra(&Resolution::Ten, src, dst).expect("path");
+    let (path, cost) = graph::astar(&Resolution::Ten, src, dst, |cell| {
+        LatLng::from(cell).distance_rads(goal)
+    })
+    .expect("path");
+
+    assert_float_eq!(cost, expected, abs <= 1e-12);
+    assert_float_eq!(cost, path_cost(&path), abs <= 1e-12);
+}
+
+#[test]
+fn dijkstra_cell_set() {
+    let center = CellIndex::try_from(0x8a1fb46622dffff).expect("center");
+    let mut cells = center.grid_disk::<CellSet>(3);
+    let ring = center
+        .grid_ring_fast(1)
+        .collect::<Option<Vec<_>>>()
+        .expect("ring");
+    // Wall off the center, except one cell of the ring.
+    for cell in &ring[1..] {
+        cells.remove(*cell);
+    }
+    let dst = center.grid_ring_fast(2).next().flatten().expect("dst");
+
+    let (path, _) = graph::dijkstra(&cells, center, dst).expect("path");
+    assert_eq!(path[1], ring[0]);
+    assert!(path.iter().all(|&cell| cells.contains(cell)));
+}
+
+#[test]
+fn custom_graph() {
+    let center = CellIndex::try_from(0x8a1fb46622dffff).expect("center");
+    let wall = center
+        .grid_ring_fast(1)
+        .collect::<Option<CellSet>>()
+        .expect("ring");
+    let graph = Walled { wall };
+
+    // Going around the wall takes more hops than the grid distance.
+    let src = center.grid_ring_fast(2).next().flatten().expect("src");
+    let target = center.grid_ring_fast(2).nth(6).flatten().expect("target");
+    let (path, cost) = graph::dijkstra(&graph, src, target).expect("path");
+    assert_eq!(path.len(), 7);
+    assert_float_eq!(cost, 6., abs <= 0.);
+    assert_eq!(src.grid_distance(target).expect("distance"), 4);
+    assert!(path.iter().all(|&cell| !graph.wall.contains(cell)));
+}
+
+#[test]
+fn unreachable() {
+    let src = CellIndex::try_from(0x8a1fb46622dffff).expect("src");
+    let dst = CellIndex::try_from(0x8a1fb4644937fff).expect("dst");
+    let cells = CellSet::from_iter([src, dst]);
+
+    assert!(graph::dijkstra(&cells, src, dst).is_none());
+    // Blocked start/end.
+    assert!(graph::dijkstra(&CellSet::new(), src, dst).is_none());
+    assert!(graph::dijkstra(&Resolution::Nine, src, dst).is_none());
+}
+
+#[test]
+fn same_cell() {
+    let src = CellIndex::try_from(0x8a1fb46622dffff).expect("src");
+    let (path, cost) =
+        graph::dijkstra(&Resolution::Ten, src, src).expect("path");
+
+    assert_eq!(path, vec![src]);
+    assert_eq!(cost, 0.);
+}
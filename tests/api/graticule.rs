@@ -0,0 +1,69 @@
+use h3o::{Graticule, LatLng, Resolution};
+use std::collections::BTreeSet;
+
+#[test]
+fn new() {
+    assert!(Graticule::new(10., 10.).is_ok());
+    assert!(Graticule::new(0.5, 7.5).is_ok());
+
+    assert!(Graticule::new(0., 10.).is_err(), "zero");
+    assert!(Graticule::new(10., -10.).is_err(), "negative");
+    assert!(Graticule::new(f64::NAN, 10.).is_err(), "NaN");
+    assert!(Graticule::new(10., f64::INFINITY).is_err(), "infinite");
+
+    assert!(Graticule::new(Graticule::MIN_STEP, 10.).is_ok(), "min step");
+    assert!(Graticule::new(10., 1e-300).is_err(), "too small");
+    assert!(Graticule::new(0.001, 10.).is_err(), "too small");
+}
+
+#[test]
+fn lines() {
+    let graticule = Graticule::new(30., 100.).expect("graticule");
+
+    assert_eq!(
+        graticule.parallels().collect::<Vec<_>>(),
+        vec![-60., -30., 0., 30., 60.]
+    );
+    assert_eq!(
+        graticule.meridians().collect::<Vec<_>>(),
+        vec![-100., 0., 100.]
+    );
+}
+
+#[test]
+fn to_cells() {
+    let resolution = Resolution::Three;
+    let graticule = Graticule::new(30., 45.).expect("graticule");
+    let cells = graticule.to_cells(resolution).collect::<Vec<_>>();
+
+    let unique = cells.iter().copied().collect::<BTreeSet<_>>();
+    assert_eq!(unique.len(), cells.len(), "no duplicate");
+    assert!(cells.windows(2).all(|pair| pair[0] < pair[1]), "sorted");
+
+    // Every cell containing a point of a line must be there.
+    let mut expected = BTreeSet::new();
+    for i in 0..=3600 {
+        let t = f64::from(i) / 10.;
+        for lng in graticule.meridians() {
+            let ll = LatLng::new(t / 2. - 90., lng).expect("meridian");
+            expected.insert(ll.to_cell(resolution));
+        }
+        for lat in graticule.parallels() {
+            let ll = LatLng::new(lat, t - 180.).expect("parallel");
+            expected.insert(ll.to_cell(resolution));
+        }
+    }
+    assert!(expected.is_subset(&unique));
+    // And nothing far from them.
+    assert!(cells.len() < expected.len() * 3 / 2);
+
+    // Poles are reached by the meridians.
+    for lat in [-90., 90.] {
+        let pole = LatLng::new(lat, 0.).expect("pole").to_cell(resolution);
+        assert!(unique.contains(&pole));
+    }
+
+    // Cells around the antimeridian, from the -180° meridian.
+    let antimeridian = LatLng::new(0., 180.).expect("ll").to_cell(resolution);
+    assert!(unique.contains(&antimeridian));
+}
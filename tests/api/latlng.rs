@@ -103,3 +103,34 @@ fn to_cell_icosahedron_center() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn great_circle_cells() {
+    let resolution = Resolution::Four;
+    let routes = [
+        // Paris to New York.
+        ((48.864716, 2.349014), (40.712776, -74.005974)),
+        // Tokyo to San Francisco, across the antimeridian.
+        ((35.689487, 139.691706), (37.774929, -122.419416)),
+    ];
+
+    for (start, end) in routes {
+        let start = LatLng::new(start.0, start.1).expect("start");
+        let end = LatLng::new(end.0, end.1).expect("end");
+        let cells = start.great_circle_cells(end, resolution);
+
+        assert_eq!(cells.first(), Some(&start.to_cell(resolution)));
+        assert_eq!(cells.last(), Some(&end.to_cell(resolution)));
+        for pair in cells.windows(2) {
+            assert_eq!(pair[0].is_neighbor_with(pair[1]), Ok(true));
+        }
+    }
+}
+
+#[test]
+fn great_circle_cells_same_location() {
+    let ll = LatLng::new(48.864716, 2.349014).expect("ll");
+    let result = ll.great_circle_cells(ll, Resolution::Nine);
+
+    assert_eq!(result, vec![ll.to_cell(Resolution::Nine)]);
+}
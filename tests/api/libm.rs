@@ -0,0 +1,69 @@
+//! With the `libm` feature, results must be bit-identical on every platform:
+//! those checksums must hold everywhere (CI checks them on both x86_64 and
+//! aarch64).
+
+use h3o::{LatLng, Resolution};
+
+/// Deterministic pseudo-random coordinates, in radians.
+fn coordinates(count: usize) -> impl Iterator<Item = LatLng> {
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    let mut next = move || {
+        // xorshift64
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        // 53 bits of randomness, in [0, 1).
+        (state >> 11) as f64 / (1_u64 << 53) as f64
+    };
+
+    (0..count).map(move |_| {
+        let lat = (next() - 0.5) * std::f64::consts::PI;
+        let lng = (next() - 0.5) * 2. * std::f64::consts::PI;
+        LatLng::from_radians(lat, lng).expect("ll")
+    })
+}
+
+/// FNV-1a hash.
+fn fnv1a(hash: u64, value: u64) -> u64 {
+    value.to_le_bytes().iter().fold(hash, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x100_0000_01b3)
+    })
+}
+
+const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+
+#[test]
+fn to_cell() {
+    let checksum = coordinates(10_000).fold(FNV_OFFSET, |hash, ll| {
+        Resolution::range(Resolution::Zero, Resolution::Fifteen)
+            .fold(hash, |hash, resolution| {
+                fnv1a(hash, u64::from(ll.to_cell(resolution)))
+            })
+    });
+
+    assert_eq!(checksum, 0xf891_41a8_ad54_eb01);
+}
+
+#[test]
+fn to_latlng() {
+    let checksum = coordinates(10_000).fold(FNV_OFFSET, |hash, ll| {
+        let center = LatLng::from(ll.to_cell(Resolution::Fifteen));
+        let hash = fnv1a(hash, center.lat_radians().to_bits());
+        fnv1a(hash, center.lng_radians().to_bits())
+    });
+
+    assert_eq!(checksum, 0x292f_842d_d303_ad84);
+}
+
+#[test]
+fn boundary() {
+    let checksum = coordinates(1_000).fold(FNV_OFFSET, |hash, ll| {
+        let boundary = ll.to_cell(Resolution::Ten).boundary();
+        boundary.iter().fold(hash, |hash, vertex| {
+            let hash = fnv1a(hash, vertex.lat_radians().to_bits());
+            fnv1a(hash, vertex.lng_radians().to_bits())
+        })
+    });
+
+    assert_eq!(checksum, 0x26b9_519e_9d7d_6bce);
+}
@@ -1,4 +1,4 @@
-use h3o::{CellIndex, LocalIJ};
+use h3o::{error::LocalIjError, CellIndex, LatLng, LocalIJ, Resolution};
 
 #[test]
 fn display() {
@@ -8,3 +8,72 @@ fn display() {
 
     assert_eq!(local_ij.to_string(), expected);
 }
+
+#[test]
+fn error_too_far() {
+    let anchor = CellIndex::try_from(0x8a1fb46622dffff).expect("anchor");
+    let far = LatLng::new(-33.8688, 151.2093)
+        .expect("coord")
+        .to_cell(Resolution::Ten);
+
+    assert_eq!(
+        far.to_local_ij(anchor),
+        Err(LocalIjError::TooFar {
+            anchor: anchor.base_cell(),
+            target: Some(far.base_cell()),
+        })
+    );
+
+    // Coordinates way outside of the anchor neighborhood.
+    let local_ij = LocalIJ::new_unchecked(anchor, 10_000_000, 0);
+    assert!(matches!(
+        CellIndex::try_from(local_ij),
+        Err(LocalIjError::TooFar { anchor: base_cell, target: None })
+            if base_cell == anchor.base_cell()
+    ));
+}
+
+#[test]
+fn error_pentagon() {
+    let pentagon = CellIndex::try_from(0x821c07fffffffff).expect("pentagon");
+    let cells = pentagon.grid_disk::<Vec<_>>(3);
+
+    // At least one pair of cells around the pentagon fails, and reports it.
+    let error = cells
+        .iter()
+        .flat_map(|&anchor| cells.iter().map(move |&cell| (anchor, cell)))
+        .find_map(|(anchor, cell)| cell.to_local_ij(anchor).err())
+        .expect("pentagon distortion");
+    assert!(matches!(
+        error,
+        LocalIjError::Pentagon { pentagon: base_cell, .. }
+            if base_cell == pentagon.base_cell()
+    ));
+}
+
+#[test]
+fn base_cell_overlap() {
+    // The anchor is on base cell 0, and the coordinates fall where its
+    // coordinate space overlaps with the one of base cell 4.
+    let anchor = CellIndex::try_from(0x81003ffffffffff).expect("anchor");
+    let local_ij = LocalIJ::new_unchecked(anchor, 2, -2);
+    let expected = CellIndex::try_from(0x8109bffffffffff).expect("cell");
+
+    // Resolved into a cell of base cell 4, like H3 does...
+    assert_eq!(CellIndex::try_from(local_ij), Ok(expected));
+    assert_ne!(expected.to_local_ij(anchor), Ok(local_ij));
+    // ... unless the strict conversion is used.
+    assert!(matches!(
+        local_ij.to_cell_strict(),
+        Err(LocalIjError::BaseCellOverlap { anchor: base_cell, neighbor })
+            if base_cell == anchor.base_cell() && u8::from(neighbor) == 4
+    ));
+
+    // Coordinates from an actual cell always resolve.
+    for cell in anchor.grid_disk::<Vec<_>>(3) {
+        if let Ok(local_ij) = cell.to_local_ij(anchor) {
+            assert_eq!(CellIndex::try_from(local_ij), Ok(cell));
+            assert_eq!(local_ij.to_cell_strict(), Ok(cell));
+        }
+    }
+}
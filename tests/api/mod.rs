@@ -1,17 +1,33 @@
+#[cfg(feature = "arrayvec")]
+mod arrayvec;
 mod base_cell;
 mod boundary;
 mod cell_index;
+mod cell_set;
 mod directed_edge_index;
 mod direction;
 mod edge;
 mod face;
 mod face_set;
+mod fixed_latlng;
+mod frozen_cell_set;
+#[cfg(feature = "geoarrow")]
+mod geoarrow;
 #[cfg(feature = "geo")]
 mod geom;
+mod graph;
+mod graticule;
 mod index_mode;
 mod latlng;
+#[cfg(feature = "libm")]
+mod libm;
 mod localij;
 mod resolution;
+mod simplify;
+#[cfg(all(feature = "tracing", feature = "geo"))]
+mod tracing;
+mod track;
+mod undirected_edge_index;
 mod vertex;
 mod vertex_index;
 
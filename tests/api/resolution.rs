@@ -1,4 +1,6 @@
-use h3o::Resolution;
+use float_eq::assert_float_eq;
+use h3o::{CellIndex, DirectedEdgeIndex, Resolution};
+use std::collections::HashSet;
 
 #[test]
 fn try_from_u8() {
@@ -34,3 +36,145 @@ fn display() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn range() {
+    let range = Resolution::range(Resolution::Five, Resolution::Seven);
+    assert_eq!(range.len(), 3);
+    assert_eq!(
+        range.rev().collect::<Vec<_>>(),
+        vec![Resolution::Seven, Resolution::Six, Resolution::Five]
+    );
+
+    let mut range = Resolution::range(Resolution::Two, Resolution::Zero);
+    assert_eq!(range.len(), 0);
+    assert!(range.next().is_none());
+}
+
+#[test]
+fn iter() {
+    let resolutions = Resolution::iter().collect::<Vec<_>>();
+    assert_eq!(resolutions.len(), 16);
+    for (value, resolution) in resolutions.into_iter().enumerate() {
+        assert_eq!(usize::from(u8::from(resolution)), value);
+    }
+}
+
+#[test]
+fn succ_pred() {
+    let mut resolution = Some(Resolution::Zero);
+    for expected in Resolution::iter() {
+        assert_eq!(resolution, Some(expected));
+        resolution = expected.succ();
+    }
+    assert!(resolution.is_none());
+
+    for resolution in Resolution::range(Resolution::One, Resolution::Fifteen) {
+        assert_eq!(
+            resolution.pred().and_then(Resolution::succ),
+            Some(resolution)
+        );
+    }
+    assert!(Resolution::Zero.pred().is_none());
+}
+
+#[test]
+fn edge_length_extremes() {
+    for resolution in Resolution::range(Resolution::Zero, Resolution::Two) {
+        let (min, max) = CellIndex::base_cells()
+            .flat_map(|cell| cell.children(resolution))
+            .flat_map(CellIndex::edges)
+            .map(DirectedEdgeIndex::length_km)
+            .fold((f64::MAX, 0_f64), |(min, max), length| {
+                (min.min(length), max.max(length))
+            });
+        assert_float_eq!(
+            min,
+            resolution.edge_length_min_km(),
+            r2nd <= 1e-12,
+            "{resolution}"
+        );
+        assert_float_eq!(
+            max,
+            resolution.edge_length_max_km(),
+            r2nd <= 1e-12,
+            "{resolution}"
+        );
+    }
+    for resolution in Resolution::iter() {
+        assert!(
+            resolution.edge_length_min_km() < resolution.edge_length_max_km()
+        );
+        if let Some(finer) = resolution.succ() {
+            assert!(
+                finer.edge_length_max_km() < resolution.edge_length_min_km()
+            );
+        }
+    }
+}
+
+#[test]
+fn total_area() {
+    for resolution in Resolution::range(Resolution::Zero, Resolution::Two) {
+        let area = CellIndex::base_cells()
+            .flat_map(|cell| cell.children(resolution))
+            .map(CellIndex::area_km2)
+            .sum::<f64>();
+        assert_float_eq!(
+            area,
+            Resolution::total_area_km2(),
+            r2nd <= 1e-12,
+            "{resolution}"
+        );
+    }
+}
+
+#[test]
+fn for_zoom() {
+    assert_eq!(Resolution::for_zoom(0., 32.), Resolution::Zero);
+    assert_eq!(Resolution::for_zoom(10., 32.), Resolution::Six);
+    assert_eq!(Resolution::for_zoom(30., 32.), Resolution::Fifteen);
+    // Coarsest resolution when even base cells are too small.
+    assert_eq!(Resolution::for_zoom(0., 4096.), Resolution::Zero);
+
+    let resolutions = (0..=30)
+        .map(|zoom| Resolution::for_zoom(f64::from(zoom), 32.))
+        .collect::<Vec<_>>();
+    assert!(resolutions.windows(2).all(|pair| pair[0] <= pair[1]));
+}
+
+#[test]
+fn zoom() {
+    for resolution in Resolution::iter() {
+        let zoom = resolution.zoom(32.);
+        assert_eq!(Resolution::for_zoom(zoom, 32.), resolution, "{zoom}");
+        // Bigger cells on screen require a deeper zoom.
+        assert!(resolution.zoom(64.) > zoom, "{resolution}");
+    }
+    for resolution in Resolution::range(Resolution::One, Resolution::Fifteen) {
+        let zoom = resolution.zoom(32.);
+        assert_eq!(
+            Resolution::for_zoom(zoom - 0.01, 32.),
+            resolution.pred().expect("coarser resolution"),
+            "{resolution}"
+        );
+    }
+}
+
+#[test]
+fn vertexes() {
+    for resolution in Resolution::range(Resolution::Zero, Resolution::Three) {
+        let vertexes = resolution.vertexes().collect::<Vec<_>>();
+        let unique = vertexes.iter().copied().collect::<HashSet<_>>();
+
+        assert_eq!(unique.len(), vertexes.len(), "duplicates");
+        assert_eq!(vertexes.len() as u64, resolution.vertex_count());
+    }
+
+    let vertexes = Resolution::Two.vertexes().collect::<HashSet<_>>();
+    for cell in CellIndex::base_cells()
+        .flat_map(|cell| cell.children(Resolution::Two).collect::<Vec<_>>())
+    {
+        assert!(cell.vertexes().all(|vertex| vertexes.contains(&vertex)));
+    }
+}
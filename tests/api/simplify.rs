@@ -0,0 +1,142 @@
+use h3o::{
+    simplify_path, trace_to_cells, CellIndex, LatLng, LocalIJ, Resolution,
+};
+
+fn path(src: CellIndex, dst: CellIndex) -> Vec<CellIndex> {
+    src.grid_path_cells(dst)
+        .expect("path")
+        .collect::<Result<Vec<_>, _>>()
+        .expect("path cells")
+}
+
+fn reconstruct(waypoints: &[CellIndex]) -> Vec<CellIndex> {
+    let mut cells = vec![waypoints[0]];
+    for pair in waypoints.windows(2) {
+        cells.extend(path(pair[0], pair[1]).into_iter().skip(1));
+    }
+    cells
+}
+
+#[test]
+fn short() {
+    let cell = CellIndex::try_from(0x8a1fb46622dffff).expect("cell");
+    let neighbor = cell.grid_disk::<Vec<_>>(1)[1];
+
+    assert!(simplify_path(&[], 1.).is_empty());
+    assert_eq!(simplify_path(&[cell], 1.), vec![cell]);
+    assert_eq!(simplify_path(&[cell, neighbor], 1.), vec![cell, neighbor]);
+}
+
+#[test]
+fn straight_line() {
+    let src = LatLng::new(48.8584, 2.2945)
+        .expect("src")
+        .to_cell(Resolution::Nine);
+    let dst = LatLng::new(48.8606, 2.3376)
+        .expect("dst")
+        .to_cell(Resolution::Nine);
+    let cells = path(src, dst);
+
+    let waypoints = simplify_path(&cells, 0.5);
+
+    assert_eq!(waypoints, vec![src, dst]);
+    assert_eq!(reconstruct(&waypoints), cells);
+}
+
+#[test]
+fn corner() {
+    let origin = CellIndex::try_from(0x8a1fb46622dffff).expect("origin");
+    let ij = origin.to_local_ij(origin).expect("local ij");
+    let (i, j) = (ij.i(), ij.j());
+    let at = |i, j| {
+        CellIndex::try_from(LocalIJ::new_unchecked(origin, i, j)).expect("cell")
+    };
+    // Go along the I axis, then along the J axis.
+    let corner = at(i + 10, j);
+    let end = at(i + 10, j + 10);
+    let mut cells = path(origin, corner);
+    cells.extend(path(corner, end).into_iter().skip(1));
+
+    let waypoints = simplify_path(&cells, 0.1);
+
+    assert_eq!(waypoints, vec![origin, corner, end]);
+    assert_eq!(reconstruct(&waypoints), cells);
+}
+
+#[test]
+fn great_circles() {
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    let mut next = move || {
+        // xorshift64
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    };
+
+    for _ in 0..500 {
+        let lat = (next() % 160_001) as f64 / 1000. - 80.;
+        let lng = (next() % 360_000) as f64 / 1000. - 180.;
+        let src = LatLng::new(lat, lng).expect("src");
+        let dst = LatLng::new(
+            lat + (next() % 2001) as f64 / 100_000. - 0.01,
+            lng + (next() % 2001) as f64 / 100_000. - 0.01,
+        )
+        .expect("dst");
+        let cells =
+            trace_to_cells([src, dst], Resolution::Nine, 100.).remove(0);
+
+        for tolerance in [0.5, 1., f64::INFINITY] {
+            let waypoints = simplify_path(&cells, tolerance);
+            assert!(waypoints.len() <= cells.len());
+            assert_eq!(
+                reconstruct(&waypoints),
+                cells,
+                "{src} -> {dst} ({tolerance})"
+            );
+        }
+    }
+}
+
+#[test]
+fn tolerance() {
+    let origin = CellIndex::try_from(0x8a1fb46622dffff).expect("origin");
+    let ring = origin.grid_ring_fast(20).collect::<Option<Vec<_>>>();
+    let mut cells = ring.expect("ring");
+    cells.push(cells[0]);
+
+    let fine = simplify_path(&cells, 0.);
+    let coarse = simplify_path(&cells, 3.);
+
+    assert_eq!(fine.first(), cells.first());
+    assert_eq!(fine.last(), cells.last());
+    assert!(coarse.len() < fine.len());
+    assert!(fine.len() < cells.len());
+    assert!(simplify_path(&cells, f64::NAN).len() == cells.len());
+}
+
+#[test]
+fn pentagon() {
+    let pentagon = Resolution::Nine.pentagons().next().expect("pentagon");
+    let center = LatLng::from(pentagon);
+    // Circle around the pentagon.
+    let points = (0..=36).map(|step| {
+        let angle = f64::from(step) * 10_f64.to_radians();
+        LatLng::new(
+            center.lat() + 0.01 * angle.cos(),
+            center.lng() + 0.01 * angle.sin(),
+        )
+        .expect("point")
+    });
+    let cells = trace_to_cells(points, Resolution::Nine, 10.).remove(0);
+
+    let waypoints = simplify_path(&cells, 10.);
+
+    assert_eq!(waypoints.first(), cells.first());
+    assert_eq!(waypoints.last(), cells.last());
+    assert!(waypoints.len() < cells.len());
+    for pair in waypoints.windows(2) {
+        let path = pair[0].grid_path_cells(pair[1]).expect("path");
+        assert!(path.into_iter().all(|cell| cell.is_ok()));
+    }
+}
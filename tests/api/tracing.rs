@@ -0,0 +1,129 @@
+use h3o::{
+    geom::{Polygon, ToCells, ToGeo},
+    CellIndex, LatLng, Resolution,
+};
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+use tracing::{
+    field::{Field, Visit},
+    span, Event, Metadata, Subscriber,
+};
+
+/// Recorded spans, with their integer fields.
+type Spans = Arc<Mutex<Vec<(&'static str, HashMap<&'static str, u64>)>>>;
+
+/// Minimal subscriber, recording every span.
+#[derive(Default)]
+struct Recorder {
+    spans: Spans,
+}
+
+struct Fields<'a>(&'a mut HashMap<&'static str, u64>);
+
+impl Visit for Fields<'_> {
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        self.0.insert(field.name(), value);
+    }
+
+    fn record_debug(&mut self, _: &Field, _: &dyn std::fmt::Debug) {}
+}
+
+impl Subscriber for Recorder {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, span: &span::Attributes<'_>) -> span::Id {
+        let mut fields = HashMap::new();
+        span.record(&mut Fields(&mut fields));
+        let mut spans = self.spans.lock().expect("lock");
+        spans.push((span.metadata().name(), fields));
+        span::Id::from_u64(spans.len() as u64)
+    }
+
+    fn record(&self, span: &span::Id, values: &span::Record<'_>) {
+        let mut spans = self.spans.lock().expect("lock");
+        let index = usize::try_from(span.into_u64()).expect("id") - 1;
+        values.record(&mut Fields(&mut spans[index].1));
+    }
+
+    fn record_follows_from(&self, _: &span::Id, _: &span::Id) {}
+
+    fn event(&self, _: &Event<'_>) {}
+
+    fn enter(&self, _: &span::Id) {}
+
+    fn exit(&self, _: &span::Id) {}
+}
+
+/// Runs `f` and returns the recorded spans.
+fn record(f: impl FnOnce()) -> Vec<(&'static str, HashMap<&'static str, u64>)> {
+    let recorder = Recorder::default();
+    let spans = Arc::clone(&recorder.spans);
+    tracing::subscriber::with_default(recorder, f);
+    let spans = spans.lock().expect("lock").clone();
+    spans
+}
+
+fn polygon() -> Polygon<'static> {
+    let polygon = geo::Polygon::new(
+        geo::LineString::from(vec![
+            (2.2, 48.8),
+            (2.4, 48.8),
+            (2.4, 48.9),
+            (2.2, 48.9),
+            (2.2, 48.8),
+        ]),
+        Vec::new(),
+    );
+    Polygon::from_degrees(polygon).expect("polygon")
+}
+
+#[test]
+fn polyfill() {
+    let polygon = polygon();
+    let mut count = 0;
+    let spans = record(|| {
+        count = polygon.to_cells(Resolution::Seven).count() as u64;
+    });
+
+    let (name, fields) = &spans[0];
+    assert_eq!(*name, "polyfill");
+    assert_eq!(fields["resolution"], 7);
+    assert_eq!(fields["rings"], 1);
+    assert_eq!(fields["cells"], count);
+}
+
+#[test]
+fn compaction() {
+    let cells = CellIndex::try_from(0x85283473fffffff)
+        .expect("cell")
+        .children(Resolution::Seven)
+        .collect::<Vec<_>>();
+    let spans = record(|| {
+        let compacted = CellIndex::compact(cells).expect("compact").count();
+        assert_eq!(compacted, 1);
+    });
+
+    let (name, fields) = &spans[0];
+    assert_eq!(*name, "compaction");
+    assert_eq!(fields["cells"], 1);
+}
+
+#[test]
+fn outline() {
+    let cell = LatLng::new(48.864716, 2.349014)
+        .expect("ll")
+        .to_cell(Resolution::Five);
+    let cells = cell.grid_disk::<Vec<_>>(1);
+    let spans = record(|| {
+        cells.to_geom(true).expect("outline");
+    });
+
+    let (name, fields) = &spans[0];
+    assert_eq!(*name, "outline");
+    assert_eq!(fields["cells"], 7);
+    assert_eq!(fields["rings"], 1);
+}
@@ -0,0 +1,109 @@
+use h3o::{trace_to_cells, CellIndex, LatLng, Resolution};
+
+fn assert_contiguous(path: &[CellIndex]) {
+    for pair in path.windows(2) {
+        assert_eq!(
+            pair[0].is_neighbor_with(pair[1]),
+            Ok(true),
+            "{} -> {}",
+            pair[0],
+            pair[1]
+        );
+    }
+}
+
+#[test]
+fn empty() {
+    let result = trace_to_cells([], Resolution::Nine, 1.);
+
+    assert!(result.is_empty());
+}
+
+#[test]
+fn single_point() {
+    let point = LatLng::new(48.8584, 2.2945).expect("point");
+    let result = trace_to_cells([point], Resolution::Nine, 1.);
+
+    assert_eq!(result, vec![vec![point.to_cell(Resolution::Nine)]]);
+}
+
+#[test]
+fn interpolation() {
+    // Sparse sampling: cells are skipped between the points.
+    let points = [
+        LatLng::new(48.8584, 2.2945).expect("start"),
+        LatLng::new(48.8606, 2.3376).expect("end"),
+    ];
+    let result = trace_to_cells(points, Resolution::Ten, 5.);
+
+    assert_eq!(result.len(), 1);
+    let path = &result[0];
+    assert!(path.len() > 20);
+    assert_eq!(path.first(), Some(&points[0].to_cell(Resolution::Ten)));
+    assert_eq!(path.last(), Some(&points[1].to_cell(Resolution::Ten)));
+    assert_contiguous(path);
+}
+
+#[test]
+fn gap() {
+    let points = [
+        LatLng::new(48.8584, 2.2945).expect("a"),
+        LatLng::new(48.8606, 2.3376).expect("b"),
+        // ~12km away.
+        LatLng::new(48.8009, 2.4519).expect("c"),
+        LatLng::new(48.8010, 2.4530).expect("d"),
+    ];
+    let result = trace_to_cells(points, Resolution::Nine, 5.);
+
+    assert_eq!(result.len(), 2);
+    assert_eq!(result[0].last(), Some(&points[1].to_cell(Resolution::Nine)));
+    assert_eq!(
+        result[1].first(),
+        Some(&points[2].to_cell(Resolution::Nine))
+    );
+    for path in &result {
+        assert_contiguous(path);
+    }
+}
+
+#[test]
+fn jitter() {
+    // A point going back and forth over the boundary of two cells.
+    let cell = CellIndex::try_from(0x8a1fb46622dffff).expect("cell");
+    let neighbor = cell.grid_disk::<Vec<_>>(1)[1];
+    let (a, b) = (LatLng::from(cell), LatLng::from(neighbor));
+    let points = [a, b, a, b, a];
+    let result = trace_to_cells(points, cell.resolution(), 1.);
+
+    assert_eq!(result, vec![vec![cell]]);
+}
+
+#[test]
+fn out_and_back() {
+    // Densely sampled 2km drive eastward, then back west on the same road.
+    let start = LatLng::new(48.8584, 2.2945).expect("start");
+    let outbound = (0..=200)
+        .map(|i| {
+            let lng = start.lng() + 0.0273 * f64::from(i) / 200.;
+            LatLng::new(start.lat(), lng).expect("point")
+        })
+        .collect::<Vec<_>>();
+    let points = outbound
+        .iter()
+        .chain(outbound.iter().rev().skip(1))
+        .copied()
+        .collect::<Vec<_>>();
+
+    let out = trace_to_cells(outbound, Resolution::Nine, 1.);
+    let result = trace_to_cells(points, Resolution::Nine, 1.);
+
+    assert_eq!(out.len(), 1);
+    assert!(out[0].len() > 5);
+    assert_eq!(result.len(), 1);
+    let path = &result[0];
+    assert_contiguous(path);
+    // The U-turn is kept: the path goes there and back again.
+    assert_eq!(path.len(), 2 * out[0].len() - 1);
+    assert_eq!(path[..out[0].len()], out[0]);
+    assert_eq!(path.last(), Some(&start.to_cell(Resolution::Nine)));
+}
@@ -0,0 +1,88 @@
+use h3o::{
+    error::ErrorCode, CellIndex, DirectedEdgeIndex, UndirectedEdgeIndex,
+};
+
+#[test]
+fn canonical() {
+    let index = DirectedEdgeIndex::try_from(0x13a194e699ab7fff).expect("index");
+    let edge = UndirectedEdgeIndex::from(index);
+
+    assert_eq!(edge, UndirectedEdgeIndex::from(index.reversed()));
+    let (canonical, other) = edge.directed_edges();
+    assert_eq!(DirectedEdgeIndex::from(edge), canonical);
+    assert_eq!(canonical.reversed(), other);
+    assert!(canonical < other);
+}
+
+#[test]
+fn one_per_physical_edge() {
+    let cell = CellIndex::try_from(0x8a1fb46622dffff).expect("cell");
+    let edges = cell
+        .grid_disk::<Vec<_>>(1)
+        .into_iter()
+        .flat_map(CellIndex::edges)
+        .map(UndirectedEdgeIndex::from)
+        .collect::<std::collections::HashSet<_>>();
+
+    // 7 hexagons with 6 edges each, 12 of them being shared inside the disk.
+    assert_eq!(edges.len(), 7 * 6 - 12);
+}
+
+#[test]
+fn cells() {
+    let index = DirectedEdgeIndex::try_from(0x13a194e699ab7fff).expect("index");
+    let edge = UndirectedEdgeIndex::from(index);
+    let (origin, destination) = edge.cells();
+
+    assert_eq!(
+        (destination, origin),
+        index.cells(),
+        "canonical is the reversed edge"
+    );
+}
+
+#[test]
+fn length() {
+    let index = DirectedEdgeIndex::try_from(0x13a194e699ab7fff).expect("index");
+    let edge = UndirectedEdgeIndex::from(index);
+
+    assert_eq!(edge.length_rads(), edge.directed_edges().0.length_rads());
+    assert_eq!(edge.boundary().len(), 2);
+}
+
+#[test]
+fn display() {
+    let index = DirectedEdgeIndex::try_from(0x13a194e699ab7fff).expect("index");
+    let edge = UndirectedEdgeIndex::from(index);
+
+    assert_eq!(edge.to_string(), DirectedEdgeIndex::from(edge).to_string());
+    assert_eq!(u64::from(edge), u64::from(index.reversed()));
+}
+
+#[test]
+fn try_from_u64() {
+    let index = DirectedEdgeIndex::try_from(0x13a194e699ab7fff).expect("index");
+    let edge = UndirectedEdgeIndex::from(index);
+
+    assert_eq!(UndirectedEdgeIndex::try_from(u64::from(edge)), Ok(edge));
+    // `index` isn't the canonical direction.
+    let error = UndirectedEdgeIndex::try_from(u64::from(index))
+        .expect_err("non-canonical");
+    assert_eq!(error.code(), ErrorCode::UndirEdgeInvalid);
+    let error = UndirectedEdgeIndex::try_from(0x8a1fb46622dffff)
+        .expect_err("cell index");
+    assert_eq!(error.code(), ErrorCode::UndirEdgeInvalid);
+}
+
+#[test]
+fn from_str() {
+    let index = DirectedEdgeIndex::try_from(0x13a194e699ab7fff).expect("index");
+    let edge = UndirectedEdgeIndex::from(index);
+
+    assert_eq!(edge.to_string().parse::<UndirectedEdgeIndex>(), Ok(edge));
+    assert!(index.to_string().parse::<UndirectedEdgeIndex>().is_err());
+    let error = "foo"
+        .parse::<UndirectedEdgeIndex>()
+        .expect_err("invalid hex");
+    assert_eq!(error.code(), ErrorCode::UndirEdgeInvalid);
+}
@@ -0,0 +1 @@
+//! Database integration tests (cf. `tests/`).
@@ -0,0 +1,67 @@
+use diesel::{
+    dsl::sql,
+    prelude::*,
+    sql_types::{BigInt, Text},
+    sqlite::SqliteConnection,
+};
+use h3o::CellIndex;
+
+fn connection() -> SqliteConnection {
+    SqliteConnection::establish(":memory:").expect("in-memory database")
+}
+
+#[test]
+fn bigint_roundtrip() {
+    let mut conn = connection();
+    let cell = CellIndex::try_from(0x8a1fb46622dffff).expect("cell index");
+
+    let result = diesel::select(cell.into_sql::<BigInt>())
+        .get_result::<CellIndex>(&mut conn)
+        .expect("roundtrip");
+
+    assert_eq!(result, cell);
+}
+
+#[test]
+fn bigint_invalid() {
+    let mut conn = connection();
+
+    let result =
+        diesel::select(sql::<BigInt>("-1")).get_result::<CellIndex>(&mut conn);
+    assert!(result.is_err(), "negative");
+
+    let result =
+        diesel::select(sql::<BigInt>("42")).get_result::<CellIndex>(&mut conn);
+    assert!(result.is_err(), "invalid index");
+}
+
+#[test]
+fn text_roundtrip() {
+    let mut conn = connection();
+    let cell = CellIndex::try_from(0x8a1fb46622dffff).expect("cell index");
+
+    let result = diesel::select(cell.into_sql::<Text>())
+        .get_result::<String>(&mut conn)
+        .expect("encode");
+    assert_eq!(result, "8a1fb46622dffff");
+
+    let result = diesel::select(cell.into_sql::<Text>())
+        .get_result::<CellIndex>(&mut conn)
+        .expect("roundtrip");
+    assert_eq!(result, cell);
+}
+
+#[test]
+fn text() {
+    let mut conn = connection();
+    let cell = CellIndex::try_from(0x8a1fb46622dffff).expect("cell index");
+
+    let result = diesel::select(sql::<Text>("'8a1fb46622dffff'"))
+        .get_result::<CellIndex>(&mut conn)
+        .expect("decode");
+    assert_eq!(result, cell);
+
+    let result =
+        diesel::select(sql::<Text>("'foo'")).get_result::<CellIndex>(&mut conn);
+    assert!(result.is_err(), "invalid index");
+}
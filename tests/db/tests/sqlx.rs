@@ -0,0 +1,61 @@
+use futures_executor::block_on;
+use h3o::CellIndex;
+use sqlx::{types::Text, Connection, SqliteConnection};
+
+fn query(
+    statement: &str,
+    cell: Option<CellIndex>,
+) -> Result<CellIndex, sqlx::Error> {
+    block_on(async {
+        let mut conn = SqliteConnection::connect(":memory:").await?;
+        let query = sqlx::query_scalar(statement);
+        let query = match cell {
+            Some(cell) => query.bind(cell),
+            None => query,
+        };
+        query.fetch_one(&mut conn).await
+    })
+}
+
+#[test]
+fn bigint_roundtrip() {
+    let cell = CellIndex::try_from(0x8a1fb46622dffff).expect("cell index");
+
+    let result = query("SELECT ?", Some(cell)).expect("roundtrip");
+
+    assert_eq!(result, cell);
+}
+
+#[test]
+fn bigint_invalid() {
+    assert!(query("SELECT -1", None).is_err(), "negative");
+    assert!(query("SELECT 42", None).is_err(), "invalid index");
+}
+
+#[test]
+fn text_roundtrip() {
+    let cell = CellIndex::try_from(0x8a1fb46622dffff).expect("cell index");
+
+    let (text, result) = block_on(async {
+        let mut conn = SqliteConnection::connect(":memory:").await?;
+        sqlx::query_as::<_, (String, CellIndex)>("SELECT ?, ?")
+            .bind(Text(cell))
+            .bind(Text(cell))
+            .fetch_one(&mut conn)
+            .await
+    })
+    .expect("roundtrip");
+
+    assert_eq!(text, "8a1fb46622dffff");
+    assert_eq!(result, cell);
+}
+
+#[test]
+fn text() {
+    let cell = CellIndex::try_from(0x8a1fb46622dffff).expect("cell index");
+
+    let result = query("SELECT '8a1fb46622dffff'", None).expect("decode");
+    assert_eq!(result, cell);
+
+    assert!(query("SELECT 'foo'", None).is_err(), "invalid index");
+}
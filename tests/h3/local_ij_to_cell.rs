@@ -1,5 +1,5 @@
 use super::h3api;
-use h3o::{CellIndex, Resolution};
+use h3o::{CellIndex, LocalIJ, Resolution};
 
 macro_rules! exhaustive_test {
     ($name:ident, $resolution:literal) => {
@@ -37,3 +37,21 @@ macro_rules! exhaustive_test {
 exhaustive_test!(exhaustive_res0, 0);
 exhaustive_test!(exhaustive_res1, 1);
 exhaustive_test!(exhaustive_res2, 2);
+
+#[test]
+fn arbitrary_coordinates() {
+    // Not only the coordinates of actual cells, which also covers the areas
+    // where the coordinate spaces of base cells overlap.
+    for anchor in CellIndex::base_cells()
+        .flat_map(|index| index.children(Resolution::One))
+    {
+        for i in -6..=6 {
+            for j in -6..=6 {
+                let coord = LocalIJ::new_unchecked(anchor, i, j);
+                let result = CellIndex::try_from(coord).ok();
+                let reference = h3api::local_ij_to_cell(coord);
+                assert_eq!(result, reference, "{coord}");
+            }
+        }
+    }
+}